@@ -0,0 +1,93 @@
+use std::collections::BTreeSet;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use escapegoat::SgSet;
+
+mod test_data;
+use test_data::RAND_10_000;
+
+const SET_LEN: usize = 1_000;
+const CAPACITY: usize = 2 * SET_LEN;
+
+// Two random sets overlapping by half
+fn get_sets() -> (SgSet<usize, CAPACITY>, SgSet<usize, CAPACITY>) {
+    let a = RAND_10_000.keys[..SET_LEN].iter().cloned().collect();
+    let b = RAND_10_000.keys[SET_LEN / 2..SET_LEN / 2 + SET_LEN]
+        .iter()
+        .cloned()
+        .collect();
+    (a, b)
+}
+
+// Benches -------------------------------------------------------------------------------------------------------------
+
+fn bench_intersection(c: &mut Criterion) {
+    let (a, b) = get_sets();
+
+    c.bench_function("sgs_intersection_set_1_000_rand", |bench| {
+        bench.iter(|| a.intersection_set(&b).unwrap())
+    });
+
+    c.bench_function("sgs_intersection_collect_1_000_rand", |bench| {
+        bench.iter(|| a.intersection(&b).cloned().collect::<SgSet<_, CAPACITY>>())
+    });
+
+    let (a_std, b_std): (BTreeSet<_>, BTreeSet<_>) = (a.iter().collect(), b.iter().collect());
+    c.bench_function("std_intersection_collect_1_000_rand", |bench| {
+        bench.iter(|| a_std.intersection(&b_std).cloned().collect::<BTreeSet<_>>())
+    });
+}
+
+fn bench_union(c: &mut Criterion) {
+    let (a, b) = get_sets();
+
+    c.bench_function("sgs_union_set_1_000_rand", |bench| {
+        bench.iter(|| a.union_set(&b).unwrap())
+    });
+
+    c.bench_function("sgs_union_collect_1_000_rand", |bench| {
+        bench.iter(|| a.union(&b).cloned().collect::<SgSet<_, CAPACITY>>())
+    });
+
+    let (a_std, b_std): (BTreeSet<_>, BTreeSet<_>) = (a.iter().collect(), b.iter().collect());
+    c.bench_function("std_union_collect_1_000_rand", |bench| {
+        bench.iter(|| a_std.union(&b_std).cloned().collect::<BTreeSet<_>>())
+    });
+}
+
+fn bench_difference(c: &mut Criterion) {
+    let (a, b) = get_sets();
+
+    c.bench_function("sgs_difference_set_1_000_rand", |bench| {
+        bench.iter(|| a.difference_set(&b).unwrap())
+    });
+
+    c.bench_function("sgs_difference_collect_1_000_rand", |bench| {
+        bench.iter(|| a.difference(&b).cloned().collect::<SgSet<_, CAPACITY>>())
+    });
+}
+
+fn bench_symmetric_difference(c: &mut Criterion) {
+    let (a, b) = get_sets();
+
+    c.bench_function("sgs_symmetric_difference_set_1_000_rand", |bench| {
+        bench.iter(|| a.symmetric_difference_set(&b).unwrap())
+    });
+
+    c.bench_function("sgs_symmetric_difference_collect_1_000_rand", |bench| {
+        bench.iter(|| {
+            a.symmetric_difference(&b)
+                .cloned()
+                .collect::<SgSet<_, CAPACITY>>()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_intersection,
+    bench_union,
+    bench_difference,
+    bench_symmetric_difference
+);
+criterion_main!(benches);
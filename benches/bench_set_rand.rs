@@ -1,8 +1,8 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::iter::FromIterator;
 
-use criterion::{Criterion, criterion_group, criterion_main};
-use escapegoat::SgSet;
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use escapegoat::{SgMap, SgSet};
 
 mod test_data;
 use test_data::{
@@ -74,6 +74,26 @@ fn bench_insert(c: &mut Criterion) {
     });
 }
 
+fn bench_insert_many(c: &mut Criterion) {
+    // SGS bulk vs SGS loop 10_000 -------------------------------------------------------------------------------------
+
+    c.bench_function("sgs_insert_many_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgs = SgSet::<_, 10_000>::new();
+            sgs.insert_many(RAND_10_000.keys.iter().cloned()).unwrap();
+        })
+    });
+
+    c.bench_function("sgs_insert_loop_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgs = SgSet::<_, 10_000>::new();
+            for k in &RAND_10_000.keys {
+                sgs.insert(*k);
+            }
+        })
+    });
+}
+
 fn bench_get(c: &mut Criterion) {
     // SGS vs STD 100 --------------------------------------------------------------------------------------------------
 
@@ -194,7 +214,91 @@ fn bench_remove(c: &mut Criterion) {
     });
 }
 
+fn bench_remove_many(c: &mut Criterion) {
+    // SGS bulk vs SGS loop 10_000 -------------------------------------------------------------------------------------
+
+    let mut sorted_remove_idxs = RAND_10_000.remove_idxs.clone();
+    sorted_remove_idxs.sort_unstable();
+
+    c.bench_function("sgs_remove_many_10_000_rand", |b| {
+        b.iter_batched(
+            || SGS_10_000_RAND.clone(),
+            |mut sgs| sgs.remove_many(&sorted_remove_idxs),
+            BatchSize::LargeInput,
+        )
+    });
+
+    c.bench_function("sgs_remove_loop_10_000_rand", |b| {
+        b.iter_batched(
+            || SGS_10_000_RAND.clone(),
+            |mut sgs| {
+                for k in &sorted_remove_idxs {
+                    sgs.remove(k);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_upsert(c: &mut Criterion) {
+    // SGM upsert/insert_with vs SGM entry vs STD entry, 10_000 keys into 1_024 buckets --------------------------------
+
+    c.bench_function("sgm_upsert_histogram_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgm = SgMap::<_, usize, 1_024>::new();
+            for k in &RAND_10_000.keys {
+                sgm.upsert(k % 1_024, 1, |n| *n += 1);
+            }
+        })
+    });
+
+    c.bench_function("sgm_entry_histogram_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgm = SgMap::<_, usize, 1_024>::new();
+            for k in &RAND_10_000.keys {
+                *sgm.entry(k % 1_024).or_insert(0) += 1;
+            }
+        })
+    });
+
+    c.bench_function("sgm_insert_with_histogram_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgm = SgMap::<_, usize, 1_024>::new();
+            for k in &RAND_10_000.keys {
+                sgm.insert_with(k % 1_024, 1, |old, new| old + new).unwrap();
+            }
+        })
+    });
+
+    c.bench_function("sgm_entry_and_modify_histogram_10_000_rand", |b| {
+        b.iter(|| {
+            let mut sgm = SgMap::<_, usize, 1_024>::new();
+            for k in &RAND_10_000.keys {
+                sgm.entry(k % 1_024).and_modify(|n| *n += 1).or_insert(1);
+            }
+        })
+    });
+
+    c.bench_function("std_entry_histogram_10_000_rand", |b| {
+        b.iter(|| {
+            let mut std = BTreeMap::new();
+            for k in &RAND_10_000.keys {
+                *std.entry(k % 1_024).or_insert(0_usize) += 1;
+            }
+        })
+    });
+}
+
 // Runner --------------------------------------------------------------------------------------------------------------
 
-criterion_group!(benches, bench_insert, bench_get, bench_remove);
+criterion_group!(
+    benches,
+    bench_insert,
+    bench_insert_many,
+    bench_get,
+    bench_remove,
+    bench_remove_many,
+    bench_upsert
+);
 criterion_main!(benches);
@@ -1,7 +1,7 @@
 use std::collections::BTreeSet;
 use std::iter::FromIterator;
 
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
 use escapegoat::SgSet;
 
 mod test_data;
@@ -194,7 +194,45 @@ fn bench_remove(c: &mut Criterion) {
     });
 }
 
+fn bench_prewarm(c: &mut Criterion) {
+    // SGS lazy vs SGS prewarmed, first 100 inserts into 10_000 capacity -----------------------------------------------
+
+    c.bench_function("sgs_first_insert_100_of_10_000_lazy", |b| {
+        b.iter_batched_ref(
+            SgSet::<usize, 10_000>::new,
+            |sgs| {
+                for k in &SEQ_100.keys {
+                    sgs.insert(*k);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    c.bench_function("sgs_first_insert_100_of_10_000_prewarm", |b| {
+        b.iter_batched_ref(
+            || {
+                let mut sgs = SgSet::<usize, 10_000>::new();
+                sgs.prewarm();
+                sgs
+            },
+            |sgs| {
+                for k in &SEQ_100.keys {
+                    sgs.insert(*k);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
 // Runner --------------------------------------------------------------------------------------------------------------
 
-criterion_group!(benches, bench_insert, bench_get, bench_remove);
+criterion_group!(
+    benches,
+    bench_insert,
+    bench_get,
+    bench_remove,
+    bench_prewarm
+);
 criterion_main!(benches);
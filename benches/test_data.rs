@@ -1,3 +1,6 @@
+// Shared by multiple bench targets, each of which only uses a subset.
+#![allow(dead_code)]
+
 use std::collections::BTreeSet;
 use std::iter::FromIterator;
 
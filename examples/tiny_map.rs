@@ -17,6 +17,21 @@ fn main() {
     #[cfg(not(feature = "fast_rebalance"))]
     #[cfg(feature = "low_mem_insert")]
     {
-        assert_eq!(size_of_val(&tiny_map), 1_352);
+        assert_eq!(size_of_val(&tiny_map), 1_344);
+    }
+    // Caching subtree sizes for faster rebalancing
+    #[cfg(target_pointer_width = "64")]
+    #[cfg(feature = "fast_rebalance")]
+    #[cfg(not(feature = "low_mem_insert"))]
+    {
+        assert_eq!(size_of_val(&tiny_map), 1_864);
+    }
+
+    // Both
+    #[cfg(target_pointer_width = "64")]
+    #[cfg(feature = "fast_rebalance")]
+    #[cfg(feature = "low_mem_insert")]
+    {
+        assert_eq!(size_of_val(&tiny_map), 1_600);
     }
 }
@@ -14,11 +14,12 @@ use libfuzzer_sys::{
 };
 
 use escapegoat::map_types::Entry as SgEntry;
-use escapegoat::SgMap;
+use escapegoat::{SgError, SgMap};
 use std::collections::btree_map::Entry as BtEntry;
 use std::collections::BTreeMap;
 
 const CAPACITY: usize = 2048;
+const OTHER_CAPACITY: usize = 256;
 
 // Map's Entry ---------------------------------------------------------------------------------------------------------
 
@@ -62,6 +63,7 @@ enum MapVacantEntry<V: Debug> {
 enum MapMethod<K: Ord + Debug, V: Debug> {
     // APIs ------------------------------------------------------------------------------------------------------------
     Append { other: Vec<(K, V)> },
+    AppendFrom { other: Vec<(K, V)> },
     // capacity() returns a constant. Omitted, irrelevant coverage.
     Clear,
     ContainsKey { key: K },
@@ -159,7 +161,7 @@ fn assert_len_unchanged<K: Ord , V, const N: usize>(
     assert_eq!(checked_get_len(sg_map, bt_map), old_len);
 }
 
-fn assert_eq_entry<K: Ord  + Debug, V:  Debug, const N: usize>(
+fn assert_eq_entry<K: Ord  + Debug, V: PartialEq + Debug, const N: usize>(
     sg_entry: &SgEntry<K, V, N>,
     bt_entry: &BtEntry<K, V>,
 ) {
@@ -174,6 +176,8 @@ fn assert_eq_entry<K: Ord  + Debug, V:  Debug, const N: usize>(
             }
             SgEntry::Vacant(sgv) => {
                 assert_eq!(btv.key(), sgv.key());
+                assert!(sg_entry.is_vacant() && !sg_entry.is_occupied());
+                assert_eq!(sg_entry.get(), None);
             }
         },
         BtEntry::Occupied(bto) => match sg_entry {
@@ -182,6 +186,8 @@ fn assert_eq_entry<K: Ord  + Debug, V:  Debug, const N: usize>(
             }
             SgEntry::Occupied(sgo) => {
                 assert_eq!(bto.key(), sgo.key());
+                assert!(sg_entry.is_occupied() && !sg_entry.is_vacant());
+                assert_eq!(sg_entry.get(), Some(bto.get()));
             }
         },
     }
@@ -217,6 +223,29 @@ fuzz_target!(|methods: Vec<MapMethod<usize, usize>>| {
                     assert!(checked_get_len(&sg_map, &bt_map) >= len_old);
                 }
             }
+            MapMethod::AppendFrom { other } => {
+                let mut sg_other = SgMap::<_, _, OTHER_CAPACITY>::new();
+                if sg_other.try_extend(other.clone()).is_err() {
+                    continue;
+                }
+
+                let mut bt_other = BTreeMap::from_iter(other);
+                let len_old = checked_get_len(&sg_map, &bt_map);
+                let union_len = len_old + bt_other.keys().filter(|k| !bt_map.contains_key(k)).count();
+
+                assert_eq!(sg_other.len(), bt_other.len());
+                if union_len <= sg_map.capacity() {
+                    assert!(sg_map.append_from(&mut sg_other).is_ok());
+                    bt_map.append(&mut bt_other);
+
+                    assert!(sg_other.is_empty());
+                    assert_eq!(checked_get_len(&sg_map, &bt_map), union_len);
+                } else {
+                    assert_eq!(sg_map.append_from(&mut sg_other), Err(SgError::StackCapacityExceeded));
+                    assert_eq!(checked_get_len(&sg_map, &bt_map), len_old);
+                    assert_eq!(sg_other.len(), bt_other.len());
+                }
+            }
             MapMethod::Clear => {
                 sg_map.clear();
                 bt_map.clear();
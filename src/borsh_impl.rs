@@ -0,0 +1,116 @@
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::map::SgMap;
+use crate::set::SgSet;
+use crate::tree::SortedBuilder;
+
+/*
+Note:
+
+Wire format matches `borsh`'s own `BTreeMap`/`BTreeSet` encoding: a `u32` length prefix followed by entries in
+ascending key order. Deserialization is strict - keys must be unique and sorted, and the length must fit within `N`.
+Violations are reported as `ErrorKind::InvalidData`, never as a panic. Since input is verified sorted as it streams in,
+entries go straight into the arena and the balanced tree is linked once at the end, in `O(n)` total.
+*/
+
+const ERR_CAPACITY_EXCEEDED: &str = "length prefix exceeds stack capacity";
+const ERR_WRONG_ORDER: &str = "keys were not serialized in ascending order";
+
+// Read and validate the length prefix against static capacity.
+fn deserialize_len<R: Read, const N: usize>(reader: &mut R) -> Result<usize> {
+    let len = u32::deserialize_reader(reader)? as usize;
+    match len <= N {
+        true => Ok(len),
+        false => Err(Error::new(ErrorKind::InvalidData, ERR_CAPACITY_EXCEEDED)),
+    }
+}
+
+// Write the length prefix.
+fn serialize_len<W: Write>(len: usize, writer: &mut W) -> Result<()> {
+    u32::try_from(len)
+        .map_err(|_| ErrorKind::InvalidData)?
+        .serialize(writer)
+}
+
+// Map -----------------------------------------------------------------------------------------------------------------
+
+impl<K, V, const N: usize> BorshSerialize for SgMap<K, V, N>
+where
+    K: BorshSerialize + Ord,
+    V: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_len(self.len(), writer)?;
+        for (k, v) in self {
+            k.serialize(writer)?;
+            v.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, const N: usize> BorshDeserialize for SgMap<K, V, N>
+where
+    K: BorshDeserialize + Ord,
+    V: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = deserialize_len::<R, N>(reader)?;
+        let mut builder = SortedBuilder::new();
+
+        for _ in 0..len {
+            let k = K::deserialize_reader(reader)?;
+            let v = V::deserialize_reader(reader)?;
+            if builder.last_key().is_some_and(|last| *last >= k) {
+                return Err(Error::new(ErrorKind::InvalidData, ERR_WRONG_ORDER));
+            }
+            builder
+                .try_push(k, v)
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERR_CAPACITY_EXCEEDED))?;
+        }
+
+        Ok(SgMap {
+            bst: builder.build(),
+        })
+    }
+}
+
+// Set -----------------------------------------------------------------------------------------------------------------
+
+impl<T, const N: usize> BorshSerialize for SgSet<T, N>
+where
+    T: BorshSerialize + Ord,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_len(self.len(), writer)?;
+        for e in self {
+            e.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> BorshDeserialize for SgSet<T, N>
+where
+    T: BorshDeserialize + Ord,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let len = deserialize_len::<R, N>(reader)?;
+        let mut builder = SortedBuilder::new();
+
+        for _ in 0..len {
+            let e = T::deserialize_reader(reader)?;
+            if builder.last_key().is_some_and(|last| *last >= e) {
+                return Err(Error::new(ErrorKind::InvalidData, ERR_WRONG_ORDER));
+            }
+            builder
+                .try_push(e, ())
+                .map_err(|_| Error::new(ErrorKind::InvalidData, ERR_CAPACITY_EXCEEDED))?;
+        }
+
+        Ok(SgSet {
+            bst: builder.build(),
+        })
+    }
+}
@@ -114,6 +114,7 @@ let big_map: SgMap<u64, u64, 2_048> = SgMap::new(); // 2,048 item capacity
 #[cfg(target_pointer_width = "64")]
 #[cfg(not(feature = "low_mem_insert"))]
 #[cfg(not(feature = "fast_rebalance"))]
+#[cfg(not(feature = "access_tracking"))]
 {
     assert_eq!(size_of_val(&small_map), 2_680); // 2.7 KB
     assert_eq!(size_of_val(&big_map), 53_328);  // 53.3 KB
@@ -193,8 +194,7 @@ Licensed under the [MIT license](https://github.com/stevefan1999-personal/escape
 
 // Production
 #![forbid(clippy::undocumented_unsafe_blocks)]
-#![cfg_attr(not(any(test, fuzzing)), no_std)]
-#![cfg_attr(not(any(test, fuzzing)), deny(missing_docs))]
+#![cfg_attr(not(any(test, fuzzing, feature = "std")), no_std)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/stevefan1999-personal/escapegoat/master/img/escapegoat.svg"
 )]
@@ -206,6 +206,8 @@ pub use crate::tree::{Arena, Node, NodeGetHelper, NodeRebuildHelper};
 
 mod tree;
 pub use crate::tree::Alpha;
+pub use crate::tree::ArenaStats;
+pub use crate::tree::CollectError;
 pub use crate::tree::SgError;
 
 mod map;
@@ -220,5 +222,37 @@ pub use crate::set::SgSet;
 /// [`SgSet`][crate::set::SgSet]'s iterator return types.
 pub mod set_types;
 
+mod map_with;
+pub use crate::map_with::{Comparator, SgMapWith};
+
+/// [`SgMapWith`][crate::map_with::SgMapWith]'s iterator return types.
+pub mod map_with_types;
+
+mod map_policy;
+pub use crate::map_policy::{OverflowPolicy, SgMapPolicy};
+
+/// [`OverflowPolicy`][crate::OverflowPolicy] choices for [`SgMapPolicy`][crate::SgMapPolicy].
+pub mod policy {
+    pub use crate::map_policy::{Ignore, Panic};
+}
+
 // Initialization convenience macros.
 mod macros;
+
+// Optional `borsh` (de)serialization.
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+
+// Optional `serde` (de)serialization.
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use crate::serde_impl::SortedSeq;
+
+// Optional random sampling.
+#[cfg(feature = "rand")]
+mod rand_impl;
+
+// Optional conversions from `std` collections.
+#[cfg(feature = "std")]
+mod std_impl;
@@ -71,3 +71,111 @@ macro_rules! sgset {
         }
     };
 }
+
+/// Fallibly create an [`SgMap`][crate::map::SgMap] with capacity `CAP` from an iterator of key-value pairs.
+/// Evaluates to `Result<SgMap<_, _, CAP>, SgError>`.
+///
+/// Unlike [`FromIterator`], which panics if the iterator yields more unique keys than `CAP`, this returns
+/// `Err(SgError::StackCapacityExceeded)` on overflow. Duplicate keys only count once, the last value wins.
+///
+/// # Examples
+///
+/// ```
+/// use escapegoat::{SgError, sgmap_from};
+///
+/// let map = sgmap_from!((1..=3).map(|n| (n, n * 10)), 4).unwrap();
+/// assert_eq!(map.capacity(), 4);
+/// assert_eq!(map[&2], 20);
+///
+/// assert_eq!(
+///     sgmap_from!((0..5).map(|n| (n, ())), 4),
+///     Err(SgError::StackCapacityExceeded)
+/// );
+/// ```
+#[macro_export]
+macro_rules! sgmap_from {
+    ( $iter:expr_2021, $capacity:expr_2021 $(,)? ) => {
+        'sg_map_from: {
+            let mut _sg_map = $crate::SgMap::<_, _, $capacity>::new();
+            for (_key, _value) in $iter {
+                if let Err(err) = _sg_map.try_insert(_key, _value) {
+                    break 'sg_map_from Err(err);
+                }
+            }
+            Ok::<_, $crate::SgError>(_sg_map)
+        }
+    };
+}
+
+/// Fallibly create an [`SgSet`][crate::set::SgSet] with capacity `CAP` from an iterator of values.
+/// Evaluates to `Result<SgSet<_, CAP>, SgError>`.
+///
+/// Unlike [`FromIterator`], which panics if the iterator yields more unique values than `CAP`, this returns
+/// `Err(SgError::StackCapacityExceeded)` on overflow. Duplicate values only count once.
+///
+/// # Examples
+///
+/// ```
+/// use escapegoat::{SgError, sgset_from};
+///
+/// let readings = [3, 1, 3, 2];
+/// let set = sgset_from!(readings, 3).unwrap();
+/// assert!(set.is_full());
+///
+/// assert_eq!(sgset_from!(0..4, 3), Err(SgError::StackCapacityExceeded));
+/// ```
+#[macro_export]
+macro_rules! sgset_from {
+    ( $iter:expr_2021, $capacity:expr_2021 $(,)? ) => {
+        'sg_set_from: {
+            let mut _sg_set = $crate::SgSet::<_, $capacity>::new();
+            for _value in $iter {
+                if let Err(err) = _sg_set.try_insert(_value) {
+                    break 'sg_set_from Err(err);
+                }
+            }
+            Ok::<_, $crate::SgError>(_sg_set)
+        }
+    };
+}
+
+/// Assert at compile time that an [`SgMap`][crate::map::SgMap] or [`SgSet`][crate::set::SgSet] type can hold `required` items.
+/// Expands to a `const { }` block, so a capacity mismatch is a build error rather than a runtime panic.
+///
+/// In generic code, the check runs when the function is instantiated with a concrete `N`.
+/// At item level, bind it to an unnamed constant: `const _: () = assert_capacity!(SgSet<u8, 16>, 12);`.
+///
+/// # Examples
+///
+/// ```
+/// use escapegoat::{SgMap, assert_capacity};
+///
+/// const SENSORS: usize = 12;
+///
+/// fn new_readings<const N: usize>() -> SgMap<u8, f32, N> {
+///     assert_capacity!(SgMap<u8, f32, N>, SENSORS);
+///     SgMap::new()
+/// }
+///
+/// let readings = new_readings::<16>();
+/// assert_eq!(readings.capacity(), 16);
+/// ```
+///
+/// Insufficient capacity fails to compile:
+///
+/// ```compile_fail
+/// use escapegoat::{SgSet, assert_capacity};
+///
+/// const _: () = assert_capacity!(SgSet<u8, 8>, 12);
+/// ```
+#[macro_export]
+macro_rules! assert_capacity {
+    ( $sg_type:ty, $required:expr_2021 $(,)? ) => {
+        const {
+            assert!(
+                <$sg_type>::fits($required),
+                "required item count exceeds stack capacity"
+            )
+        }
+    };
+}
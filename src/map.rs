@@ -1,14 +1,20 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
-use core::iter::FromIterator;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, Rev};
 use core::ops::{Index, RangeBounds};
 
+use arrayvec::ArrayVec;
+
 use crate::map_types::{
-    Entry, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, OccupiedEntry, OccupiedError,
-    Range, RangeMut, VacantEntry, Values, ValuesMut,
+    DiffEvent, DrainChunks, Entry, EntryRef, FilterValues, FilterValuesMut, IntoIter, IntoKeys,
+    IntoValues, Iter, IterMut, Keys, MergeIter, MergeValue, NodeRef, OccupiedEntry, OccupiedError,
+    Range, RangeMut, RemovableIterMut, SgMapView, VacantEntry, VacantEntryRef, Values, ValuesMut,
+    Windows,
 };
 use crate::tree::Alpha;
-use crate::tree::{Idx, SgError, SgTree, node::NodeGetHelper};
+use crate::tree::{ArenaStats, Idx, SgError, SgTree, SmallNode, group_runs, node::NodeGetHelper};
 
 /// Safe, fallible, embedded-friendly ordered map.
 ///
@@ -16,8 +22,17 @@ use crate::tree::{Idx, SgError, SgTree, node::NodeGetHelper};
 ///
 /// * [`try_insert`][crate::map::SgMap::try_insert]
 /// * [`try_append`][crate::map::SgMap::try_append]
+/// * [`append_from`][crate::map::SgMap::append_from]
+/// * [`append_reporting`][crate::map::SgMap::append_reporting]
 /// * [`try_extend`][crate::map::SgMap::try_extend]
+/// * [`insert_many`][crate::map::SgMap::insert_many]
+/// * [`replace_all`][crate::map::SgMap::replace_all]
 /// * [`try_from_iter`][crate::map::SgMap::try_from_iter]
+/// * [`from_refs`][crate::map::SgMap::from_refs]
+/// * [`from_sorted_slices`][crate::map::SgMap::from_sorted_slices]
+/// * [`extend_sorted`][crate::map::SgMap::extend_sorted]
+/// * [`resize`][crate::map::SgMap::resize]
+/// * [`ensure_capacity`][crate::map::SgMap::ensure_capacity]
 ///
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
@@ -27,7 +42,7 @@ use crate::tree::{Idx, SgError, SgTree, node::NodeGetHelper};
 ///
 /// The majority of API examples and descriptions are adapted or directly copied from the standard library's [`BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html).
 /// The goal is to offer embedded developers familiar, ergonomic APIs on resource constrained systems that otherwise don't get the luxury of dynamic collections.
-#[derive(Default, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
 pub struct SgMap<K: Ord, V, const N: usize> {
     pub(crate) bst: SgTree<K, V, N>,
 }
@@ -48,6 +63,23 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         SgMap { bst: SgTree::new() }
     }
 
+    /// An empty `SgMap`, equivalent to [`new`][SgMap::new], for initializing `const` and `static` items
+    /// (or fields of `const`-initialized structs) without a function call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// struct Registry {
+    ///     handlers: SgMap<u8, fn() -> u8, 8>,
+    /// }
+    ///
+    /// const REGISTRY: Registry = Registry { handlers: SgMap::EMPTY };
+    /// assert!(REGISTRY.handlers.is_empty());
+    /// ```
+    pub const EMPTY: Self = Self::new();
+
     /// The [original scapegoat tree paper's](https://people.csail.mit.edu/rivest/pubs/GR93.pdf) alpha, `a`, can be chosen in the range `0.5 <= a < 1.0`.
     /// `a` tunes how "aggressively" the data structure self-balances.
     /// It controls the trade-off between total rebuild time and maximum height guarantees.
@@ -77,6 +109,34 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         self.bst.set_rebal_param(alpha)
     }
 
+    /// Re-tunes alpha on a live instance to `numerator / denominator`, then immediately rebuilds the tree
+    /// (like [`optimize`][SgMap::optimize]), so subsequent operations start from a balanced tree under the new bound.
+    /// Lets adaptive systems trade insert cost against query depth as their workload shifts.
+    /// See [`set_rebal_param`][SgMap::set_rebal_param] for what alpha controls.
+    ///
+    /// Returns `Err` if `0.5 <= numerator / denominator < 1.0` isn't `true` (including a zero `denominator`),
+    /// in which case the map is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, Alpha, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 100>::from_iter((0..100).map(|i| (i, i)));
+    ///
+    /// // Read-heavy phase: rebalance strictly
+    /// assert!(map.set_alpha(3, 5).is_ok());
+    /// assert_eq!(map.rebal_param(), Alpha::from_num(3) / Alpha::from_num(5));
+    /// assert_eq!(map.debug_stats().height, 7); // Minimum for 100 elements
+    ///
+    /// assert_eq!(map.set_alpha(1, 3), Err(SgError::RebalanceFactorOutOfRange));
+    /// assert_eq!(map.set_alpha(1, 0), Err(SgError::RebalanceFactorOutOfRange));
+    /// ```
+    #[doc(alias = "rebalance")]
+    pub fn set_alpha(&mut self, numerator: u32, denominator: u32) -> Result<(), SgError> {
+        self.bst.set_alpha(numerator, denominator)
+    }
+
     /// Get the current rebalance parameter, alpha.
     /// See [the corresponding setter method][SgMap::set_rebal_param] for more details.
     ///
@@ -115,6 +175,160 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         self.bst.capacity()
     }
 
+    /// Returns `true` if `required` entries fit within the map's stack capacity, `N`.
+    /// Being a `const fn` without a receiver, it can be evaluated at compile time, e.g. within a `const { }` block.
+    /// See [`assert_capacity`][crate::assert_capacity] for a build-time assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// const FITS: bool = SgMap::<u8, u8, 10>::fits(8);
+    /// assert!(FITS);
+    /// assert!(!SgMap::<u8, u8, 10>::fits(11));
+    /// ```
+    pub const fn fits(required: usize) -> bool {
+        required <= N
+    }
+
+    /// Returns a read-only snapshot of the map's arena usage: live and free slots, capacity,
+    /// current tree height, and the number of rebuilds performed so far.
+    /// Computing the height visits every node, so this is intended for debugging and profiling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{ArenaStats, SgMap};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// for i in 0..4 {
+    ///     map.insert(i, i);
+    /// }
+    /// map.remove(&0);
+    ///
+    /// let stats: ArenaStats = map.debug_stats();
+    /// assert_eq!(stats.live, 3);
+    /// assert_eq!(stats.free, 1);
+    /// assert_eq!(stats.capacity, 10);
+    /// assert!(stats.height >= 2);
+    /// ```
+    pub fn debug_stats(&self) -> ArenaStats {
+        self.bst.debug_stats()
+    }
+
+    /// Rebuilds the map's entire tree into a perfectly balanced shape, in `O(n)` time.
+    ///
+    /// Scapegoat trees rebalance lazily, so a burst of removals can leave the tree taller than necessary
+    /// until a later operation triggers a rebuild. Calling this during a quiet period moves that cost
+    /// out of latency-sensitive code paths. Contents and iteration order are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 100>::from_iter((0..100).map(|i| (i, i)));
+    /// map.retain(|k, _| *k % 3 == 0);
+    ///
+    /// map.optimize();
+    /// assert_eq!(map.len(), 34);
+    /// assert_eq!(map.debug_stats().height, 6); // Minimum for 34 elements
+    /// ```
+    pub fn optimize(&mut self) {
+        self.bst.optimize()
+    }
+
+    /// Returns a deterministic 64-bit hash of the map's contents, for cheap change detection
+    /// (e.g. invalidating a cached computation derived from the map).
+    ///
+    /// The length and all entries are hashed in sorted order using 64-bit
+    /// [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/index.html) with its standard, fixed offset basis.
+    /// Unlike hashing with `std`'s randomly-seeded `DefaultHasher`, the result is reproducible across runs.
+    /// It depends only on logical contents: capacity, insertion order, and internal tree shape don't affect it.
+    ///
+    /// **Not cryptographic:** collisions can be constructed deliberately, so don't use this to detect tampering.
+    /// Values are only comparable between builds of the same target, since integers are hashed in native byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "b");
+    ///
+    /// // Same contents, different insertion order and capacity
+    /// let b = SgMap::<_, _, 20>::from_iter([(2, "b"), (1, "a")]);
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    ///
+    /// let before = a.content_hash();
+    /// a.insert(2, "c");
+    /// assert_ne!(a.content_hash(), before);
+    /// ```
+    pub fn content_hash(&self) -> u64
+    where
+        K: Hash,
+        V: Hash,
+    {
+        self.bst.content_hash()
+    }
+
+    /// Feeds the map's length and entries, in sorted order, into `hasher`, decoupling the hash algorithm from the
+    /// container (e.g. to plug in SipHash or xxHash instead of [`content_hash`][Self::content_hash]'s FNV-1a).
+    /// The [`Hash`] implementation is built on this method, so both produce the same stream of writes.
+    ///
+    /// Since entries are fed in sorted order, the result doesn't depend on insertion order:
+    /// maps with equal contents feed identical input, regardless of capacity or internal tree shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::Hasher;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use escapegoat::SgMap;
+    ///
+    /// let a = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b")]);
+    /// let b = SgMap::<_, _, 20>::from_iter([(2, "b"), (1, "a")]);
+    ///
+    /// let mut hasher_a = DefaultHasher::new();
+    /// let mut hasher_b = DefaultHasher::new();
+    /// a.hash_with(&mut hasher_a);
+    /// b.hash_with(&mut hasher_b);
+    /// assert_eq!(hasher_a.finish(), hasher_b.finish());
+    /// ```
+    pub fn hash_with<H: Hasher>(&self, hasher: &mut H)
+    where
+        K: Hash,
+        V: Hash,
+    {
+        self.bst.hash_with(hasher)
+    }
+
+    /// Clones the map, for parity with fallible APIs: storage is fixed-capacity, so cloning can't fail to allocate
+    /// and this never returns `Err`.
+    ///
+    /// Like [`Clone::clone`], this is panic-safe: if cloning an element panics part way through, every element
+    /// already cloned is dropped during unwinding, nothing is leaked and the original map is untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b")]);
+    /// let copy = map.try_clone().unwrap();
+    /// assert_eq!(copy, map);
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, SgError>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        Ok(self.clone())
+    }
+
     /// Gets an iterator over the keys of the map, in sorted order.
     ///
     /// # Examples
@@ -220,6 +434,98 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Calls `f` on every entry, in order by key, allowing each value to be modified in place.
+    /// Keys are untouched, so the tree is left exactly as is: nothing moves and no rebalancing occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut prices = SgMap::<_, _, 10>::from_iter([(1, 100), (2, 250), (3, 40)]);
+    ///
+    /// // Discount every item but the first
+    /// prices.map_values_in_place(|id, cents| if *id > 1 { *cents /= 2 });
+    ///
+    /// assert!(prices.into_values().eq([100, 125, 20]));
+    /// ```
+    pub fn map_values_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V),
+    {
+        self.iter_mut().for_each(|(k, v)| f(k, v));
+    }
+
+    /// Consumes the map, returning a map with the same keys and each value converted by `f`, called in order by key.
+    /// The sorted tree structure is reused as is, so no re-sorting or rebalancing is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let words = SgMap::<_, _, 10>::from_iter([(2, "bb"), (1, "a"), (3, "ccc")]);
+    /// let lens: SgMap<_, usize, 10> = words.map_values(|w| w.len());
+    ///
+    /// assert!(lens.into_iter().eq([(1, 1), (2, 2), (3, 3)]));
+    /// ```
+    pub fn map_values<W, F>(self, f: F) -> SgMap<K, W, N>
+    where
+        F: FnMut(V) -> W,
+    {
+        SgMap {
+            bst: self.bst.map_values(f),
+        }
+    }
+
+    /// Gets an iterator over the entries of the map whose values satisfy `pred`, in order by key.
+    /// Nothing is collected: entries are tested lazily as the iterator advances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, 10), (2, 25), (3, 5), (4, 40)]);
+    ///
+    /// let large: Vec<_> = map.filter_values(|v| *v >= 10).collect();
+    /// assert_eq!(large, [(&1, &10), (&2, &25), (&4, &40)]);
+    /// ```
+    pub fn filter_values<F>(&self, pred: F) -> FilterValues<'_, K, V, N, F>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        FilterValues {
+            inner: self.iter(),
+            pred,
+        }
+    }
+
+    /// Gets a mutable iterator over the entries of the map whose values satisfy `pred`, in order by key.
+    /// Nothing is collected: entries are tested lazily as the iterator advances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(1, 10), (2, 25), (3, 5), (4, 40)]);
+    ///
+    /// for (_, v) in map.filter_values_mut(|v| *v < 20) {
+    ///     *v = 0;
+    /// }
+    /// assert!(map.values().eq([0, 25, 0, 40].iter()));
+    /// ```
+    pub fn filter_values_mut<F>(&mut self, pred: F) -> FilterValuesMut<'_, K, V, N, F>
+    where
+        F: FnMut(&V) -> bool,
+    {
+        FilterValuesMut {
+            inner: self.iter_mut(),
+            pred,
+        }
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
     /// # Examples
@@ -307,6 +613,75 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         self.bst.try_append(&mut other.bst)
     }
 
+    /// Attempts to move all elements from `other` into `self`, leaving `other` empty, and reports collisions.
+    /// Keys present in both maps take their value from `other`, and are returned in sorted order.
+    ///
+    /// Keys are returned in an [`ArrayVec`](arrayvec::ArrayVec) rather than a `Vec`, so this works without an allocator,
+    /// like the rest of the crate. At most `N` keys can collide, so it can't overflow.
+    /// Where `std` is available, `.to_vec()` or `.into_iter().collect()` converts it.
+    ///
+    /// Returns [`SgError::StackCapacityExceeded`] if `self` cannot hold the union of both maps.
+    /// The check is done up front, so on failure neither `self` nor `other` is modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut config = SgMap::<_, _, 4>::from_iter([("host", "a"), ("port", "80")]);
+    /// let mut overrides = SgMap::<_, _, 4>::from_iter([("port", "8080"), ("user", "b")]);
+    ///
+    /// let overwritten = config.append_reporting(&mut overrides).unwrap();
+    /// assert_eq!(overwritten.as_slice(), ["port"]);
+    /// assert!(overrides.is_empty());
+    /// assert_eq!(config["port"], "8080");
+    ///
+    /// let mut extra = SgMap::<_, _, 4>::from_iter([("a", ""), ("b", "")]);
+    /// assert_eq!(config.append_reporting(&mut extra), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!((config.len(), extra.len()), (3, 2));
+    /// ```
+    pub fn append_reporting(
+        &mut self,
+        other: &mut SgMap<K, V, N>,
+    ) -> Result<ArrayVec<K, N>, SgError>
+    where
+        K: Clone,
+    {
+        self.bst.try_append_reporting(&mut other.bst)
+    }
+
+    /// Attempts to move all elements from `other`, a map of any capacity, into `self`, leaving `other` empty.
+    /// Keys present in both maps take their value from `other`, as with [`try_append`][crate::map::SgMap::try_append].
+    ///
+    /// Returns [`SgError::StackCapacityExceeded`] if `self` cannot hold the union of both maps.
+    /// The check is done up front, so on failure neither `self` nor `other` is modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut a = SgMap::<_, _, 4>::from_iter([(1, "a"), (2, "b")]);
+    /// let mut b = SgMap::<_, _, 10>::from_iter([(2, "c"), (3, "d")]);
+    ///
+    /// assert!(a.append_from(&mut b).is_ok());
+    /// assert!(b.is_empty());
+    /// assert!(a.into_iter().eq([(1, "a"), (2, "c"), (3, "d")]));
+    ///
+    /// let mut small = SgMap::<_, _, 2>::from_iter([(1, "a")]);
+    /// let mut big = SgMap::<_, _, 10>::from_iter([(2, "b"), (3, "c")]);
+    ///
+    /// assert_eq!(small.append_from(&mut big), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(small.len(), 1);
+    /// assert_eq!(big.len(), 2);
+    /// ```
+    pub fn append_from<const M: usize>(
+        &mut self,
+        other: &mut SgMap<K, V, M>,
+    ) -> Result<(), SgError> {
+        self.bst.try_append_from(&mut other.bst)
+    }
+
     /// Insert a key-value pair into the map.
     /// If the map did not have this key present, `None` is returned.
     /// If the map did have this key present, the value is updated, the old value is returned,
@@ -332,6 +707,106 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         self.bst.insert(key, val)
     }
 
+    /// Insert a key-value pair into the map, exactly like [`insert`][crate::map::SgMap::insert].
+    /// Additionally returns `true` if this insertion triggered a rebalance (an `O(n)` subtree rebuild).
+    ///
+    /// Useful for latency profiling, e.g. correlating tail-latency spikes with rebuilds,
+    /// without diffing [`debug_stats`][crate::map::SgMap::debug_stats] around every call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 100>::new();
+    ///
+    /// // Ascending keys skew the tree, eventually forcing a rebuild
+    /// let rebuilds = (0..100).filter(|&i| map.insert_tracked(i, i).1).count();
+    /// assert!(rebuilds > 0);
+    /// assert_eq!(rebuilds, map.debug_stats().rebuilds);
+    ///
+    /// // Updating an existing key never rebuilds
+    /// assert_eq!(map.insert_tracked(50, 0), (Some(50), false));
+    /// ```
+    pub fn insert_tracked(&mut self, key: K, val: V) -> (Option<V>, bool)
+    where
+        K: Ord,
+    {
+        self.bst.insert_tracked(key, val)
+    }
+
+    /// Inserts `default` if `key` is absent, or applies `update` to the existing value if present.
+    /// Returns a mutable reference to the resulting value, so calls can be chained.
+    ///
+    /// Equivalent to `map.entry(key).and_modify(update).or_insert(default)`, but completes in a single tree descent
+    /// instead of a lookup followed by an insertion, e.g. for hot counting loops.
+    /// On update, `default` is dropped and the stored key is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is absent and the map is already at its stack capacity, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut counts = SgMap::<_, _, 10>::new();
+    /// for c in "abracadabra".chars() {
+    ///     counts.upsert(c, 1, |n| *n += 1);
+    /// }
+    /// assert_eq!(counts[&'a'], 5);
+    /// assert_eq!(counts[&'c'], 1);
+    ///
+    /// *counts.upsert('z', 0, |n| *n += 1) += 10;
+    /// assert_eq!(counts[&'z'], 10);
+    /// ```
+    pub fn upsert<F>(&mut self, key: K, default: V, update: F) -> &mut V
+    where
+        K: Ord,
+        F: FnOnce(&mut V),
+    {
+        self.bst.upsert(key, default, update)
+    }
+
+    /// Inserts `val` if `key` is absent, or replaces the existing value with `merge(old, val)` if present.
+    /// This generalizes [`upsert`][crate::map::SgMap::upsert] to combinations that consume both values,
+    /// e.g. sum, max, or concatenation. On merge, the stored key is left unchanged.
+    ///
+    /// Returns `Err` only if `key` is absent and the map is already at its stack capacity, `N`.
+    /// Merging into an existing key of a full map succeeds.
+    ///
+    /// Both cases take a single descent. A merge updates the value in place, so it never triggers a rebuild.
+    /// If `merge` panics, the entry is removed while unwinding: the map remains valid but no longer contains `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgMap};
+    ///
+    /// let mut words = SgMap::<_, _, 3>::new();
+    /// for w in "the cat saw the other cat".split(' ') {
+    ///     if words.insert_with(w, 1, |old, new| old + new).is_err() {
+    ///         assert_eq!(w, "other");
+    ///     }
+    /// }
+    /// assert!(words.into_iter().eq([("cat", 2), ("saw", 1), ("the", 2)]));
+    ///
+    /// let mut longest = SgMap::<_, _, 10>::new();
+    /// longest.insert_with('a', "ant", |old: &str, new| if new.len() > old.len() { new } else { old })?;
+    /// longest.insert_with('a', "ape", |old: &str, new| if new.len() > old.len() { new } else { old })?;
+    /// longest.insert_with('a', "aphid", |old: &str, new| if new.len() > old.len() { new } else { old })?;
+    /// assert_eq!(longest[&'a'], "aphid");
+    /// # Ok::<(), SgError>(())
+    /// ```
+    pub fn insert_with<F>(&mut self, key: K, val: V, merge: F) -> Result<(), SgError>
+    where
+        K: Ord,
+        F: FnOnce(V, V) -> V,
+    {
+        self.bst.insert_with(key, val, merge)
+    }
+
     /// Insert a key-value pair into the map.
     /// Returns `Err` if the operation can't be completed, else the `Ok` contains:
     /// * `None` if the map did not have this key present.
@@ -448,62 +923,1709 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         self.bst.try_extend(iter)
     }
 
-    /// Attempt conversion from an iterator.
-    /// Will fail if iterator length exceeds `u16::MAX`.
+    /// Insert all key-value pairs from an iterator in bulk, returning the number of new keys added.
+    /// If a key appears more than once, the last pair wins (both key and value are updated, as with [`insert`][SgMap::insert]).
+    ///
+    /// Faster than repeated [`insert`][SgMap::insert] for large batches of unsorted input:
+    /// pairs are sorted once, merged with the existing entries, and the tree is rebuilt balanced in a single pass.
+    ///
+    /// All-or-nothing: returns `Err`, leaving the map unmodified, if the new keys don't fit within capacity.
+    /// The map is likewise left unmodified if the iterator or a key comparison panics.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::{SgMap, SgError};
     ///
-    /// const CAPACITY_1: usize = 1_000;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
-    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    /// let mut map = SgMap::<_, _, 4>::new();
+    /// map.insert(2, "b");
     ///
-    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
+    /// // One update, two new keys
+    /// assert_eq!(map.insert_many([(3, "c"), (1, "a"), (2, "B"), (3, "C")]), Ok(2));
+    /// assert_eq!(map.into_iter().collect::<Vec<_>>(), [(1, "a"), (2, "B"), (3, "C")]);
+    ///
+    /// let mut map = SgMap::<_, _, 2>::new();
     /// assert_eq!(
-    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
-    ///     Err(SgError::MaximumCapacityExceeded)
+    ///     map.insert_many([(1, "a"), (2, "b"), (3, "c")]),
+    ///     Err(SgError::StackCapacityExceeded)
     /// );
+    /// assert!(map.is_empty());
     /// ```
+    pub fn insert_many<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<usize, SgError> {
+        self.bst.insert_many(iter)
+    }
+
+    /// Insert each key-value pair from an iterator only if its key is absent, returning the newly added keys
+    /// in iteration order. Existing entries are left untouched, unlike [`extend`](Extend::extend),
+    /// which overwrites. If a key appears more than once, the first pair wins.
     ///
-    /// ### Note
+    /// Suits idempotent, deduplicating ingestion that needs an audit of additions.
     ///
-    /// There is no `TryFromIterator` trait in `core`/`std`.
-    pub fn try_from_iter<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
-        iter: I,
-    ) -> Result<Self, SgError> {
-        match iter.len() <= SgTree::<K, V, N>::max_capacity() {
+    /// Returns `Err` if a pair with a new key doesn't fit within capacity, along with the keys added before it.
+    /// Iteration stops at that pair, which is dropped. All pairs consumed before it are kept.
+    ///
+    /// Keys are returned in an [`ArrayVec`](arrayvec::ArrayVec) rather than a `Vec`, so this works without an allocator,
+    /// like the rest of the crate. Each added key takes a distinct slot, so at most `N` are ever reported.
+    /// Each pair costs a single descent: absent keys are inserted, and present ones left as-is, along the same path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 4>::from_iter([(2, "b")]);
+    ///
+    /// let added = map.insert_all_new([(3, "c"), (2, "B"), (1, "a"), (3, "C")]).unwrap();
+    /// assert_eq!(added.as_slice(), [3, 1]);
+    /// assert!(map.iter().eq([(&1, &"a"), (&2, &"b"), (&3, &"c")]));
+    ///
+    /// let (added, err) = map.insert_all_new([(1, "x"), (5, "e"), (6, "f")]).unwrap_err();
+    /// assert_eq!((added.as_slice(), err), ([5].as_slice(), SgError::StackCapacityExceeded));
+    /// assert_eq!(map.len(), 4);
+    /// ```
+    pub fn insert_all_new<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<ArrayVec<K, N>, (ArrayVec<K, N>, SgError)>
+    where
+        K: Ord + Clone,
+    {
+        let mut added = ArrayVec::new();
+        for (key, val) in iter {
+            // Full: nothing can be added, so a lookup decides between skipping and failing
+            if self.is_full() {
+                match self.contains_key(&key) {
+                    true => continue,
+                    false => return Err((added, SgError::StackCapacityExceeded)),
+                }
+            }
+
+            // Inserts if absent, else drops the incoming pair and leaves the present entry as-is
+            let old_len = self.len();
+            let (_, idx) = self
+                .bst
+                .internal_balancing_upsert::<Idx, _>(key, val, |_, _, _| None);
+
+            // Every added key occupies a distinct slot, so `added` never exceeds `N`
+            if self.len() > old_len {
+                added.push(self.bst.arena[idx].key().clone());
+            }
+        }
+
+        Ok(added)
+    }
+
+    /// Replaces the map's entire contents with pairs from an iterator, transactionally.
+    /// If a key appears more than once, the last pair wins (as with [`insert`][SgMap::insert]).
+    ///
+    /// New contents are built in a temporary map on the stack and only swapped in once every pair fits,
+    /// so the original contents are dropped on success but left intact on failure.
+    /// Useful for a "refresh from source" pattern, where a partial load would be worse than a stale one.
+    ///
+    /// Returns `Err` if the pairs don't fit within capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 3>::from_iter([(1, "a"), (2, "b")]);
+    ///
+    /// assert_eq!(map.replace_all([(7, "x"), (8, "y"), (9, "z")]), Ok(()));
+    /// assert!(map.iter().eq([(&7, &"x"), (&8, &"y"), (&9, &"z")]));
+    ///
+    /// // Overflow, original contents kept
+    /// assert_eq!(
+    ///     map.replace_all([(1, "a"), (2, "b"), (3, "c"), (4, "d")]),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// assert!(map.iter().eq([(&7, &"x"), (&8, &"y"), (&9, &"z")]));
+    /// ```
+    pub fn replace_all<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), SgError> {
+        self.bst.try_replace_all(iter)
+    }
+
+    /// Attempt conversion from an iterator.
+    /// Will fail if iterator length exceeds `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// const CAPACITY_1: usize = 1_000;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
+    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    ///
+    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
+    /// assert_eq!(
+    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
+    ///     Err(SgError::MaximumCapacityExceeded)
+    /// );
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// There is no `TryFromIterator` trait in `core`/`std`.
+    pub fn try_from_iter<I: ExactSizeIterator + IntoIterator<Item = (K, V)>>(
+        iter: I,
+    ) -> Result<Self, SgError> {
+        match iter.len() <= SgTree::<K, V, N>::max_capacity() {
             true => Ok(SgMap::from_iter(iter)),
             false => Err(SgError::MaximumCapacityExceeded),
         }
     }
 
-    /// Gets an iterator over the entries of the map, sorted by key.
+    /// Creates a map from an iterator of borrowed pairs, cloning each key and value as it's inserted.
+    /// Avoids collecting the borrowed data into an owned buffer just to call [`from_iter`][Self::from_iter].
+    /// As with [`from_iter`][Self::from_iter], a later duplicate key replaces the earlier value.
+    ///
+    /// Returns `Err` ([`SgError::StackCapacityExceeded`]) if the iterator yields more distinct keys than capacity `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let keys = [3, 1, 2];
+    /// let vals = ["c", "a", "b"];
+    ///
+    /// let map = SgMap::<_, _, 10>::from_refs(keys.iter().zip(vals.iter())).unwrap();
+    /// assert!(map.into_iter().eq([(1, "a"), (2, "b"), (3, "c")]));
+    ///
+    /// assert_eq!(
+    ///     SgMap::<_, _, 2>::from_refs(keys.iter().zip(vals.iter())),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn from_refs<'a, I>(iter: I) -> Result<Self, SgError>
+    where
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+        K: Clone + 'a,
+        V: Clone + 'a,
+    {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.try_insert(k.clone(), v.clone())?;
+        }
+        Ok(map)
+    }
+
+    /// Creates a map whose capacity `N` is exactly the length of `arr`, so construction can never overflow.
+    /// Useful when an array literal is the complete dataset: capacity is inferred, not spelled out.
+    ///
+    /// Equivalent to the [`From`] array conversion, but names the intent at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::from_exact([(3, "c"), (1, "a"), (2, "b")]);
+    /// assert_eq!(map.capacity(), 3);
+    /// assert!(map.is_full());
+    /// assert!(map.into_iter().eq([(1, "a"), (2, "b"), (3, "c")]));
+    /// ```
+    pub fn from_exact(arr: [(K, V); N]) -> Self {
+        Self::from(arr)
+    }
+
+    /// Creates a map from parallel slices of keys and values, e.g. columns loaded from storage.
+    /// Keys must be strictly ascending, `vals[i]` is paired with `keys[i]`.
+    /// Since input is already sorted, the tree is built balanced in a single pass instead of via per-entry insertion.
+    ///
+    /// Returns `Err` if:
+    /// * The slices differ in length ([`SgError::SliceLengthMismatch`]).
+    /// * Their length exceeds capacity `N` ([`SgError::StackCapacityExceeded`]).
+    /// * Keys are unsorted or duplicated ([`SgError::KeysNotSorted`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let keys = [1, 2, 3];
+    /// let vals = ["a", "b", "c"];
+    ///
+    /// let map = SgMap::<_, _, 10>::from_sorted_slices(&keys, &vals).unwrap();
+    /// assert!(map.into_iter().eq([(1, "a"), (2, "b"), (3, "c")]));
+    ///
+    /// assert_eq!(
+    ///     SgMap::<_, _, 10>::from_sorted_slices(&keys, &vals[..2]),
+    ///     Err(SgError::SliceLengthMismatch)
+    /// );
+    /// assert_eq!(
+    ///     SgMap::<_, _, 10>::from_sorted_slices(&[1, 3, 2], &vals),
+    ///     Err(SgError::KeysNotSorted)
+    /// );
+    /// assert_eq!(
+    ///     SgMap::<_, _, 2>::from_sorted_slices(&keys, &vals),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn from_sorted_slices(keys: &[K], vals: &[V]) -> Result<Self, SgError>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        SgTree::try_from_sorted_slices(keys, vals).map(|bst| SgMap { bst })
+    }
+
+    /// Merges a pre-sorted, deduplicated batch of entries into the map, in `O(n + m)` time with a single rebuild.
+    /// Faster than inserting entries one at a time, especially into an empty map or one holding only smaller keys.
+    /// Entries in `sorted` replace existing entries with equal keys.
+    ///
+    /// The batch must be in strictly ascending key order. Anything else is rejected, rather than corrupting the tree.
+    ///
+    /// Returns `Err`, leaving the map unmodified, if:
+    /// * Keys in `sorted` are unsorted or duplicated ([`SgError::KeysNotSorted`]).
+    /// * The merged map would exceed capacity `N` ([`SgError::StackCapacityExceeded`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 5>::from_iter([(1, "a"), (4, "d")]);
+    ///
+    /// assert!(map.extend_sorted(&[(2, "b"), (4, "D"), (5, "e")]).is_ok());
+    /// assert!(map.iter().eq([(&1, &"a"), (&2, &"b"), (&4, &"D"), (&5, &"e")]));
+    ///
+    /// assert_eq!(map.extend_sorted(&[(7, "g"), (6, "f")]), Err(SgError::KeysNotSorted));
+    /// assert_eq!(map.extend_sorted(&[(6, "f"), (7, "g")]), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(map.len(), 4);
+    /// ```
+    pub fn extend_sorted(&mut self, sorted: &[(K, V)]) -> Result<(), SgError>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.bst.try_extend_sorted(sorted)
+    }
+
+    /// Moves all elements into a new map with capacity `M`.
+    /// Since elements are already sorted, the new map's tree is built balanced in a single pass.
+    ///
+    /// Returns `Err` containing the original, unmodified map if `M` is less than the current length
+    /// (or `M` exceeds `u16::MAX`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// map.insert(3, "c");
+    ///
+    /// // Grow
+    /// let map = map.resize::<20>().unwrap();
+    /// assert_eq!(map.capacity(), 20);
+    /// assert_eq!(map[&2], "b");
+    ///
+    /// // Too small, original map returned
+    /// let (map, err) = map.resize::<2>().unwrap_err();
+    /// assert_eq!(err, SgError::StackCapacityExceeded);
+    /// assert_eq!(map.len(), 3);
+    ///
+    /// // Shrink to fit
+    /// let map = map.resize::<3>().unwrap();
+    /// assert!(map.is_full());
+    /// ```
+    pub fn resize<const M: usize>(self) -> Result<SgMap<K, V, M>, (Self, SgError)> {
+        match self.bst.resize::<M>() {
+            Ok(bst) => Ok(SgMap { bst }),
+            Err((bst, err)) => Err((SgMap { bst }, err)),
+        }
+    }
+
+    /// Moves all elements into a new map with capacity `M`, e.g. promoting a nearly full map to the next size tier.
+    /// Since elements are already sorted, the new map's tree is built balanced in a single pass.
+    /// If `M == N`, the map is moved as-is, without a rebuild.
+    ///
+    /// Returns `Err` ([`SgError::StackCapacityExceeded`]) if `M` is less than the current length
+    /// (or [`SgError::MaximumCapacityExceeded`] if `M` exceeds `u16::MAX`).
+    /// Unlike [`resize`][Self::resize], the map is consumed either way, so prefer that method to recover it on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// for i in 0..9 {
+    ///     map.insert(i, i * 10);
+    /// }
+    ///
+    /// // 90% full, promote to the next tier
+    /// let map = map.ensure_capacity::<20>().unwrap();
+    /// assert_eq!(map.capacity(), 20);
+    /// assert_eq!(map[&4], 40);
+    ///
+    /// // Same capacity, moved without a rebuild
+    /// let map = map.ensure_capacity::<20>().unwrap();
+    /// assert_eq!(map.len(), 9);
+    ///
+    /// assert_eq!(
+    ///     map.ensure_capacity::<8>().unwrap_err(),
+    ///     SgError::StackCapacityExceeded
+    /// );
+    /// ```
+    pub fn ensure_capacity<const M: usize>(self) -> Result<SgMap<K, V, M>, SgError> {
+        self.bst.ensure_capacity::<M>().map(|bst| SgMap { bst })
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(3, "c");
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// for (key, value) in map.iter() {
+    ///     println!("{}: {}", key, value);
+    /// }
+    ///
+    /// let (first_key, first_value) = map.iter().next().unwrap();
+    /// assert_eq!((*first_key, *first_value), (1, "a"));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V, N> {
+        Iter::new(self)
+    }
+
+    /// Gets an iterator over owned clones of the map's entries, sorted by key.
+    /// Shorthand for `map.iter().map(|(k, v)| (k.clone(), v.clone()))`, for pipelines that consume owned data
+    /// but only borrow the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, String::from("b")), (1, String::from("a"))]);
+    ///
+    /// let owned: Vec<(u32, String)> = map.cloned_iter().collect();
+    /// assert_eq!(owned, [(1, String::from("a")), (2, String::from("b"))]);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    pub fn cloned_iter(&self) -> impl DoubleEndedIterator<Item = (K, V)> + ExactSizeIterator
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.iter().map(|(k, v)| (k.clone(), v.clone()))
+    }
+
+    /// Gets an iterator over copies of the map's entries, sorted by key.
+    /// Like [`cloned_iter`][crate::map::SgMap::cloned_iter], for [`Copy`] keys and values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(3, 'c'), (1, 'a'), (2, 'b')]);
+    ///
+    /// assert!(map.copied_iter().rev().eq([(3, 'c'), (2, 'b'), (1, 'a')]));
+    /// ```
+    pub fn copied_iter(&self) -> impl DoubleEndedIterator<Item = (K, V)> + ExactSizeIterator
+    where
+        K: Copy,
+        V: Copy,
+    {
+        self.iter().map(|(k, v)| (*k, *v))
+    }
+
+    /// Calls `f` on each entry in ascending key order, stopping at and returning the first `Err`.
+    ///
+    /// A fallible `for_each`, e.g. for custom serialization to a fixed buffer or peripheral without `serde`,
+    /// aborting as soon as a write fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use core::fmt::Write;
+    /// use arrayvec::ArrayString;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, "b"), (1, "a"), (3, "c")]);
+    ///
+    /// let mut buf = ArrayString::<16>::new();
+    /// assert!(map.for_each_sorted(|k, v| write!(buf, "{}={};", k, v)).is_ok());
+    /// assert_eq!(buf.as_str(), "1=a;2=b;3=c;");
+    ///
+    /// // Sink too small, stops at first failure
+    /// let mut small_buf = ArrayString::<6>::new();
+    /// let mut written = 0;
+    /// let res = map.for_each_sorted(|k, v| {
+    ///     write!(small_buf, "{}={};", k, v)?;
+    ///     written += 1;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Err(core::fmt::Error));
+    /// assert_eq!(written, 1);
+    /// ```
+    pub fn for_each_sorted<E, F>(&self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&K, &V) -> Result<(), E>,
+    {
+        self.iter().try_for_each(|(k, v)| f(k, v))
+    }
+
+    /// Folds every entry into an accumulator in ascending key order, stopping at and returning the first `Err`.
+    /// Otherwise returns the final accumulator (`init` for an empty map).
+    ///
+    /// Like `iter().try_fold(..)` without the tuple destructuring, e.g. for a running aggregate that must stop
+    /// on an invalid entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let ledger = SgMap::<_, _, 10>::from_iter([(1, 50), (2, -20), (3, 10)]);
+    /// let balance = ledger.try_fold_entries(0, |bal, _, amt| Ok::<_, u32>(bal + amt));
+    /// assert_eq!(balance, Ok(40));
+    ///
+    /// // Overdraft at entry 2 stops the fold, reporting the offending key
+    /// let ledger = SgMap::<_, _, 10>::from_iter([(1, 50), (2, -80), (3, 100)]);
+    /// let balance = ledger.try_fold_entries(0, |bal, k, amt| match bal + amt {
+    ///     new_bal if new_bal < 0 => Err(*k),
+    ///     new_bal => Ok(new_bal),
+    /// });
+    /// assert_eq!(balance, Err(2));
+    /// ```
+    pub fn try_fold_entries<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &K, &V) -> Result<B, E>,
+    {
+        self.iter().try_fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+
+    /// Like [`try_fold_entries`][crate::map::SgMap::try_fold_entries], but folds in descending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, 'a'), (2, 'b'), (3, 'c')]);
+    ///
+    /// // Collect the newest entries until a limit is hit
+    /// let newest = map.try_rfold_entries(Vec::new(), |mut acc, k, v| match acc.len() < 2 {
+    ///     true => {
+    ///         acc.push((*k, *v));
+    ///         Ok(acc)
+    ///     }
+    ///     false => Err(acc),
+    /// });
+    /// assert_eq!(newest, Err(vec![(3, 'c'), (2, 'b')]));
+    /// ```
+    pub fn try_rfold_entries<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &K, &V) -> Result<B, E>,
+    {
+        self.iter().rev().try_fold(init, |acc, (k, v)| f(acc, k, v))
+    }
+
+    /// Gets a mutable iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert("a", 1);
+    /// map.insert("b", 2);
+    /// map.insert("c", 3);
+    ///
+    /// // Add 10 to the value if the key isn't "a"
+    /// for (key, value) in map.iter_mut() {
+    ///     if key != &"a" {
+    ///         *value += 10;
+    ///     }
+    /// }
+    ///
+    /// let (second_key, second_value) = map.iter().skip(1).next().unwrap();
+    /// assert_eq!((*second_key, *second_value), ("b", 12));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, N> {
+        IterMut::new(self)
+    }
+
+    /// Gets a mutable iterator over the entries of the map, sorted by key, each paired with its sorted rank.
+    /// The rank is the entry's `0`-based position in key order, i.e. the index [`get_index`][crate::map::SgMap::get_index]
+    /// would take, and stays correct when iterating from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut weights = SgMap::<_, _, 10>::from_iter([("c", 0), ("a", 0), ("b", 0)]);
+    ///
+    /// // Position-dependent weights
+    /// for (rank, _, weight) in weights.iter_mut_indexed() {
+    ///     *weight = 1 << rank;
+    /// }
+    /// assert!(weights.into_iter().eq([("a", 1), ("b", 2), ("c", 4)]));
+    /// ```
+    pub fn iter_mut_indexed(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &K, &mut V)> + ExactSizeIterator {
+        self.iter_mut().enumerate().map(|(idx, (k, v))| (idx, k, v))
+    }
+
+    /// Returns a read-only view of the map, forwarding lookup and iteration methods (`get`, `iter`, `range`, `len`,
+    /// `contains_key`, etc.) but none that mutate. Unlike `&SgMap`, the view guarantees immutability at the type level,
+    /// e.g. for handing the map across an API boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use escapegoat::map_types::SgMapView;
+    ///
+    /// fn total(view: SgMapView<'_, &str, u32, 10>) -> u32 {
+    ///     view.values().sum()
+    /// }
+    ///
+    /// let stock = SgMap::<_, _, 10>::from_iter([("apple", 3), ("kiwi", 5)]);
+    /// let view = stock.as_view();
+    ///
+    /// assert_eq!(view.get("kiwi"), Some(&5));
+    /// assert_eq!(view.len(), 2);
+    /// assert_eq!(total(view), 8);
+    /// ```
+    pub fn as_view(&self) -> SgMapView<'_, K, V, N> {
+        SgMapView::new(self)
+    }
+
+    /// Gets an iterator over the map's arena nodes in ascending key order, each a read-only
+    /// [`NodeRef`][crate::map_types::NodeRef] exposing its key, value, and arena slot.
+    /// A stable, documented view for external indexers, e.g. to record per-entry slots in a side table.
+    /// Node links and the free list stay private, and nothing can be mutated through it.
+    ///
+    /// Arena slots are only valid until the map is next mutated: removals free slots and later inserts reuse them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (2, "b")]);
+    /// map.remove(&1);
+    ///
+    /// let nodes: Vec<_> = map.nodes_in_order().collect();
+    /// assert_eq!(nodes.len(), 2);
+    /// assert_eq!((nodes[0].key(), nodes[0].val()), (&2, &"b"));
+    ///
+    /// // Slots are distinct and within capacity, but unrelated to sorted position
+    /// assert!(nodes.iter().all(|n| n.arena_idx() < map.capacity()));
+    /// assert_ne!(nodes[0].arena_idx(), nodes[1].arena_idx());
+    /// ```
+    pub fn nodes_in_order(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = NodeRef<'_, K, V>> + ExactSizeIterator {
+        self.bst.in_order_idxs().into_iter().map(move |idx| {
+            let node = &self.bst.arena[idx];
+            NodeRef::new(node.key(), node.val(), idx)
+        })
+    }
+
+    /// Gets an iterator over each pair of adjacent entries, sorted by key.
+    /// Like slice [`windows(2)`](slice::windows), but over the tree without materializing a slice,
+    /// e.g. for computing deltas between consecutive keys.
+    ///
+    /// Yields nothing if the map has fewer than 2 entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let arrivals = SgMap::<_, _, 10>::from_iter([(10, 'a'), (13, 'b'), (19, 'c')]);
+    ///
+    /// let deltas: Vec<_> = arrivals.windows2().map(|((t0, _), (t1, _))| t1 - t0).collect();
+    /// assert_eq!(deltas, [3, 6]);
+    ///
+    /// assert_eq!(SgMap::<u8, u8, 10>::from_iter([(1, 1)]).windows2().count(), 0);
+    /// ```
+    pub fn windows2(&self) -> impl Iterator<Item = ((&K, &V), (&K, &V))> + Clone {
+        self.iter().zip(self.iter().skip(1))
+    }
+
+    /// Gets an iterator over all contiguous windows of `size` entries, sorted by key.
+    /// Each window is yielded in ascending order. Entry references are buffered in a ring, so the tree is
+    /// traversed once and no slice is materialized.
+    ///
+    /// Yields nothing if the map has fewer than `size` entries.
+    /// For the common pairwise case, [`windows2`][crate::map::SgMap::windows2] avoids the per-window copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    ///
+    /// let mut windows = map.windows(3);
+    /// assert_eq!(windows.next().unwrap().as_slice(), [(&1, &'a'), (&2, &'b'), (&3, &'c')]);
+    /// assert_eq!(windows.next().unwrap().as_slice(), [(&2, &'b'), (&3, &'c'), (&4, &'d')]);
+    /// assert!(windows.next().is_none());
+    ///
+    /// assert_eq!(map.windows(5).count(), 0);
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, K, V, N> {
+        Windows::new(self, size)
+    }
+
+    /// Gets a mutable iterator over the entries of the map, sorted by key, whose items can also remove themselves.
+    /// Lets a single pass mutate some values and delete other entries, based on stateful logic,
+    /// instead of collecting keys to remove in a second pass.
+    ///
+    /// Since each item borrows the iterator, this is a "lending" iterator: drive it with `while let`,
+    /// rather than a `for` loop or iterator adapters.
+    /// Removing an entry keeps the tree valid and doesn't skip its successor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut readings = SgMap::<_, _, 10>::from_iter([(1, 10), (2, 10), (3, 12), (4, 12), (5, 13)]);
+    ///
+    /// // Drop readings equal to their predecessor, scale the rest
+    /// let mut prev = None;
+    /// let mut iter = readings.iter_mut_removable();
+    /// while let Some(mut entry) = iter.next() {
+    ///     let curr = *entry.value();
+    ///     if prev.replace(curr) == Some(curr) {
+    ///         entry.remove();
+    ///     } else {
+    ///         *entry.value_mut() *= 10;
+    ///     }
+    /// }
+    ///
+    /// assert!(readings.into_iter().eq([(1, 100), (3, 120), (5, 130)]));
+    /// ```
+    pub fn iter_mut_removable(&mut self) -> RemovableIterMut<'_, K, V, N> {
+        RemovableIterMut::new(self)
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the key
+    /// was previously in the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// assert_eq!(map.remove_entry(&1), None);
+    /// ```
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove_entry(key)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+    /// The elements are visited in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep only the elements with even-numbered keys.
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.bst.retain(|k, v| f(k, v));
+    }
+
+    /// Retains only the elements specified by the predicate, returning the number of elements removed.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &v)` returns `false`.
+    /// The elements are visited in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep only the elements with even-numbered keys.
+    /// assert_eq!(map.retain_count(|&k, _| k % 2 == 0), 4);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// ```
+    pub fn retain_count<F>(&mut self, f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&K, &V) -> bool,
+    {
+        self.bst.retain_count(f)
+    }
+
+    /// Calls `f` on every entry, ordered by applying `cmp` to values, e.g. to process the lowest-priority entry first.
+    /// Entries with equal values are visited in ascending key order, so the visit order is deterministic.
+    ///
+    /// Unlike [`values_mut`][crate::map::SgMap::values_mut], `f` must not assume key-sorted order.
+    /// Keys are left untouched, so the map stays keyed as before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut tasks = SgMap::<_, _, 10>::from_iter([("build", 2), ("deploy", 3), ("lint", 1), ("test", 2)]);
+    /// let mut order = Vec::new();
+    /// let mut rank = 0;
+    /// tasks.for_each_value_by(|a, b| a.cmp(b), |name, priority| {
+    ///     order.push(*name);
+    ///     rank += 1;
+    ///     *priority = rank;
+    /// });
+    ///
+    /// assert_eq!(order, ["lint", "build", "test", "deploy"]);
+    /// assert_eq!(tasks["deploy"], 4);
+    /// ```
+    pub fn for_each_value_by<C, F>(&mut self, cmp: C, f: F)
+    where
+        K: Ord,
+        C: FnMut(&V, &V) -> Ordering,
+        F: FnMut(&K, &mut V),
+    {
+        self.bst.for_each_value_by(cmp, f);
+    }
+
+    /// Splits the collection into two at the given key. Returns everything after the given key,
+    /// including the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "b");
+    /// a.insert(3, "c");
+    /// a.insert(17, "d");
+    /// a.insert(41, "e");
+    ///
+    /// let b = a.split_off(&3);
+    ///
+    /// assert_eq!(a.len(), 2);
+    /// assert_eq!(b.len(), 3);
+    ///
+    /// assert_eq!(a[&1], "a");
+    /// assert_eq!(a[&2], "b");
+    ///
+    /// assert_eq!(b[&3], "c");
+    /// assert_eq!(b[&17], "d");
+    /// assert_eq!(b[&41], "e");
+    /// ```
+    pub fn split_off<Q>(&mut self, key: &Q) -> SgMap<K, V, N>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        SgMap {
+            bst: self.bst.split_off(key),
+        }
+    }
+
+    /// Consumes the map, splitting it into `(less_than_key, greater_or_equal_to_key)`.
+    /// Both halves keep capacity `N`, so this can't overflow.
+    /// Unlike [`split_off`][crate::map::SgMap::split_off], each half is built balanced directly from its sorted partition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "c"), (17, "d"), (41, "e")]);
+    /// let (lo, hi) = map.split_at_key(&3);
+    ///
+    /// assert!(lo.into_iter().eq([(1, "a"), (2, "b")]));
+    /// assert!(hi.into_iter().eq([(3, "c"), (17, "d"), (41, "e")]));
+    /// ```
+    pub fn split_at_key<Q>(self, key: &Q) -> (SgMap<K, V, N>, SgMap<K, V, N>)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let (lt_bst, ge_bst) = self.bst.split_at_key(key);
+        (SgMap { bst: lt_bst }, SgMap { bst: ge_bst })
+    }
+
+    /// Returns a new map holding clones of the entries within `range`, leaving `self` unchanged.
+    /// A non-destructive counterpart to [`split_off`][crate::map::SgMap::split_off], e.g. for windowed snapshots.
+    /// Since the range is already sorted, the new map's tree is built balanced in a single pass.
+    /// It keeps `self`'s rebalance parameter.
+    ///
+    /// Returns `Err` if the range holds more than `N` entries, which can't happen for a range of `self`
+    /// but keeps the signature consistent with other fallible constructors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// // Readings keyed by timestamp
+    /// let readings = SgMap::<_, _, 10>::from_iter([(100, 1.5), (160, 2.0), (230, 2.5), (300, 3.0)]);
+    ///
+    /// let window = readings.subrange(150..=250).unwrap();
+    /// assert!(window.into_iter().eq([(160, 2.0), (230, 2.5)]));
+    /// assert_eq!(readings.len(), 4);
+    /// ```
+    pub fn subrange<T, R>(&self, range: R) -> Result<SgMap<K, V, N>, SgError>
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord + Clone,
+        V: Clone,
+        R: RangeBounds<T>,
+    {
+        self.bst.subrange(&range).map(|bst| SgMap { bst })
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove(key)
+    }
+
+    /// Removes a key from the map, exactly like [`remove`][crate::map::SgMap::remove].
+    /// Additionally returns `true` if this removal triggered a rebuild (an `O(n)` rebalance of the whole tree,
+    /// done once the live entry count drops below half of the size tracked since the last full rebuild).
+    ///
+    /// The removal counterpart of [`insert_tracked`][crate::map::SgMap::insert_tracked], for the same
+    /// latency attribution use case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 100>::from_iter((0..100).map(|i| (i, i)));
+    ///
+    /// // Removing over half the entries forces a rebuild
+    /// let first_rebuild = (0..100).find(|i| map.remove_tracked(i).1);
+    /// assert_eq!(first_rebuild, Some(50));
+    /// assert_eq!(map.len(), 49);
+    ///
+    /// // Removing an absent key never rebuilds
+    /// assert_eq!(map.remove_tracked(&0), (None, false));
+    /// ```
+    pub fn remove_tracked<Q>(&mut self, key: &Q) -> (Option<V>, bool)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove_tracked(key)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    ///
+    /// The supplied key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get_key_value(&1), Some((&1, &"a")));
+    /// assert_eq!(map.get_key_value(&2), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_key_value(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key, or `default` if the key isn't present.
+    /// The map is never modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([("timeout", 30)]);
+    /// assert_eq!(map.get_or("timeout", &60), &30);
+    /// assert_eq!(map.get_or("retries", &3), &3);
+    /// assert!(!map.contains_key("retries"));
+    /// ```
+    pub fn get_or<'a, Q>(&'a self, key: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get(key).unwrap_or(default)
+    }
+
+    /// Returns a clone of the value corresponding to the key, or `default` if the key isn't present.
+    /// The map is never modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, String::from("a"))]);
+    /// assert_eq!(map.get_cloned_or(&1, String::new()), "a");
+    /// assert_eq!(map.get_cloned_or(&2, String::from("none")), "none");
+    /// ```
+    pub fn get_cloned_or<Q>(&self, key: &Q, default: V) -> V
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        V: Clone,
+    {
+        self.bst.get(key).cloned().unwrap_or(default)
+    }
+
+    // Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_mut(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key, marking the entry as most recently used.
+    /// Requires the `access_tracking` feature.
+    ///
+    /// Only `get_touch` and inserts (new or updating) advance an entry's recency.
+    /// Plain lookups like [`get`][Self::get] and [`get_mut`][Self::get_mut] leave it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut cache = SgMap::<_, _, 10>::new();
+    /// cache.insert(1, "a");
+    /// cache.insert(2, "b");
+    ///
+    /// assert_eq!(cache.get_touch(&1), Some(&"a"));
+    /// assert_eq!(cache.get_touch(&3), None);
+    ///
+    /// // Key `2` is now the least recently used
+    /// assert_eq!(cache.evict_lru(), Some((2, "b")));
+    /// ```
+    #[cfg(feature = "access_tracking")]
+    pub fn get_touch<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_touch(key)
+    }
+
+    /// Removes and returns the least recently used entry, e.g. to make room in a full cache.
+    /// Requires the `access_tracking` feature.
+    ///
+    /// Entries that were never individually inserted or touched (e.g. bulk-built from sorted input) are the oldest.
+    /// Ties go to the smallest key. Finding the entry is a linear scan, so `O(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut cache = SgMap::<_, _, 3>::new();
+    /// cache.insert("x", 1);
+    /// cache.insert("y", 2);
+    /// cache.insert("z", 3);
+    /// cache.get_touch(&"x");
+    ///
+    /// if cache.is_full() {
+    ///     assert_eq!(cache.evict_lru(), Some(("y", 2)));
+    /// }
+    /// cache.insert("w", 4);
+    ///
+    /// assert_eq!(cache.evict_lru(), Some(("z", 3)));
+    /// assert_eq!(cache.evict_lru(), Some(("x", 1)));
+    /// assert_eq!(cache.evict_lru(), Some(("w", 4)));
+    /// assert_eq!(cache.evict_lru(), None);
+    /// ```
+    #[cfg(feature = "access_tracking")]
+    pub fn evict_lru(&mut self) -> Option<(K, V)> {
+        self.bst.evict_lru()
+    }
+
+    /// Returns the stored key and a mutable reference to its value, in a single lookup.
+    ///
+    /// Useful when the stored key can differ from the lookup key while comparing equal
+    /// (e.g. a custom `Ord` that ignores some fields), to read the canonical key while updating its value.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// if let Some((k, v)) = map.get_key_value_mut(&1) {
+    ///     assert_eq!(*k, 1);
+    ///     *v = "b";
+    /// }
+    /// assert_eq!(map[&1], "b");
+    /// assert_eq!(map.get_key_value_mut(&2), None);
+    /// ```
+    pub fn get_key_value_mut<Q>(&mut self, key: &Q) -> Option<(&K, &mut V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_key_value_mut(key)
+    }
+
+    /// Swaps the values associated with keys `a` and `b`, leaving both keys in place.
+    /// Returns `true` only if both keys exist, otherwise the map is left unchanged.
+    /// Swapping a key with itself is a successful no-op.
+    ///
+    /// Cheaper than reading, cloning, and re-inserting both values: each key is looked up once and the values
+    /// are exchanged in their existing nodes, so `V` needn't be [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut slots = SgMap::<_, _, 10>::from_iter([(1, "red"), (2, "green"), (3, "blue")]);
+    ///
+    /// assert!(slots.swap_values(&1, &3));
+    /// assert!(slots.iter().eq([(&1, &"blue"), (&2, &"green"), (&3, &"red")]));
+    ///
+    /// // Missing key, nothing changes
+    /// assert!(!slots.swap_values(&2, &4));
+    /// assert_eq!(slots[&2], "green");
+    /// ```
+    pub fn swap_values<Q>(&mut self, a: &Q, b: &Q) -> bool
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.swap_values(a, b)
+    }
+
+    /// Modifies the key matching `key` in place, provided the updated key still sorts strictly between
+    /// its predecessor and successor. Cheaper than a [`remove`][crate::map::SgMap::remove] followed by an
+    /// [`insert`][crate::map::SgMap::insert], which may rebalance twice, e.g. for monotone bumps of a
+    /// version or timestamp component of the key.
+    ///
+    /// `f` runs on a clone of the stored key, which only replaces the original once the new order is verified.
+    ///
+    /// Returns `Ok(true)` if the key was updated, `Ok(false)` if `key` isn't present, and
+    /// `Err(SgError::OrderViolation)`, leaving the map unchanged, if the updated key would be out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgMap};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(10, "a"), (20, "b"), (30, "c")]);
+    ///
+    /// assert_eq!(map.update_key_in_place(&20, |k| *k += 5), Ok(true));
+    /// assert!(map.keys().eq(&[10, 25, 30]));
+    ///
+    /// // Would overtake the successor
+    /// assert_eq!(map.update_key_in_place(&25, |k| *k = 35), Err(SgError::OrderViolation));
+    /// assert_eq!(map.get(&25), Some(&"b"));
+    ///
+    /// assert_eq!(map.update_key_in_place(&99, |k| *k += 1), Ok(false));
+    /// ```
+    pub fn update_key_in_place<Q, F>(&mut self, key: &Q, f: F) -> Result<bool, SgError>
+    where
+        K: Borrow<Q> + Ord + Clone,
+        Q: Ord + ?Sized,
+        F: FnOnce(&mut K),
+    {
+        self.bst.update_key_in_place(key, f)
+    }
+
+    /// Initializes every arena slot up front, for deterministic insert timing afterwards.
+    ///
+    /// Arena slots are initialized lazily, the first time an insert needs one, so early inserts are the first to
+    /// write (and, on targets with caches or paged memory, the first to touch) that part of the map's storage.
+    /// Calling this once, e.g. during startup, moves that cost out of latency-sensitive code paths.
+    /// The gain is target-dependent: on desktop hosts, the `sgs_first_insert_*` benchmarks show no measurable difference.
+    /// Contents, capacity, and the order slots are handed out in are unchanged. Calling it again is a no-op.
+    ///
+    /// Operations that reset the map (e.g. [`clear`][SgMap::clear]) return its arena to the lazily initialized state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<u32, u32, 64>::new();
+    /// map.prewarm();
+    /// assert_eq!(map.debug_stats().free, 64);
+    ///
+    /// map.insert(1, 10);
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(map.debug_stats().free, 63);
+    /// ```
+    pub fn prewarm(&mut self) {
+        self.bst.prewarm()
+    }
+
+    /// Clears the map, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.clear();
+    /// assert!(a.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.bst.clear()
+    }
+
+    /// Removes every entry whose key is yielded by `keys`, returning the number of entries removed.
+    /// Keys not in the map are ignored.
+    ///
+    /// Rather than descending once per key, the map is merged against `keys` in a single pass and
+    /// rebuilt once. This is fastest when `keys` are in ascending order; unsorted input is still
+    /// handled correctly, but each out-of-order key costs an `O(log n)` search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter((0..8).map(|i| (i, i * 10)));
+    /// assert_eq!(map.remove_many(&[1, 3, 5, 9]), 3);
+    /// assert!(map.keys().eq([0, 2, 4, 6, 7].iter()));
+    /// ```
+    pub fn remove_many<'a, Q, I>(&mut self, keys: I) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        self.bst.remove_many(keys)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.contains_key(&1), true);
+    /// assert_eq!(map.contains_key(&2), false);
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.contains_key(key)
+    }
+
+    /// Returns `true` if both maps contain exactly the same keys, regardless of their values.
+    /// The value types may differ. Runs in `O(n)` time, as a single pass over both sorted key sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let names = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+    /// let mut scores = SgMap::<_, _, 10>::from_iter([(3, 30), (1, 10), (2, 20)]);
+    /// assert!(names.same_keys(&scores));
+    ///
+    /// scores.remove(&2);
+    /// scores.insert(4, 40);
+    /// assert!(!names.same_keys(&scores));
+    /// ```
+    pub fn same_keys<V2>(&self, other: &SgMap<K, V2, N>) -> bool
+    where
+        K: Ord,
+    {
+        self.len() == other.len() && self.keys().eq(other.keys())
+    }
+
+    /// Returns an iterator over the entries of `self` whose keys are also present in `other`, in ascending key order.
+    /// The value types may differ, only `self`'s values are yielded.
+    ///
+    /// Runs in `O(n + m)` time, as a single merge over both sorted key sequences,
+    /// instead of a [`contains_key`][crate::map::SgMap::contains_key] lookup per entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let prices = SgMap::<_, _, 10>::from_iter([("apple", 3), ("kiwi", 5), ("pear", 4)]);
+    /// let in_stock = SgMap::<_, _, 10>::from_iter([("kiwi", 12), ("pear", 0), ("plum", 7)]);
+    ///
+    /// assert!(prices.intersection_entries(&in_stock).eq([(&"kiwi", &5), (&"pear", &4)]));
+    /// ```
+    pub fn intersection_entries<'a, V2>(
+        &'a self,
+        other: &'a SgMap<K, V2, N>,
+    ) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: Ord,
+    {
+        let mut other_keys = other.keys().peekable();
+        self.iter().filter(move |(k, _)| {
+            while other_keys.next_if(|other_k| other_k < k).is_some() {}
+            other_keys.peek() == Some(k)
+        })
+    }
+
+    /// Returns `true` if the map contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// assert!(a.is_empty());
+    /// a.insert(1, "a");
+    /// assert!(!a.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.bst.is_empty()
+    }
+
+    /// Returns `true` if the map's capacity is filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 2>::new();
+    /// a.insert(1, "a");
+    /// assert!(!a.is_full());
+    /// a.insert(2, "b");
+    /// assert!(a.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.bst.is_full()
+    }
+
+    /// Returns the entries bracketing `key`: the largest entry with a key `<= key`,
+    /// and the smallest entry with a key `> key`. Both are found in a single descent.
+    ///
+    /// An exact match is returned as the lower bracket, paired with its successor.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(10, 1.0), (20, 2.0), (30, 4.0)]);
+    ///
+    /// assert_eq!(map.bracket(&25), (Some((&20, &2.0)), Some((&30, &4.0))));
+    /// assert_eq!(map.bracket(&20), (Some((&20, &2.0)), Some((&30, &4.0))));
+    /// assert_eq!(map.bracket(&5), (None, Some((&10, &1.0))));
+    /// assert_eq!(map.bracket(&35), (Some((&30, &4.0)), None));
+    ///
+    /// // Linear interpolation
+    /// if let (Some((&x0, &y0)), Some((&x1, &y1))) = map.bracket(&25) {
+    ///     let y = y0 + (y1 - y0) * f64::from(25 - x0) / f64::from(x1 - x0);
+    ///     assert_eq!(y, 3.0);
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn bracket<Q>(&self, key: &Q) -> (Option<(&K, &V)>, Option<(&K, &V)>)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.bracket(key)
+    }
+
+    /// Returns the entry with the largest key strictly less than `key`, or `None` if there isn't one.
+    /// Unlike [`bracket`][crate::map::SgMap::bracket], an exact match is skipped, and `key` needn't be present.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(10, "a"), (20, "b"), (30, "c")]);
+    ///
+    /// assert_eq!(map.prev_entry(&20), Some((&10, &"a")));
+    /// assert_eq!(map.prev_entry(&25), Some((&20, &"b")));
+    /// assert_eq!(map.prev_entry(&10), None);
+    /// ```
+    pub fn prev_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.prev_entry(key)
+    }
+
+    /// Returns the entry with the smallest key strictly greater than `key`, or `None` if there isn't one.
+    /// Like the upper half of [`bracket`][crate::map::SgMap::bracket], `key` needn't be present.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(10, "a"), (20, "b"), (30, "c")]);
+    ///
+    /// // Walk forward from an absent key
+    /// let mut key = 5;
+    /// let mut walked = Vec::new();
+    /// while let Some((&k, &v)) = map.next_entry(&key) {
+    ///     walked.push(v);
+    ///     key = k;
+    /// }
+    /// assert_eq!(walked, ["a", "b", "c"]);
+    /// ```
+    pub fn next_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.next_entry(key)
+    }
+
+    /// Returns the entry whose key is nearest to `key`, as measured by `dist`, or `None` if the map is empty.
+    /// Only the two entries [`bracket`][crate::map::SgMap::bracket]ing `key` are candidates, so this is a single
+    /// descent plus one distance comparison. On a tie, the smaller key wins.
+    ///
+    /// `dist` should grow as keys move away from `key` in either direction (e.g. absolute difference),
+    /// otherwise the nearest entry may not be one of the two candidates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// // Sensor samples keyed by timestamp
+    /// let samples = SgMap::<u64, f32, 10>::from_iter([(100, 20.5), (160, 21.0), (230, 21.5)]);
+    ///
+    /// let dist = |t: &u64, q: &u64| t.abs_diff(*q);
+    /// assert_eq!(samples.nearest_by(&150, dist), Some((&160, &21.0)));
+    /// assert_eq!(samples.nearest_by(&130, dist), Some((&100, &20.5))); // Tie, earlier sample wins
+    /// assert_eq!(samples.nearest_by(&999, dist), Some((&230, &21.5)));
+    /// ```
+    pub fn nearest_by<Q, D, F>(&self, key: &Q, dist: F) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        D: Ord,
+        F: Fn(&K, &Q) -> D,
+    {
+        match self.bst.bracket(key) {
+            (Some(lower), Some(upper)) => match dist(upper.0, key) < dist(lower.0, key) {
+                true => Some(upper),
+                false => Some(lower),
+            },
+            (opt_lower, opt_upper) => opt_lower.or(opt_upper),
+        }
+    }
+
+    /// Gets an iterator over up to `k` entries nearest to `key`, as measured by `dist`, in ascending distance order.
+    /// Generalizes [`nearest_by`][crate::map::SgMap::nearest_by]: starting from the two entries bracketing `key`,
+    /// each step yields the nearer of the next candidates below and above, then advances that side.
+    /// On a distance tie, the smaller key is yielded first.
+    ///
+    /// A single descent toward `key` seeds two in-order cursors, one walking down and one walking up, and each
+    /// step advances one of them in amortized `O(1)` time. Taking `k` entries costs `O(log n + k)`.
+    /// As with `nearest_by`, `dist` should grow as keys move away from `key` in either direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// // Sensor samples keyed by timestamp
+    /// let samples = SgMap::<u64, f32, 10>::from_iter([(100, 20.5), (160, 21.0), (230, 21.5), (300, 22.0)]);
+    ///
+    /// let dist = |t: &u64, q: &u64| t.abs_diff(*q);
+    /// let nearby: Vec<_> = samples.k_nearest_by(&210, 3, dist).map(|(t, _)| *t).collect();
+    /// assert_eq!(nearby, [230, 160, 300]);
+    ///
+    /// // Tie, earlier sample first
+    /// let nearby: Vec<_> = samples.k_nearest_by(&130, 2, dist).map(|(t, _)| *t).collect();
+    /// assert_eq!(nearby, [100, 160]);
+    /// ```
+    pub fn k_nearest_by<Q, D, F>(
+        &self,
+        key: &Q,
+        k: usize,
+        dist: F,
+    ) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        D: Ord,
+        F: Fn(&K, &Q) -> D,
+    {
+        self.bst.k_nearest_by(key, k, dist)
+    }
+
+    /// Returns a reference to the first key-value pair in the map.
+    /// The key in this pair is the minimum key in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.first_key_value(), None);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.first_key_value(), Some((&1, &"b")));
+    /// ```
+    pub fn first_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        self.bst.first_key_value()
+    }
+
+    /// Returns a reference to the first/minium key in the map, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.first_key_value(), None);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.first_key(), Some(&1));
+    /// ```
+    pub fn first_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.bst.first_key()
+    }
+
+    /// Removes and returns the first element in the map.
+    /// The key of this element is the minimum key that was in the map.
+    ///
+    /// # Examples
+    ///
+    /// Draining elements in ascending order, while keeping a usable map each iteration.
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// while let Some((key, _val)) = map.pop_first() {
+    ///     assert!((&map).into_iter().all(|(k, _v)| *k > key));
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.bst.pop_first()
+    }
+
+    /// Removes and returns the first element in the map, only if `pred` returns `true` for it.
+    /// Otherwise the map is left unchanged and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// Popping scheduled work while the earliest deadline has passed.
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut timers = SgMap::<_, _, 10>::from_iter([(5, "a"), (10, "b"), (15, "c")]);
+    /// let now = 10;
+    /// let mut ready = Vec::new();
+    /// while let Some((_deadline, task)) = timers.pop_first_if(|deadline, _| *deadline <= now) {
+    ///     ready.push(task);
+    /// }
+    /// assert_eq!(ready, ["a", "b"]);
+    /// assert_eq!(timers.first_key(), Some(&15));
+    /// ```
+    pub fn pop_first_if<F>(&mut self, pred: F) -> Option<(K, V)>
+    where
+        K: Ord,
+        F: FnOnce(&K, &V) -> bool,
+    {
+        self.bst.pop_first_if(pred)
+    }
+
+    /// Consumes the map, returning its first (minimum key) entry and a map of the remaining entries,
+    /// or `None` if the map is empty. The remainder is the same map with one entry popped, so no rebuild is needed.
+    ///
+    /// Suits "process the head, recurse on the tail" decomposition.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(3, "c");
-    /// map.insert(2, "b");
-    /// map.insert(1, "a");
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, "b"), (1, "a"), (3, "c")]);
     ///
-    /// for (key, value) in map.iter() {
-    ///     println!("{}: {}", key, value);
-    /// }
+    /// let ((k, v), rest) = map.split_first().unwrap();
+    /// assert_eq!((k, v), (1, "a"));
+    /// assert!(rest.into_iter().eq([(2, "b"), (3, "c")]));
+    /// ```
+    pub fn split_first(mut self) -> Option<((K, V), SgMap<K, V, N>)> {
+        let first = self.pop_first()?;
+        Some((first, self))
+    }
+
+    /// Returns a reference to the last key-value pair in the map.
+    /// The key in this pair is the maximum key in the map.
+    ///
+    /// # Examples
     ///
-    /// let (first_key, first_value) = map.iter().next().unwrap();
-    /// assert_eq!((*first_key, *first_value), (1, "a"));
     /// ```
-    pub fn iter(&self) -> Iter<'_, K, V, N> {
-        Iter::new(self)
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.last_key_value(), Some((&2, &"a")));
+    /// ```
+    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        self.bst.last_key_value()
     }
 
-    /// Gets a mutable iterator over the entries of the map, sorted by key.
+    /// Returns a reference to the last/maximum key in the map, if any.
     ///
     /// # Examples
     ///
@@ -511,402 +2633,617 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
     /// use escapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert("a", 1);
-    /// map.insert("b", 2);
-    /// map.insert("c", 3);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.last_key(), Some(&2));
+    /// ```
+    pub fn last_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.bst.last_key()
+    }
+
+    /// Removes and returns the last element in the map.
+    /// The key of this element is the maximum key that was in the map.
     ///
-    /// // Add 10 to the value if the key isn't "a"
-    /// for (key, value) in map.iter_mut() {
-    ///     if key != &"a" {
-    ///         *value += 10;
-    ///     }
+    /// # Examples
+    ///
+    /// Draining elements in descending order, while keeping a usable map each iteration.
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// while let Some((key, _val)) = map.pop_last() {
+    ///     assert!((&map).into_iter().all(|(k, _v)| *k < key));
     /// }
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.bst.pop_last()
+    }
+
+    /// Removes and returns the last element in the map, only if `pred` returns `true` for it.
+    /// Otherwise the map is left unchanged and `None` is returned.
+    ///
+    /// # Examples
     ///
-    /// let (second_key, second_value) = map.iter().skip(1).next().unwrap();
-    /// assert_eq!((*second_key, *second_value), ("b", 12));
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, K, V, N> {
-        IterMut::new(self)
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b")]);
+    /// assert_eq!(map.pop_last_if(|k, _| *k > 2), None);
+    /// assert_eq!(map.pop_last_if(|_, v| *v == "b"), Some((2, "b")));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn pop_last_if<F>(&mut self, pred: F) -> Option<(K, V)>
+    where
+        K: Ord,
+        F: FnOnce(&K, &V) -> bool,
+    {
+        self.bst.pop_last_if(pred)
     }
 
-    /// Removes a key from the map, returning the stored key and value if the key
-    /// was previously in the map.
+    /// Consumes the map, returning its last (maximum key) entry and a map of the remaining entries,
+    /// or `None` if the map is empty. See [`split_first`][crate::map::SgMap::split_first].
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, "b"), (1, "a"), (3, "c")]);
+    ///
+    /// let ((k, v), rest) = map.split_last().unwrap();
+    /// assert_eq!((k, v), (3, "c"));
+    /// assert!(rest.into_iter().eq([(1, "a"), (2, "b")]));
+    /// ```
+    pub fn split_last(mut self) -> Option<((K, V), SgMap<K, V, N>)> {
+        let last = self.pop_last()?;
+        Some((last, self))
+    }
+
+    /// Keeps the `k` smallest entries, removing the rest. Does nothing if `k` is at least [`len`][crate::map::SgMap::len].
+    ///
+    /// Unlike calling [`pop_last`][crate::map::SgMap::pop_last] in a loop, the surplus is removed in one pass
+    /// and the survivors are rebuilt once, into a balanced tree. Useful for capping the size of a sorted cache.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
-    /// assert_eq!(map.remove_entry(&1), None);
+    /// let mut cache = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (4, "d"), (2, "b")]);
+    /// cache.truncate_first(2);
+    /// assert!(cache.into_iter().eq([(1, "a"), (2, "b")]));
     /// ```
-    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    pub fn truncate_first(&mut self, k: usize)
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        K: Ord,
     {
-        self.bst.remove_entry(key)
+        self.bst.truncate_first(k)
     }
 
-    /// Retains only the elements specified by the predicate.
+    /// Keeps the `k` largest entries, removing the rest. Does nothing if `k` is at least [`len`][crate::map::SgMap::len].
     ///
-    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
-    /// The elements are visited in ascending key order.
+    /// Unlike calling [`pop_first`][crate::map::SgMap::pop_first] in a loop, the surplus is removed in one pass
+    /// and the survivors are rebuilt once, into a balanced tree. Useful for capping the size of a sorted cache.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
-    /// // Keep only the elements with even-numbered keys.
-    /// map.retain(|&k, _| k % 2 == 0);
-    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// let mut cache = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (4, "d"), (2, "b")]);
+    /// cache.truncate_last(2);
+    /// assert!(cache.into_iter().eq([(3, "c"), (4, "d")]));
     /// ```
-    pub fn retain<F>(&mut self, mut f: F)
+    pub fn truncate_last(&mut self, k: usize)
     where
         K: Ord,
-        F: FnMut(&K, &mut V) -> bool,
     {
-        self.bst.retain(|k, v| f(k, v));
+        self.bst.truncate_last(k)
     }
 
-    /// Splits the collection into two at the given key. Returns everything after the given key,
-    /// including the key.
+    /// Returns an iterator over up to `k` of the smallest entries in the map, in ascending order.
+    /// Traversal stops after `k` entries, and a `k` greater than [`len`][crate::map::SgMap::len] yields every entry.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "a");
-    /// a.insert(2, "b");
-    /// a.insert(3, "c");
-    /// a.insert(17, "d");
-    /// a.insert(41, "e");
+    /// let map = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (4, "d"), (2, "b")]);
+    /// assert!(map.first_n(2).eq([(&1, &"a"), (&2, &"b")]));
+    /// assert_eq!(map.first_n(10).count(), 4);
+    /// ```
+    pub fn first_n(&self, k: usize) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Ord,
+    {
+        self.bst.first_n(k)
+    }
+
+    /// Returns an iterator over up to `k` of the largest entries in the map, in ascending order.
+    /// Traversal stops after `k` entries, and a `k` greater than [`len`][crate::map::SgMap::len] yields every entry.
     ///
-    /// let b = a.split_off(&3);
+    /// # Examples
     ///
-    /// assert_eq!(a.len(), 2);
-    /// assert_eq!(b.len(), 3);
+    /// ```
+    /// use escapegoat::SgMap;
     ///
-    /// assert_eq!(a[&1], "a");
-    /// assert_eq!(a[&2], "b");
+    /// let map = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (4, "d"), (2, "b")]);
+    /// assert!(map.last_n(2).eq([(&3, &"c"), (&4, &"d")]));
+    /// assert_eq!(map.last_n(10).count(), 4);
+    /// ```
+    pub fn last_n(&self, k: usize) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Ord,
+    {
+        self.bst.last_n(k)
+    }
+
+    /// Returns an iterator yielding `(bucket, count)` for each maximal run of consecutive entries, in key order,
+    /// whose keys map to the same bucket under `key_fn`. Useful for range-bucketed aggregation without allocation.
+    ///
+    /// Runs of a bucket are only contiguous if `key_fn` is monotone in the key.
+    /// A non-monotone bucket function can produce multiple runs (and thus multiple yielded pairs) per bucket.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(b[&3], "c");
-    /// assert_eq!(b[&17], "d");
-    /// assert_eq!(b[&41], "e");
     /// ```
-    pub fn split_off<Q>(&mut self, key: &Q) -> SgMap<K, V, N>
+    /// use escapegoat::SgMap;
+    ///
+    /// let latencies = SgMap::<u32, &str, 10>::from_iter([(3, "a"), (7, "b"), (12, "c"), (15, "d"), (31, "e")]);
+    ///
+    /// // Histogram by decade
+    /// assert!(latencies.group_runs(|ms| ms / 10).eq([(0, 2), (1, 2), (3, 1)]));
+    ///
+    /// // Parity isn't monotone, so each bucket appears in several runs
+    /// assert!(latencies.group_runs(|ms| ms % 2).eq([(1, 2), (0, 1), (1, 2)]));
+    /// ```
+    pub fn group_runs<B, F>(&self, key_fn: F) -> impl Iterator<Item = (B, usize)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        K: Ord,
+        B: PartialEq,
+        F: FnMut(&K) -> B,
     {
-        SgMap {
-            bst: self.bst.split_off(key),
-        }
+        group_runs(self.keys().map(key_fn))
     }
 
-    /// Removes a key from the map, returning the value at the key if the key
-    /// was previously in the map.
+    /// Returns the entry with the minimum value, or `None` if the map is empty.
+    /// Ties are broken by key order: the first (smallest key) entry with the minimum value wins.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Runs in `O(n)` time, since entries are ordered by key, not value.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.remove(&1), Some("a"));
-    /// assert_eq!(map.remove(&1), None);
+    /// let map = SgMap::<_, _, 10>::from_iter([("c", 1), ("a", 3), ("d", 1), ("b", 2)]);
+    /// assert_eq!(map.min_by_value(), Some((&"c", &1)));
     /// ```
-    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    pub fn min_by_value(&self) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        V: Ord,
     {
-        self.bst.remove(key)
+        self.min_by_value_with(V::cmp)
     }
 
-    /// Returns the key-value pair corresponding to the supplied key.
+    /// Returns the entry with the maximum value, or `None` if the map is empty.
+    /// Ties are broken by key order: the first (smallest key) entry with the maximum value wins.
     ///
-    /// The supplied key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Runs in `O(n)` time, since entries are ordered by key, not value.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.get_key_value(&1), Some((&1, &"a")));
-    /// assert_eq!(map.get_key_value(&2), None);
+    /// let map = SgMap::<_, _, 10>::from_iter([("c", 3), ("a", 1), ("d", 3), ("b", 2)]);
+    /// assert_eq!(map.max_by_value(), Some((&"c", &3)));
     /// ```
-    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    pub fn max_by_value(&self) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        V: Ord,
     {
-        self.bst.get_key_value(key)
+        self.max_by_value_with(V::cmp)
     }
 
-    /// Returns a reference to the value corresponding to the key.
+    /// Returns the entry with the minimum value with respect to the comparison function `cmp`,
+    /// or `None` if the map is empty. Useful for value types that aren't `Ord`, like floats.
+    /// Ties are broken by key order: the first (smallest key) entry with the minimum value wins.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Runs in `O(n)` time, since entries are ordered by key, not value.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.get(&1), Some(&"a"));
-    /// assert_eq!(map.get(&2), None);
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, 2.5), (2, -1.0), (3, -1.0)]);
+    /// assert_eq!(map.min_by_value_with(f64::total_cmp), Some((&2, &-1.0)));
     /// ```
-    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    pub fn min_by_value_with<F>(&self, mut cmp: F) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        F: FnMut(&V, &V) -> Ordering,
     {
-        self.bst.get(key)
+        self.iter().reduce(|best, curr| match cmp(curr.1, best.1) {
+            Ordering::Less => curr,
+            Ordering::Equal | Ordering::Greater => best,
+        })
     }
 
-    // Returns a mutable reference to the value corresponding to the key.
+    /// Returns the entry with the maximum value with respect to the comparison function `cmp`,
+    /// or `None` if the map is empty. Useful for value types that aren't `Ord`, like floats.
+    /// Ties are broken by key order: the first (smallest key) entry with the maximum value wins.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
+    /// Runs in `O(n)` time, since entries are ordered by key, not value.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// if let Some(x) = map.get_mut(&1) {
-    ///     *x = "b";
-    /// }
-    /// assert_eq!(map[&1], "b");
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, 2.5), (2, 7.0), (3, 7.0)]);
+    /// assert_eq!(map.max_by_value_with(f64::total_cmp), Some((&2, &7.0)));
     /// ```
-    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    pub fn max_by_value_with<F>(&self, mut cmp: F) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        F: FnMut(&V, &V) -> Ordering,
     {
-        self.bst.get_mut(key)
+        self.iter().reduce(|best, curr| match cmp(curr.1, best.1) {
+            Ordering::Greater => curr,
+            Ordering::Equal | Ordering::Less => best,
+        })
     }
 
-    /// Clears the map, removing all elements.
+    /// Returns an iterator over every key whose value equals `value`, in ascending key order.
+    ///
+    /// This inverse lookup scans all entries, so it's `O(n)`. It covers the occasional reverse query on small maps,
+    /// but for frequent ones a second map from value to key is preferable.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// a.insert(1, "a");
-    /// a.clear();
-    /// assert!(a.is_empty());
+    /// let owners = SgMap::<_, _, 10>::from_iter([(4, "bob"), (1, "ann"), (3, "ann"), (2, "cat")]);
+    /// assert!(owners.keys_for_value(&"ann").eq([&1, &3]));
+    /// assert_eq!(owners.keys_for_value(&"dan").count(), 0);
+    /// ```
+    pub fn keys_for_value<'a>(&'a self, value: &V) -> impl Iterator<Item = &'a K>
+    where
+        V: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(k, _)| k)
+    }
+
+    /// Returns the smallest key whose value equals `value`, or `None` if there isn't one.
+    /// Like [`keys_for_value`][crate::map::SgMap::keys_for_value], but stops scanning at the first match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let codes = SgMap::<_, _, 10>::from_iter([("de", 49), ("fr", 33), ("ch", 41)]);
+    /// assert_eq!(codes.any_key_for_value(&33), Some(&"fr"));
+    /// assert_eq!(codes.any_key_for_value(&1), None);
+    /// ```
+    pub fn any_key_for_value(&self, value: &V) -> Option<&K>
+    where
+        V: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over each value that appears more than once in the map, with its number of occurrences.
+    /// Values are yielded in ascending order. Useful for checking that a map intended to be a bijection is one.
+    ///
+    /// Runs in `O(n log n)` time, by sorting a stack buffer of value references (no heap allocation).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let owners = SgMap::<_, _, 10>::from_iter([(1, "ann"), (2, "bob"), (3, "ann"), (4, "cat"), (5, "ann"), (6, "bob")]);
+    /// assert!(owners.duplicate_values().eq([(&"ann", 3), (&"bob", 2)]));
+    /// ```
+    pub fn duplicate_values(&self) -> impl Iterator<Item = (&V, usize)>
+    where
+        V: Ord,
+    {
+        group_runs(self.sorted_values().into_iter()).filter(|(_, cnt)| *cnt > 1)
+    }
+
+    /// Returns `true` if any value appears more than once in the map.
+    /// Stops at the first repeat found, without counting occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// assert!(!map.has_duplicate_values());
+    ///
+    /// map.insert(4, 'a');
+    /// assert!(map.has_duplicate_values());
     /// ```
-    pub fn clear(&mut self) {
-        self.bst.clear()
+    pub fn has_duplicate_values(&self) -> bool
+    where
+        V: Ord,
+    {
+        self.sorted_values().windows(2).any(|w| w[0] == w[1])
     }
 
-    /// Returns `true` if the map contains a value for the specified key.
+    // Value references in ascending value order.
+    fn sorted_values(&self) -> ArrayVec<&V, N>
+    where
+        V: Ord,
+    {
+        let mut vals: ArrayVec<&V, N> = self.values().collect();
+        vals.sort_unstable();
+        vals
+    }
+
+    /// Returns the key-value pair at sorted position `index` (0-based), or `None` if `index >= len`.
+    ///
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise it walks the tree in order, taking `O(log n + index)` time.
     ///
-    /// The key may be any borrowed form of the map's key type, but the ordering
-    /// on the borrowed form *must* match the ordering on the key type.
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// assert_eq!(map.contains_key(&1), true);
-    /// assert_eq!(map.contains_key(&2), false);
+    /// let map = SgMap::<_, _, 10>::from_iter([(30, "c"), (10, "a"), (20, "b")]);
+    /// assert_eq!(map.get_index(0), Some((&10, &"a")));
+    /// assert_eq!(map.get_index(2), Some((&30, &"c")));
+    /// assert_eq!(map.get_index(3), None);
     /// ```
-    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)>
     where
-        K: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        K: Ord,
     {
-        self.bst.contains_key(key)
+        self.bst.get_index(index)
     }
 
-    /// Returns `true` if the map contains no elements.
+    /// Returns the entry at quantile `q` of the sorted keys: the one at position `round(q * (len - 1))`,
+    /// rounding halves up. E.g. `quantile(0.99)` for a p99 latency summary, without any external sorting.
+    ///
+    /// Returns `None` if the map is empty, or if `q` is outside `0.0..=1.0` (including `NaN`) rather than panicking.
+    /// Built on [`get_index`][crate::map::SgMap::get_index], so it shares its complexity.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 10>::new();
-    /// assert!(a.is_empty());
-    /// a.insert(1, "a");
-    /// assert!(!a.is_empty());
+    /// // Request latencies in ms, keyed by (latency, request id) to allow repeats
+    /// let latencies = SgMap::<_, _, 16>::from_iter((1..=11).map(|i| ((i * 10, i), ())));
+    ///
+    /// assert_eq!(latencies.quantile(0.0).map(|(k, _)| k.0), Some(10));
+    /// assert_eq!(latencies.quantile(0.9).map(|(k, _)| k.0), Some(100));
+    /// assert_eq!(latencies.quantile(1.0).map(|(k, _)| k.0), Some(110));
+    /// assert_eq!(latencies.quantile(1.5), None);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.bst.is_empty()
+    pub fn quantile(&self, q: f64) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        if !(0.0..=1.0).contains(&q) || self.is_empty() {
+            return None;
+        }
+
+        // Non-negative, so adding a half and truncating rounds halves up (`f64::round` isn't in `core`)
+        let pos = (q * (self.len() - 1) as f64 + 0.5) as usize;
+        self.get_index(pos)
     }
 
-    /// Returns `true` if the map's capacity is filled.
+    /// Returns the entry at the middle sorted position, shorthand for [`quantile(0.5)`][crate::map::SgMap::quantile].
+    /// For an even number of entries, the upper of the two middle entries is returned.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut a = SgMap::<_, _, 2>::new();
-    /// a.insert(1, "a");
-    /// assert!(!a.is_full());
-    /// a.insert(2, "b");
-    /// assert!(a.is_full());
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (2, "b")]);
+    /// assert_eq!(map.median(), Some((&2, &"b")));
+    ///
+    /// map.insert(4, "d");
+    /// assert_eq!(map.median(), Some((&3, &"c")));
     /// ```
-    pub fn is_full(&self) -> bool {
-        self.bst.is_full()
+    pub fn median(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        self.quantile(0.5)
     }
 
-    /// Returns a reference to the first key-value pair in the map.
-    /// The key in this pair is the minimum key in the map.
+    /// Returns the key and a mutable reference to the value at sorted position `index` (0-based),
+    /// or `None` if `index >= len`.
+    ///
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise it walks the tree in order, taking `O(log n + index)` time.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// assert_eq!(map.first_key_value(), None);
-    /// map.insert(1, "b");
-    /// map.insert(2, "a");
-    /// assert_eq!(map.first_key_value(), Some((&1, &"b")));
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(30, "c"), (10, "a"), (20, "b")]);
+    /// if let Some((_, v)) = map.get_index_mut(1) {
+    ///     *v = "B";
+    /// }
+    /// assert_eq!(map[&20], "B");
     /// ```
-    pub fn first_key_value(&self) -> Option<(&K, &V)>
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)>
     where
         K: Ord,
     {
-        self.bst.first_key_value()
+        self.bst.get_index_mut(index)
     }
 
-    /// Returns a reference to the first/minium key in the map, if any.
+    /// Returns the sorted position (0-based) of `key` in the map, if present.
+    /// This is the inverse of [`get_index`][crate::map::SgMap::get_index].
+    ///
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise smaller subtrees are counted node by node, taking `O(log n + position)` time.
+    /// A missing key costs `O(log n)`.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// assert_eq!(map.first_key_value(), None);
-    /// map.insert(1, "b");
-    /// map.insert(2, "a");
-    /// assert_eq!(map.first_key(), Some(&1));
+    /// let map = SgMap::<_, _, 10>::from_iter([(30, "c"), (10, "a"), (20, "b")]);
+    /// assert_eq!(map.position(&10), Some(0));
+    /// assert_eq!(map.position(&30), Some(2));
+    /// assert_eq!(map.position(&25), None);
     /// ```
-    pub fn first_key(&self) -> Option<&K>
+    pub fn position<Q>(&self, key: &Q) -> Option<usize>
     where
-        K: Ord,
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
     {
-        self.bst.first_key()
+        self.bst.position(key)
     }
 
-    /// Removes and returns the first element in the map.
-    /// The key of this element is the minimum key that was in the map.
+    /// Returns the sorted position (0-based) of the first entry for which `pred` returns `true`, if any.
+    /// Entries are tested in ascending key order, so this runs in `O(n)` time.
     ///
     /// # Examples
     ///
-    /// Draining elements in ascending order, while keeping a usable map each iteration.
-    ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// map.insert(2, "b");
-    /// while let Some((key, _val)) = map.pop_first() {
-    ///     assert!((&map).into_iter().all(|(k, _v)| *k > key));
-    /// }
-    /// assert!(map.is_empty());
+    /// let map = SgMap::<_, _, 10>::from_iter([(30, "c"), (10, "a"), (20, "b")]);
+    /// assert_eq!(map.find_index(|_, v| *v != "a"), Some(1));
+    /// assert_eq!(map.find_index(|k, _| *k > 30), None);
     /// ```
-    pub fn pop_first(&mut self) -> Option<(K, V)>
+    pub fn find_index<F>(&self, pred: F) -> Option<usize>
     where
         K: Ord,
+        F: FnMut(&K, &V) -> bool,
     {
-        self.bst.pop_first()
+        self.bst.find_index(pred)
     }
 
-    /// Returns a reference to the last key-value pair in the map.
-    /// The key in this pair is the maximum key in the map.
+    /// Returns the number of entries for which `pred` returns `true`.
+    /// Entries are tested in ascending key order, so this runs in `O(n)` time.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "b");
-    /// map.insert(2, "a");
-    /// assert_eq!(map.last_key_value(), Some((&2, &"a")));
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "a"), (4, "c")]);
+    /// assert_eq!(map.count_where(|_, v| *v == "a"), 2);
+    /// assert_eq!(map.count_where(|k, _| *k > 4), 0);
     /// ```
-    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    pub fn count_where<F>(&self, mut pred: F) -> usize
     where
         K: Ord,
+        F: FnMut(&K, &V) -> bool,
     {
-        self.bst.last_key_value()
+        self.iter().filter(|(k, v)| pred(k, v)).count()
     }
 
-    /// Returns a reference to the last/maximum key in the map, if any.
+    /// Gets an iterator from the first entry satisfying `start_pred` through the first entry, at or after it,
+    /// satisfying `end_pred` (inclusive), in order by key.
+    /// A predicate-bounded alternative to [`range`][crate::map::SgMap::range], e.g. for scanning semi-structured logs
+    /// between two markers.
+    ///
+    /// Both predicates are evaluated up front, so the returned iterator yields nothing if either entry isn't found.
+    /// The start entry may also be the end entry.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "b");
-    /// map.insert(2, "a");
-    /// assert_eq!(map.last_key(), Some(&2));
-    /// ```
-    pub fn last_key(&self) -> Option<&K>
+    /// let log = SgMap::<_, _, 10>::from_iter([
+    ///     (1, "boot"),
+    ///     (2, "BEGIN"),
+    ///     (3, "work"),
+    ///     (4, "more work"),
+    ///     (5, "END"),
+    ///     (6, "idle"),
+    /// ]);
+    ///
+    /// let section: Vec<_> = log.range_between(|_, m| *m == "BEGIN", |_, m| *m == "END").map(|(t, _)| *t).collect();
+    /// assert_eq!(section, [2, 3, 4, 5]);
+    ///
+    /// // No end marker after the start, so nothing is yielded
+    /// assert_eq!(log.range_between(|t, _| *t > 5, |_, m| *m == "END").count(), 0);
+    /// ```
+    pub fn range_between<P1, P2>(
+        &self,
+        mut start_pred: P1,
+        mut end_pred: P2,
+    ) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator
     where
         K: Ord,
+        P1: FnMut(&K, &V) -> bool,
+        P2: FnMut(&K, &V) -> bool,
     {
-        self.bst.last_key()
+        let opt_bounds = self
+            .iter()
+            .position(|(k, v)| start_pred(k, v))
+            .and_then(|start| {
+                self.iter()
+                    .skip(start)
+                    .position(|(k, v)| end_pred(k, v))
+                    .map(|len| (start, len + 1))
+            });
+
+        let (start, len) = opt_bounds.unwrap_or((0, 0));
+        self.iter().skip(start).take(len)
     }
 
-    /// Removes and returns the last element in the map.
-    /// The key of this element is the maximum key that was in the map.
+    /// Returns `true` if the entries, read as half-open `[start, end)` intervals via `bounds`, are each well-formed
+    /// (`start <= end`), ascending, and non-overlapping when scanned in key order. Adjacent intervals may touch.
+    /// Stops at the first violation.
     ///
-    /// # Examples
+    /// Validates e.g. an allocator's `start -> len` map in a single pass, without extracting and re-sorting.
     ///
-    /// Draining elements in descending order, while keeping a usable map each iteration.
+    /// # Examples
     ///
     /// ```
     /// use escapegoat::SgMap;
     ///
-    /// let mut map = SgMap::<_, _, 10>::new();
-    /// map.insert(1, "a");
-    /// map.insert(2, "b");
-    /// while let Some((key, _val)) = map.pop_last() {
-    ///     assert!((&map).into_iter().all(|(k, _v)| *k < key));
-    /// }
-    /// assert!(map.is_empty());
+    /// let mut free = SgMap::<u64, u64, 10>::from_iter([(0, 16), (16, 8), (64, 32)]);
+    /// assert!(free.is_disjoint_intervals(|start, len| (*start, start + len)));
+    ///
+    /// // [20, 28) overlaps [16, 24)
+    /// free.insert(20, 8);
+    /// assert!(!free.is_disjoint_intervals(|start, len| (*start, start + len)));
     /// ```
-    pub fn pop_last(&mut self) -> Option<(K, V)>
+    pub fn is_disjoint_intervals<F>(&self, bounds: F) -> bool
     where
         K: Ord,
+        F: Fn(&K, &V) -> (u64, u64),
     {
-        self.bst.pop_last()
+        let mut opt_prev_end = None;
+        self.iter().all(|(k, v)| {
+            let (start, end) = bounds(k, v);
+            let valid = (start <= end) && opt_prev_end.is_none_or(|prev_end| prev_end <= start);
+            opt_prev_end = Some(end);
+            valid
+        })
     }
 
     /// Returns the number of elements in the map.
@@ -954,6 +3291,42 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Gets the entry for a borrowed form of the key, for in-place manipulation.
+    ///
+    /// Unlike [`entry`][SgMap::entry], this doesn't require an owned key up front:
+    /// one is only constructed, from the borrowed key, if a vacant entry is inserted into.
+    /// Useful for keys that are expensive to construct or clone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut count = SgMap::<String, usize, 10>::new();
+    ///
+    /// // count the number of occurrences of words in the vec, allocating only on first sight
+    /// for x in ["apple", "banana", "apple", "cherry", "apple", "banana"] {
+    ///     *count.entry_ref(x).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(count["apple"], 3);
+    /// assert_eq!(count["cherry"], 1);
+    /// ```
+    pub fn entry_ref<'a, 'b, Q>(&'a mut self, key: &'b Q) -> EntryRef<'a, 'b, K, Q, V, N>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let ngh: NodeGetHelper<Idx> = self.bst.internal_get(None, key);
+        match ngh.node_idx() {
+            Some(node_idx) => EntryRef::Occupied(OccupiedEntry {
+                node_idx,
+                table: self,
+            }),
+            None => EntryRef::Vacant(VacantEntryRef { key, table: self }),
+        }
+    }
+
     /// Returns the first entry in the map for in-place manipulation.
     /// The key of this entry is the minimum key in the map.
     ///
@@ -1016,6 +3389,106 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         })
     }
 
+    /// Returns a lazy iterator over the union of keys in `self` and `other`, in ascending order.
+    /// Each key is paired with a [`MergeValue`][crate::map_types::MergeValue] telling whether it's only in `self` (left), only in `other` (right),
+    /// or in both. Runs in `O(n + m)` time without allocation, e.g. to diff two snapshots of a map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use escapegoat::map_types::MergeValue;
+    ///
+    /// let before = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+    /// let after = SgMap::<_, _, 10>::from_iter([(2, "b"), (3, "C"), (4, "d")]);
+    ///
+    /// let diff: Vec<_> = before.merge_iter(&after).collect();
+    /// assert_eq!(
+    ///     diff,
+    ///     [
+    ///         (&1, MergeValue::Left(&"a")),
+    ///         (&2, MergeValue::Both(&"b", &"b")),
+    ///         (&3, MergeValue::Both(&"c", &"C")),
+    ///         (&4, MergeValue::Right(&"d")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn merge_iter<'a>(&'a self, other: &'a SgMap<K, V, N>) -> MergeIter<'a, K, V, N>
+    where
+        K: Ord,
+    {
+        MergeIter::new(self, other)
+    }
+
+    /// Returns a lazy iterator over the changes needed to turn `self` into `newer`, in ascending key order.
+    /// Keys present in both maps with equal values are skipped, so the output is a minimal changeset,
+    /// e.g. to replicate state to a remote peer. Runs in `O(n + m)` time without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use escapegoat::map_types::DiffEvent;
+    ///
+    /// let older = SgMap::<_, _, 10>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+    /// let newer = SgMap::<_, _, 10>::from_iter([(2, "b"), (3, "C"), (4, "d")]);
+    ///
+    /// let changes: Vec<_> = older.diff(&newer).collect();
+    /// assert_eq!(
+    ///     changes,
+    ///     [
+    ///         DiffEvent::Removed(&1, &"a"),
+    ///         DiffEvent::Changed(&3, &"c", &"C"),
+    ///         DiffEvent::Added(&4, &"d"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff<'a>(
+        &'a self,
+        newer: &'a SgMap<K, V, N>,
+    ) -> impl Iterator<Item = DiffEvent<'a, K, V>> + 'a
+    where
+        K: Ord,
+        V: PartialEq,
+    {
+        self.merge_iter(newer).filter_map(|(k, mv)| match mv {
+            MergeValue::Left(old) => Some(DiffEvent::Removed(k, old)),
+            MergeValue::Right(new) => Some(DiffEvent::Added(k, new)),
+            MergeValue::Both(old, new) => (old != new).then_some(DiffEvent::Changed(k, old, new)),
+        })
+    }
+
+    /// Creates an iterator that removes entries from the map in batches of up to `chunk_size`, smallest keys first.
+    /// Each chunk is in sorted order and every chunk but the last holds exactly `chunk_size` entries.
+    ///
+    /// Removal is lazy: each call to `next` pops one chunk, so entries not yet yielded remain in the map
+    /// if the iterator is dropped early.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::from_iter([(4, 'd'), (1, 'a'), (3, 'c'), (5, 'e'), (2, 'b')]);
+    ///
+    /// let mut chunks = map.drain_chunks(2);
+    /// assert_eq!(chunks.next().unwrap().as_slice(), [(1, 'a'), (2, 'b')]);
+    /// assert_eq!(chunks.next().unwrap().as_slice(), [(3, 'c'), (4, 'd')]);
+    /// drop(chunks);
+    ///
+    /// assert!(map.into_iter().eq([(5, 'e')]));
+    /// ```
+    pub fn drain_chunks(&mut self, chunk_size: usize) -> DrainChunks<'_, K, V, N>
+    where
+        K: Ord,
+    {
+        DrainChunks::new(self, chunk_size)
+    }
+
     /// Constructs a double-ended iterator over a sub-range of elements in the map.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -1058,6 +3531,101 @@ impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Constructs an iterator over a sub-range of elements in the map, in descending key order.
+    /// Equivalent to `range(range).rev()`, e.g. for "most recent first" reads within a window of keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|i| (i, i * 10)).collect();
+    /// assert!(map.range_rev(2..5).eq([(&4, &40), (&3, &30), (&2, &20)]));
+    /// assert_eq!(map.range_rev(..).next(), Some((&7, &70)));
+    /// ```
+    pub fn range_rev<T, R>(&self, range: R) -> Rev<Range<'_, K, V, N>>
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        self.range(range).rev()
+    }
+
+    /// Returns the number of entries within `range` and, if it's non-empty, the arena slots of its first and last entries.
+    /// Slots match [`NodeRef::arena_idx`][crate::map_types::NodeRef::arena_idx], and are only valid until the map is next mutated.
+    ///
+    /// A cheap pre-check, e.g. to decide whether iterating a range is worthwhile before building the iterator.
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise the count prunes out-of-range subtrees like [`range_len`][crate::map::SgMap::range_len],
+    /// taking `O(log n + len)` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, "a"), (3, "b"), (5, "c"), (7, "d")]);
+    ///
+    /// let (len, slots) = map.range_bounds_resolved(2..=7);
+    /// assert_eq!(len, 3);
+    ///
+    /// // Slots identify the first and last entries in range
+    /// let (first, last) = slots.unwrap();
+    /// let key_at = |slot| map.nodes_in_order().find(|n| n.arena_idx() == slot).map(|n| *n.key());
+    /// assert_eq!((key_at(first), key_at(last)), (Some(3), Some(7)));
+    ///
+    /// // Skip building an iterator over nothing
+    /// assert_eq!(map.range_bounds_resolved(8..), (0, None));
+    /// ```
+    pub fn range_bounds_resolved<T, R>(&self, range: R) -> (usize, Option<(usize, usize)>)
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        self.bst.range_bounds_resolved(&range)
+    }
+
+    /// Returns the number of entries within `range`, without building a [`Range`][crate::map_types::Range] iterator.
+    /// Subtrees entirely outside the range are skipped, so this runs in `O(log n + len)` time without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use core::ops::Bound::{Excluded, Unbounded};
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(1, "a"), (3, "b"), (5, "c"), (7, "d")]);
+    ///
+    /// assert_eq!(map.range_len(2..=7), 3);
+    /// assert_eq!(map.range_len((Excluded(3), Unbounded)), 2);
+    /// assert_eq!(map.range_len(..), map.len());
+    /// ```
+    pub fn range_len<T, R>(&self, range: R) -> usize
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        self.bst.range_len(&range)
+    }
+
     /// Constructs a mutable single-ended iterator over a sub-range of elements in the map.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -1115,6 +3683,13 @@ where
     }
 }
 
+// Default
+impl<K: Ord, V, const N: usize> Default for SgMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // From array.
 impl<K, V, const N: usize> From<[(K, V); N]> for SgMap<K, V, N>
 where
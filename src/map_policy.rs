@@ -0,0 +1,251 @@
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::map::SgMap;
+use crate::map_types::{IntoIter, Iter};
+
+/// What an [`SgMapPolicy`] does when inserting a new key into a full map, chosen at the type level.
+///
+/// The policy is a compile-time constant, so each `insert` is monomorphized to either the panicking path or the
+/// dropping path: there is no runtime dispatch.
+pub trait OverflowPolicy {
+    /// `true` to silently drop an insert that would exceed capacity, `false` to panic (as [`SgMap::insert`] does).
+    const DROP_ON_OVERFLOW: bool;
+}
+
+/// Overflow policy that panics on an insert exceeding capacity, exactly like [`SgMap::insert`].
+/// The default for [`SgMapPolicy`].
+#[derive(Debug)]
+pub enum Panic {}
+
+impl OverflowPolicy for Panic {
+    const DROP_ON_OVERFLOW: bool = false;
+}
+
+/// Overflow policy that silently drops an insert exceeding capacity, reporting it through the return flag.
+#[derive(Debug)]
+pub enum Ignore {}
+
+impl OverflowPolicy for Ignore {
+    const DROP_ON_OVERFLOW: bool = true;
+}
+
+/// Safe, fallible, embedded-friendly ordered map whose overflow behavior is fixed by an [`OverflowPolicy`] `P`.
+///
+/// Picking the policy once at the type level (e.g. in a module-wide type alias) avoids choosing between
+/// [`insert`][SgMap::insert] and [`try_insert`][SgMap::try_insert] at every call site.
+/// `P` defaults to [`Panic`], which behaves like a plain [`SgMap`].
+/// Opt into dropping over-capacity inserts with [`Ignore`]: `SgMapPolicy<K, V, N, Ignore>`.
+///
+/// Read-only [`SgMap`] APIs are available through [`Deref`]. Mutation goes through this type's own methods,
+/// so every insert honors `P`.
+///
+/// # Examples
+///
+/// ```
+/// use escapegoat::SgMapPolicy;
+/// use escapegoat::policy::Ignore;
+///
+/// type Cache = SgMapPolicy<u32, &'static str, 2, Ignore>;
+///
+/// let mut cache = Cache::new();
+/// assert_eq!(cache.insert(1, "a"), (None, true));
+/// assert_eq!(cache.insert(2, "b"), (None, true));
+///
+/// // Full: new keys are dropped, existing keys still update
+/// assert_eq!(cache.insert(3, "c"), (None, false));
+/// assert_eq!(cache.insert(2, "B"), (Some("b"), true));
+///
+/// // Read-only `SgMap` APIs via `Deref`
+/// assert_eq!(cache.len(), 2);
+/// assert_eq!(cache.get(&2), Some(&"B"));
+/// assert!(!cache.contains_key(&3));
+/// ```
+pub struct SgMapPolicy<K: Ord, V, const N: usize, P: OverflowPolicy = Panic> {
+    map: SgMap<K, V, N>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> SgMapPolicy<K, V, N, P> {
+    /// Makes a new, empty `SgMapPolicy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMapPolicy;
+    ///
+    /// let mut map = SgMapPolicy::<_, _, 10>::new();
+    ///
+    /// map.insert(1, "a");
+    /// ```
+    pub const fn new() -> Self {
+        SgMapPolicy {
+            map: SgMap::new(),
+            policy: PhantomData,
+        }
+    }
+
+    /// Consumes the wrapper, returning the underlying map.
+    pub fn into_inner(self) -> SgMap<K, V, N> {
+        self.map
+    }
+
+    /// Inserts a key-value pair into the map, returning the old value (if any) and whether the pair was stored.
+    /// If the map did have this key present, the value is updated, and the old value is returned.
+    ///
+    /// If the key is new and the map is full, the outcome depends on `P`:
+    /// * [`Panic`]: panics, like [`SgMap::insert`]. The flag is therefore always `true`.
+    /// * [`Ignore`]: the pair is dropped, the map is unchanged, and `(None, false)` is returned.
+    pub fn insert(&mut self, key: K, val: V) -> (Option<V>, bool) {
+        // Full: only an existing key can be stored, so update it in place on the node found by a single descent
+        if P::DROP_ON_OVERFLOW && self.map.is_full() {
+            return match self.map.bst.replace_existing(key, val) {
+                Ok(old_val) => (Some(old_val), true),
+                Err(_) => (None, false),
+            };
+        }
+
+        (self.map.insert(key, val), true)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the key was previously in the map.
+    pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        self.map.remove_entry(key)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.map.retain(f);
+    }
+
+    /// Clears the map, removing all elements.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Removes and returns the first element in the map.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.map.pop_first()
+    }
+
+    /// Removes and returns the last element in the map.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.map.pop_last()
+    }
+}
+
+// Convenience Traits --------------------------------------------------------------------------------------------------
+
+// Read-only access to the underlying map
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> Deref for SgMapPolicy<K, V, N, P> {
+    type Target = SgMap<K, V, N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+// Debug
+impl<K, V, const N: usize, P> Debug for SgMapPolicy<K, V, N, P>
+where
+    K: Ord + Debug,
+    V: Debug,
+    P: OverflowPolicy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+// Default
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> Default for SgMapPolicy<K, V, N, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Clone
+impl<K, V, const N: usize, P> Clone for SgMapPolicy<K, V, N, P>
+where
+    K: Ord + Clone,
+    V: Clone,
+    P: OverflowPolicy,
+{
+    fn clone(&self) -> Self {
+        SgMapPolicy {
+            map: self.map.clone(),
+            policy: PhantomData,
+        }
+    }
+}
+
+// Wrap an existing map.
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> From<SgMap<K, V, N>>
+    for SgMapPolicy<K, V, N, P>
+{
+    fn from(map: SgMap<K, V, N>) -> Self {
+        SgMapPolicy {
+            map,
+            policy: PhantomData,
+        }
+    }
+}
+
+// Construct from iterator, honoring `P` for every pair.
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> FromIterator<(K, V)>
+    for SgMapPolicy<K, V, N, P>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = SgMapPolicy::new();
+        map.extend(iter);
+        map
+    }
+}
+
+// Extension from iterator, honoring `P` for every pair.
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> Extend<(K, V)> for SgMapPolicy<K, V, N, P> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(move |(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+// Iterators -----------------------------------------------------------------------------------------------------------
+
+// Reference iterator
+impl<'a, K: Ord, V, const N: usize, P: OverflowPolicy> IntoIterator
+    for &'a SgMapPolicy<K, V, N, P>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+// Consuming iterator
+impl<K: Ord, V, const N: usize, P: OverflowPolicy> IntoIterator for SgMapPolicy<K, V, N, P> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
@@ -1,4 +1,5 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt;
 use core::iter::{FusedIterator, Peekable};
 use core::ops::RangeBounds;
@@ -7,7 +8,8 @@ use arrayvec::ArrayVec;
 
 use crate::map::SgMap;
 use crate::tree::{
-    Idx, IntoIter as TreeIntoIter, Iter as TreeIter, IterMut as TreeIterMut, SmallNode,
+    CollectError, Idx, IntoIter as TreeIntoIter, Iter as TreeIter, IterMut as TreeIterMut, SgError,
+    SmallNode, collect_array,
 };
 
 // General Iterators ---------------------------------------------------------------------------------------------------
@@ -36,6 +38,16 @@ impl<'a, K: Ord, V, const N: usize> Iterator for Iter<'a, K, V, N> {
     fn next(&mut self) -> Option<Self::Item> {
         self.ref_iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ref_iter.size_hint()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for Iter<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ref_iter.next_back()
+    }
 }
 
 impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
@@ -46,6 +58,14 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
 
+impl<'a, K: Ord, V, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the entries of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_iter`][crate::map::SgMap::into_iter] method on [`SgMap`][crate::map::SgMap].
@@ -61,6 +81,24 @@ impl<K: Ord, V, const N: usize> IntoIter<K, V, N> {
             cons_iter: TreeIntoIter::new(map.bst),
         }
     }
+
+    /// Moves exactly `M` remaining entries into an array, without heap allocation.
+    ///
+    /// Returns `Err` if fewer or more than `M` entries remain. Entries already moved out are dropped on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, CollectError};
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, "b"), (1, "a"), (3, "c")]);
+    /// assert_eq!(map.clone().into_iter().collect_array(), Ok([(1, "a"), (2, "b"), (3, "c")]));
+    /// assert_eq!(map.clone().into_iter().collect_array::<2>(), Err(CollectError::TooMany));
+    /// assert_eq!(map.into_iter().collect_array::<4>(), Err(CollectError::TooFew));
+    /// ```
+    pub fn collect_array<const M: usize>(self) -> Result<[(K, V); M], CollectError> {
+        collect_array(self)
+    }
 }
 
 impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
@@ -71,6 +109,12 @@ impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
     }
 }
 
+impl<K: Ord, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next_back()
+    }
+}
+
 impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
     fn len(&self) -> usize {
         self.cons_iter.len()
@@ -104,6 +148,12 @@ impl<'a, K: Ord, V, const N: usize> Iterator for IterMut<'a, K, V, N> {
     }
 }
 
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for IterMut<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.mut_iter.next_back()
+    }
+}
+
 impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
     fn len(&self) -> usize {
         self.mut_iter.len()
@@ -112,6 +162,78 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
 
+/// A mutable, lending iterator over the entries of a [`SgMap`][crate::map::SgMap], in order by key,
+/// whose items can remove themselves from the map.
+///
+/// This `struct` is created by the [`iter_mut_removable`][crate::map::SgMap::iter_mut_removable] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct RemovableIterMut<'a, K: Ord, V, const N: usize> {
+    map: &'a mut SgMap<K, V, N>,
+    sorted_idxs: arrayvec::IntoIter<usize, N>,
+}
+
+impl<'a, K: Ord, V, const N: usize> RemovableIterMut<'a, K, V, N> {
+    /// Construct removable iterator.
+    pub(crate) fn new(map: &'a mut SgMap<K, V, N>) -> Self {
+        // Removal relinks nodes but never moves them, so arena indexes stay valid for the whole walk
+        let sorted_idxs = map.bst.in_order_idxs().into_iter();
+        RemovableIterMut { map, sorted_idxs }
+    }
+
+    /// Advances the iterator, returning a handle to the next entry.
+    /// Each handle borrows the iterator, so only one is live at a time (e.g. loop with `while let`).
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<RemovableEntry<'_, K, V, N>> {
+        let node_idx = self.sorted_idxs.next()?;
+        Some(RemovableEntry {
+            node_idx,
+            table: self.map,
+        })
+    }
+
+    /// Returns the number of entries not yet visited.
+    pub fn len(&self) -> usize {
+        self.sorted_idxs.len()
+    }
+
+    /// Returns `true` if every entry has been visited.
+    pub fn is_empty(&self) -> bool {
+        self.sorted_idxs.len() == 0
+    }
+}
+
+/// A handle to the current entry of a [`RemovableIterMut`].
+pub struct RemovableEntry<'a, K: Ord, V, const N: usize> {
+    node_idx: usize,
+    table: &'a mut SgMap<K, V, N>,
+}
+
+impl<'a, K: Ord, V, const N: usize> RemovableEntry<'a, K, V, N> {
+    /// Gets a reference to the key of the entry.
+    pub fn key(&self) -> &K {
+        self.table.bst.arena[self.node_idx].key()
+    }
+
+    /// Gets a reference to the value of the entry.
+    pub fn value(&self) -> &V {
+        self.table.bst.arena[self.node_idx].val()
+    }
+
+    /// Gets a mutable reference to the value of the entry.
+    pub fn value_mut(&mut self) -> &mut V {
+        self.table.bst.arena[self.node_idx].get_mut().1
+    }
+
+    /// Removes the entry from the map, returning its key and value.
+    /// Iteration continues with the removed entry's successor.
+    pub fn remove(self) -> (K, V) {
+        self.table
+            .bst
+            .priv_remove_by_idx(self.node_idx)
+            .expect("Must be occupied")
+    }
+}
+
 // Key Iterators -------------------------------------------------------------------------------------------------------
 
 // TODO: these need more trait implementations for full compatibility
@@ -140,6 +262,14 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Keys<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for Keys<'a, K, V, N> {}
 
+impl<'a, K: Ord, V, const N: usize> Clone for Keys<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Keys {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the keys of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_keys`][crate::map::SgMap::into_keys] method on [`SgMap`][crate::map::SgMap].
@@ -192,6 +322,14 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Values<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for Values<'a, K, V, N> {}
 
+impl<'a, K: Ord, V, const N: usize> Clone for Values<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Values {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the values of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_values`][crate::map::SgMap::into_values] method on [`SgMap`][crate::map::SgMap].
@@ -240,6 +378,247 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for ValuesMut<'a, K, V, N>
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for ValuesMut<'a, K, V, N> {}
 
+/// An iterator over the entries of a [`SgMap`][crate::map::SgMap] whose values satisfy a predicate.
+///
+/// This `struct` is created by the [`filter_values`][crate::map::SgMap::filter_values] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct FilterValues<'a, K: Ord, V, const N: usize, F> {
+    pub(crate) inner: Iter<'a, K, V, N>,
+    pub(crate) pred: F,
+}
+
+impl<'a, K: Ord, V, const N: usize, F: FnMut(&V) -> bool> Iterator
+    for FilterValues<'a, K, V, N, F>
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.inner.find(|(_, v)| pred(v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize, F: FnMut(&V) -> bool> FusedIterator
+    for FilterValues<'a, K, V, N, F>
+{
+}
+
+/// A mutable iterator over the entries of a [`SgMap`][crate::map::SgMap] whose values satisfy a predicate.
+///
+/// This `struct` is created by the [`filter_values_mut`][crate::map::SgMap::filter_values_mut] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct FilterValuesMut<'a, K: Ord, V, const N: usize, F> {
+    pub(crate) inner: IterMut<'a, K, V, N>,
+    pub(crate) pred: F,
+}
+
+impl<'a, K: Ord, V, const N: usize, F: FnMut(&V) -> bool> Iterator
+    for FilterValuesMut<'a, K, V, N, F>
+{
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.inner.find(|(_, v)| pred(v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize, F: FnMut(&V) -> bool> FusedIterator
+    for FilterValuesMut<'a, K, V, N, F>
+{
+}
+
+// Merge Iterators -----------------------------------------------------------------------------------------------------
+
+/// Which side(s) of a [`merge_iter`][crate::map::SgMap::merge_iter] a key was found in, with the associated value(s).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeValue<V> {
+    /// Key only present in the left map (`self`).
+    Left(V),
+    /// Key only present in the right map (`other`).
+    Right(V),
+    /// Key present in both maps, left value first.
+    Both(V, V),
+}
+
+/// A single change between two snapshots of a map, yielded by [`diff`][crate::map::SgMap::diff].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffEvent<'a, K, V> {
+    /// Key only present in the newer map.
+    Added(&'a K, &'a V),
+    /// Key only present in the older map (`self`).
+    Removed(&'a K, &'a V),
+    /// Key present in both maps with unequal values, old value first.
+    Changed(&'a K, &'a V, &'a V),
+}
+
+/// A lazy, in-order iterator over the union of keys in two [`SgMap`][crate::map::SgMap]s.
+///
+/// This `struct` is created by the [`merge_iter`][crate::map::SgMap::merge_iter] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct MergeIter<'a, K: Ord, V, const N: usize> {
+    left_iter: Peekable<Iter<'a, K, V, N>>,
+    right_iter: Peekable<Iter<'a, K, V, N>>,
+}
+
+impl<'a, K: Ord, V, const N: usize> MergeIter<'a, K, V, N> {
+    /// Construct merge iterator.
+    pub(crate) fn new(left: &'a SgMap<K, V, N>, right: &'a SgMap<K, V, N>) -> Self {
+        MergeIter {
+            left_iter: left.iter().peekable(),
+            right_iter: right.iter().peekable(),
+        }
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> Iterator for MergeIter<'a, K, V, N> {
+    type Item = (&'a K, MergeValue<&'a V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let order = match (self.left_iter.peek(), self.right_iter.peek()) {
+            (Some((left_key, _)), Some((right_key, _))) => left_key.cmp(right_key),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+
+        match order {
+            Ordering::Less => self.left_iter.next().map(|(k, v)| (k, MergeValue::Left(v))),
+            Ordering::Greater => self
+                .right_iter
+                .next()
+                .map(|(k, v)| (k, MergeValue::Right(v))),
+            Ordering::Equal => {
+                let (k, left_val) = self.left_iter.next()?;
+                let (_, right_val) = self.right_iter.next()?;
+                Some((k, MergeValue::Both(left_val, right_val)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_len, right_len) = (self.left_iter.len(), self.right_iter.len());
+        (left_len.max(right_len), Some(left_len + right_len))
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> FusedIterator for MergeIter<'a, K, V, N> {}
+
+// Window Iterators ----------------------------------------------------------------------------------------------------
+
+/// An iterator over overlapping, fixed-size windows of entries of a [`SgMap`][crate::map::SgMap], in sorted order.
+///
+/// This `struct` is created by the [`windows`][crate::map::SgMap::windows] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct Windows<'a, K: Ord, V, const N: usize> {
+    iter: Iter<'a, K, V, N>,
+    ring: ArrayVec<(&'a K, &'a V), N>,
+    head: usize,
+    size: usize,
+}
+
+impl<'a, K: Ord, V, const N: usize> Windows<'a, K, V, N> {
+    /// Construct window iterator.
+    pub(crate) fn new(map: &'a SgMap<K, V, N>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            iter: map.iter(),
+            ring: ArrayVec::new(),
+            head: 0,
+            size,
+        }
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> Iterator for Windows<'a, K, V, N> {
+    type Item = ArrayVec<(&'a K, &'a V), N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ring.len() < self.size {
+            // First window: fill the ring (a window larger than `N` can never fill)
+            while self.ring.len() < self.size {
+                self.ring.try_push(self.iter.next()?).ok()?;
+            }
+        } else {
+            // Slide: overwrite the oldest entry
+            self.ring[self.head] = self.iter.next()?;
+            self.head = (self.head + 1) % self.size;
+        }
+
+        Some(
+            self.ring[self.head..]
+                .iter()
+                .chain(&self.ring[..self.head])
+                .copied()
+                .collect(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.ring.len() < self.size {
+            true => (self.iter.len() + self.ring.len() + 1).saturating_sub(self.size),
+            false => self.iter.len(),
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Windows<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> FusedIterator for Windows<'a, K, V, N> {}
+
+// Drain Iterators -----------------------------------------------------------------------------------------------------
+
+/// An iterator removing the entries of a [`SgMap`][crate::map::SgMap] in sorted, fixed-size batches.
+///
+/// This `struct` is created by the [`drain_chunks`][crate::map::SgMap::drain_chunks] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct DrainChunks<'a, K: Ord, V, const N: usize> {
+    map: &'a mut SgMap<K, V, N>,
+    chunk_size: usize,
+}
+
+impl<'a, K: Ord, V, const N: usize> DrainChunks<'a, K, V, N> {
+    /// Construct chunked draining iterator.
+    pub(crate) fn new(map: &'a mut SgMap<K, V, N>, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        DrainChunks { map, chunk_size }
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> Iterator for DrainChunks<'a, K, V, N> {
+    type Item = ArrayVec<(K, V), N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = ArrayVec::new();
+        while chunk.len() < self.chunk_size {
+            match self.map.pop_first() {
+                Some(kv) => chunk.push(kv),
+                None => break,
+            }
+        }
+
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunk_cnt = self.map.len().div_ceil(self.chunk_size);
+        (chunk_cnt, Some(chunk_cnt))
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for DrainChunks<'a, K, V, N> {}
+
+impl<'a, K: Ord, V, const N: usize> FusedIterator for DrainChunks<'a, K, V, N> {}
+
 // Entry APIs ----------------------------------------------------------------------------------------------------------
 
 /// A view into a single entry in a map, which may either be vacant or occupied.
@@ -273,6 +652,29 @@ impl<'a, K: Ord, V, const N: usize> Entry<'a, K, V, N> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the default if empty, and returns a mutable
+    /// reference to the value in the entry.
+    /// Returns `Err` instead of panicking if the entry is vacant and the map is already at its stack capacity, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<&str, usize, 1>::new();
+    /// assert_eq!(map.entry("poneyland").or_try_insert(12), Ok(&mut 12));
+    /// *map.entry("poneyland").or_try_insert(0).unwrap() += 1;
+    /// assert_eq!(map["poneyland"], 13);
+    ///
+    /// assert_eq!(map.entry("horseland").or_try_insert(3), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn or_try_insert(self, default: V) -> Result<&'a mut V, SgError> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.try_insert(default),
+        }
+    }
+
     /// Ensures a value is in the entry by inserting the result of the default function if empty, and returns a mutable
     /// reference to the value in the entry.
     ///
@@ -339,6 +741,64 @@ impl<'a, K: Ord, V, const N: usize> Entry<'a, K, V, N> {
         }
     }
 
+    /// Returns `true` if the entry is occupied, e.g. its key is present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<&str, usize, 10>::new();
+    /// assert!(!map.entry("poneyland").is_occupied());
+    ///
+    /// map.insert("poneyland", 12);
+    /// assert!(map.entry("poneyland").is_occupied());
+    /// ```
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, Entry::Occupied(_))
+    }
+
+    /// Returns `true` if the entry is vacant, e.g. its key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<&str, usize, 10>::new();
+    /// assert!(map.entry("poneyland").is_vacant());
+    ///
+    /// map.insert("poneyland", 12);
+    /// assert!(!map.entry("poneyland").is_vacant());
+    /// ```
+    pub fn is_vacant(&self) -> bool {
+        matches!(self, Entry::Vacant(_))
+    }
+
+    /// Returns a reference to the entry's value if occupied, or `None` if vacant, without consuming the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<&str, usize, 10>::new();
+    /// map.insert("poneyland", 12);
+    ///
+    /// let entry = map.entry("poneyland");
+    /// assert_eq!(entry.get(), Some(&12));
+    /// *entry.or_insert(0) += 1;
+    /// assert_eq!(map["poneyland"], 13);
+    ///
+    /// assert_eq!(map.entry("horseland").get(), None);
+    /// ```
+    pub fn get(&self) -> Option<&V> {
+        match self {
+            Entry::Occupied(entry) => Some(entry.get()),
+            Entry::Vacant(_) => None,
+        }
+    }
+
     /// Provides in-place mutable access to an occupied entry before any
     /// potential inserts into the map.
     ///
@@ -457,6 +917,33 @@ impl<'a, K: Ord, V, const N: usize> VacantEntry<'a, K, V, N> {
 
         self.table.bst.arena[new_node_idx].get_mut().1
     }
+
+    /// Sets the value of the entry with the [`VacantEntry`][crate::map_types::VacantEntry]'s key,
+    /// and returns a mutable reference to it.
+    /// Returns `Err` instead of panicking if the map is already at its stack capacity, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgError};
+    /// use escapegoat::map_types::Entry;
+    ///
+    /// let mut map = SgMap::<&str, u32, 1>::new();
+    ///
+    /// if let Entry::Vacant(o) = map.entry("poneyland") {
+    ///     assert_eq!(o.try_insert(37), Ok(&mut 37));
+    /// }
+    /// if let Entry::Vacant(o) = map.entry("horseland") {
+    ///     assert_eq!(o.try_insert(3), Err(SgError::StackCapacityExceeded));
+    /// }
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, SgError> {
+        match self.table.is_full() {
+            true => Err(SgError::StackCapacityExceeded),
+            false => Ok(self.insert(value)),
+        }
+    }
 }
 
 /// A view into an occupied entry in a [`SgMap`][crate::map::SgMap].
@@ -625,6 +1112,243 @@ impl<'a, K: Ord, V, const N: usize> OccupiedEntry<'a, K, V, N> {
     }
 }
 
+/// A view into a single entry in a map, looked up by a borrowed key, which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`SgMap::entry_ref`] method on [`SgMap`].
+/// Unlike [`Entry`], an owned key is only constructed if a vacant entry is inserted into.
+pub enum EntryRef<'a, 'b, K: Ord + Borrow<Q>, Q: Ord + ?Sized, V, const N: usize> {
+    /// A vacant entry.
+    Vacant(VacantEntryRef<'a, 'b, K, Q, V, N>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+}
+
+impl<'a, 'b, K: Ord + Borrow<Q>, Q: Ord + ?Sized, V, const N: usize> EntryRef<'a, 'b, K, Q, V, N> {
+    /// Ensures a value is in the entry by inserting the default if empty, and returns a mutable
+    /// reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// map.entry_ref("poneyland").or_insert(12);
+    ///
+    /// assert_eq!(map["poneyland"], 12);
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V
+    where
+        K: From<&'b Q>,
+    {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty, and returns a mutable
+    /// reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// let x = 42;
+    /// map.entry_ref("poneyland").or_insert_with(|| x);
+    ///
+    /// assert_eq!(map["poneyland"], 42);
+    /// ```
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V
+    where
+        K: From<&'b Q>,
+    {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of the default function.
+    /// The default function is provided a reference to the borrowed key passed to `.entry_ref(key)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    ///
+    /// map.entry_ref("poneyland").or_insert_with_key(|key| key.chars().count());
+    ///
+    /// assert_eq!(map["poneyland"], 9);
+    /// ```
+    pub fn or_insert_with_key<F: FnOnce(&Q) -> V>(self, default: F) -> &'a mut V
+    where
+        K: From<&'b Q>,
+    {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Returns a reference to this entry's key, in borrowed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// assert_eq!(map.entry_ref("poneyland").key(), "poneyland");
+    /// ```
+    pub fn key(&self) -> &Q {
+        match self {
+            EntryRef::Occupied(entry) => entry.key().borrow(),
+            EntryRef::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    ///
+    /// map.entry_ref("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 42);
+    ///
+    /// map.entry_ref("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 43);
+    /// ```
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> EntryRef<'a, 'b, K, Q, V, N> {
+        match self {
+            EntryRef::Occupied(mut entry) => {
+                f(entry.get_mut());
+                EntryRef::Occupied(entry)
+            }
+            EntryRef::Vacant(entry) => EntryRef::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, 'b, K: Ord + Borrow<Q> + From<&'b Q>, Q: Ord + ?Sized, V: Default, const N: usize>
+    EntryRef<'a, 'b, K, Q, V, N>
+{
+    /// Ensures a value is in the entry by inserting the default value if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, Option<usize>, 10>::new();
+    /// map.entry_ref("poneyland").or_default();
+    ///
+    /// assert_eq!(map["poneyland"], None);
+    /// ```
+    pub fn or_default(self) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+/// A view into a vacant entry in a [`SgMap`][crate::map::SgMap], holding a borrowed key.
+/// It is part of the [`EntryRef`] enum.
+pub struct VacantEntryRef<'a, 'b, K: Ord + Borrow<Q>, Q: Ord + ?Sized, V, const N: usize> {
+    pub(super) key: &'b Q,
+    pub(super) table: &'a mut SgMap<K, V, N>,
+}
+
+impl<'a, 'b, K: Ord + Borrow<Q>, Q: Ord + ?Sized, V, const N: usize>
+    VacantEntryRef<'a, 'b, K, Q, V, N>
+{
+    /// Gets a reference to the borrowed key that would be used when inserting a value
+    /// through the [`VacantEntryRef`][crate::map_types::VacantEntryRef].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 2>::new();
+    /// assert_eq!(map.entry_ref("poneyland").key(), "poneyland");
+    /// ```
+    pub fn key(&self) -> &'b Q {
+        self.key
+    }
+
+    /// Sets the value of the entry, converting the borrowed key into an owned one,
+    /// and returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use escapegoat::map_types::EntryRef;
+    ///
+    /// let mut map = SgMap::<String, u32, 2>::new();
+    ///
+    /// if let EntryRef::Vacant(v) = map.entry_ref("poneyland") {
+    ///     v.insert(37);
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    /// ```
+    pub fn insert(self, value: V) -> &'a mut V
+    where
+        K: From<&'b Q>,
+    {
+        self.insert_with_key(K::from, value)
+    }
+
+    /// Sets the value of the entry, using `make_key` to construct an owned key from the borrowed one,
+    /// and returns a mutable reference to the value.
+    ///
+    /// Useful when `K` can't implement `From<&Q>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    /// use escapegoat::map_types::EntryRef;
+    ///
+    /// let mut map = SgMap::<Vec<u8>, u32, 2>::new();
+    ///
+    /// if let EntryRef::Vacant(v) = map.entry_ref(&b"poneyland"[..]) {
+    ///     v.insert_with_key(|k| k.to_vec(), 37);
+    /// }
+    /// assert_eq!(map[&b"poneyland"[..]], 37);
+    /// ```
+    pub fn insert_with_key<F: FnOnce(&'b Q) -> K>(self, make_key: F, value: V) -> &'a mut V {
+        let key = make_key(self.key);
+        debug_assert!(
+            key.borrow() == self.key,
+            "API misuse: constructed key doesn't match borrowed key!"
+        );
+
+        let (_, new_node_idx) = self.table.bst.internal_balancing_insert::<Idx>(key, value);
+
+        self.table.bst.arena[new_node_idx].get_mut().1
+    }
+}
+
 /// The error returned by [`try_insert_std`](SgMap::try_insert_std) when the key already exists.
 ///
 /// Contains the occupied entry, and the value that was not inserted.
@@ -675,6 +1399,23 @@ impl<'a, K: Ord, V, const N: usize> Range<'a, K, V, N> {
         let node = &self.table.bst.arena[idx];
         (node.key(), node.val())
     }
+
+    /// Collects exactly `M` remaining entries into an array, without heap allocation.
+    ///
+    /// Returns `Err` if fewer or more than `M` entries remain in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, CollectError};
+    ///
+    /// let map: SgMap<_, _, 10> = (0..8).map(|i| (i, i * 10)).collect();
+    /// assert_eq!(map.range(2..4).collect_array(), Ok([(&2, &20), (&3, &30)]));
+    /// assert_eq!(map.range(2..5).collect_array::<2>(), Err(CollectError::TooMany));
+    /// ```
+    pub fn collect_array<const M: usize>(self) -> Result<[(&'a K, &'a V); M], CollectError> {
+        collect_array(self)
+    }
 }
 
 impl<'a, K: Ord, V, const N: usize> Iterator for Range<'a, K, V, N> {
@@ -695,6 +1436,15 @@ impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for Range<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for Range<'a, K, V, N> {}
 
+impl<'a, K: Ord, V, const N: usize> Clone for Range<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Range {
+            table: self.table,
+            node_idx_iter: self.node_idx_iter.clone(),
+        }
+    }
+}
+
 /// A mutable iterator over a sub-range of entries in a [`SgMap`].
 ///
 /// This `struct` is created by the [`range_mut`] method on [`SgMap`]. See its
@@ -717,6 +1467,25 @@ impl<'a, K, V, const N: usize> RangeMut<'a, K, V, N>
 where
     K: Ord,
 {
+    /// Collects exactly `M` remaining entries into an array, without heap allocation.
+    ///
+    /// Returns `Err` if fewer or more than `M` entries remain in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<_, _, 10> = (0..8).map(|i| (i, i * 10)).collect();
+    /// let [(_, lo), (_, hi)] = map.range_mut(2..4).collect_array().unwrap();
+    /// core::mem::swap(lo, hi);
+    /// assert_eq!(map[&2], 30);
+    /// assert_eq!(map[&3], 20);
+    /// ```
+    pub fn collect_array<const M: usize>(self) -> Result<[(&'a K, &'a mut V); M], CollectError> {
+        collect_array(self)
+    }
+
     // Constructor
     pub(crate) fn new<T, R>(map: &'a mut SgMap<K, V, N>, range: &R) -> Self
     where
@@ -742,28 +1511,7 @@ where
         K: Borrow<T> + Ord,
         R: RangeBounds<T>,
     {
-        let mut peekable = map.bst.iter().peekable();
-        let mut len = 0;
-
-        // Advance immutable iter to start
-        while let Some(node) = peekable.peek() {
-            if range.contains(node.0.borrow()) {
-                break;
-            }
-
-            peekable.next();
-        }
-
-        // Count remaining
-        for node in peekable {
-            if range.contains(node.0.borrow()) {
-                len += 1;
-            } else {
-                break;
-            }
-        }
-
-        len
+        map.bst.range_bounds_resolved(range).0
     }
 
     // Prepare mutable iterator to return first item in range
@@ -853,3 +1601,163 @@ where
     }
 }
 */
+
+// Read-only View ------------------------------------------------------------------------------------------------------
+
+/// A read-only view of a [`SgMap`][crate::map::SgMap].
+///
+/// This `struct` is created by the [`as_view`][crate::map::SgMap::as_view] method on [`SgMap`][crate::map::SgMap].
+/// It's a transparent borrow that only forwards lookup and iteration methods, so no mutating method can ever be
+/// reached through it. Cheap to copy and pass across API boundaries.
+#[derive(Clone, Copy)]
+pub struct SgMapView<'a, K: Ord, V, const N: usize> {
+    map: &'a SgMap<K, V, N>,
+}
+
+impl<'a, K: Ord, V, const N: usize> SgMapView<'a, K, V, N> {
+    /// Construct read-only view.
+    pub(crate) fn new(map: &'a SgMap<K, V, N>) -> Self {
+        SgMapView { map }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    /// See [`SgMap::get`][crate::map::SgMap::get].
+    pub fn get<Q>(&self, key: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.get(key)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    /// See [`SgMap::get_key_value`][crate::map::SgMap::get_key_value].
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&'a K, &'a V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.get_key_value(key)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    /// See [`SgMap::contains_key`][crate::map::SgMap::contains_key].
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by key.
+    /// See [`SgMap::iter`][crate::map::SgMap::iter].
+    pub fn iter(&self) -> Iter<'a, K, V, N> {
+        self.map.iter()
+    }
+
+    /// Gets an iterator over the keys of the map, in sorted order.
+    /// See [`SgMap::keys`][crate::map::SgMap::keys].
+    pub fn keys(&self) -> Keys<'a, K, V, N> {
+        self.map.keys()
+    }
+
+    /// Gets an iterator over the values of the map, in order by key.
+    /// See [`SgMap::values`][crate::map::SgMap::values].
+    pub fn values(&self) -> Values<'a, K, V, N> {
+        self.map.values()
+    }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the map.
+    /// See [`SgMap::range`][crate::map::SgMap::range], including for panic conditions.
+    pub fn range<T, R>(&self, range: R) -> Range<'a, K, V, N>
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T>,
+        R: RangeBounds<T>,
+    {
+        self.map.range(range)
+    }
+
+    /// Returns the first key-value pair in the map.
+    /// See [`SgMap::first_key_value`][crate::map::SgMap::first_key_value].
+    pub fn first_key_value(&self) -> Option<(&'a K, &'a V)> {
+        self.map.first_key_value()
+    }
+
+    /// Returns the last key-value pair in the map.
+    /// See [`SgMap::last_key_value`][crate::map::SgMap::last_key_value].
+    pub fn last_key_value(&self) -> Option<(&'a K, &'a V)> {
+        self.map.last_key_value()
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Total capacity, e.g. maximum number of map pairs.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> IntoIterator for SgMapView<'a, K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<K: fmt::Debug + Ord, V: fmt::Debug, const N: usize> fmt::Debug for SgMapView<'_, K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.map, f)
+    }
+}
+
+// Node Access ---------------------------------------------------------------------------------------------------------
+
+/// A read-only reference to a single node of a [`SgMap`][crate::map::SgMap]'s arena.
+///
+/// Yielded by the [`nodes_in_order`][crate::map::SgMap::nodes_in_order] method on [`SgMap`][crate::map::SgMap].
+/// Exposes the node's entry and arena slot, but never its links, the free list, or any way to mutate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRef<'a, K, V> {
+    key: &'a K,
+    val: &'a V,
+    arena_idx: usize,
+}
+
+impl<'a, K, V> NodeRef<'a, K, V> {
+    /// Construct node reference.
+    pub(crate) fn new(key: &'a K, val: &'a V, arena_idx: usize) -> Self {
+        NodeRef {
+            key,
+            val,
+            arena_idx,
+        }
+    }
+
+    /// The node's key.
+    pub fn key(&self) -> &'a K {
+        self.key
+    }
+
+    /// The node's value.
+    pub fn val(&self) -> &'a V {
+        self.val
+    }
+
+    /// The node's slot in the arena, in `0..capacity`.
+    /// Unrelated to sorted position. Only stable until the map is next mutated.
+    pub fn arena_idx(&self) -> usize {
+        self.arena_idx
+    }
+}
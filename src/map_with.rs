@@ -0,0 +1,400 @@
+use core::cmp::Ordering;
+use core::fmt::{self, Debug};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
+
+use crate::map::SgMap;
+use crate::map_with_types::{IntoIter, Iter, Range};
+use crate::tree::SgError;
+
+/// A total order over keys of type `K`, used in place of `K`'s [`Ord`] implementation.
+///
+/// Comparators are stateless: the ordering is a property of the type `C`, so two maps with the same `C` always agree.
+/// Implementations must be a [total order](https://doc.rust-lang.org/std/cmp/trait.Ord.html#corollaries),
+/// otherwise lookups may miss and iteration order is unspecified (but memory safety is never affected).
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use escapegoat::Comparator;
+///
+/// struct TotalF64;
+///
+/// impl Comparator<f64> for TotalF64 {
+///     fn compare(a: &f64, b: &f64) -> Ordering {
+///         a.total_cmp(b)
+///     }
+/// }
+///
+/// assert_eq!(TotalF64::compare(&-0.0, &0.0), Ordering::Less);
+/// ```
+pub trait Comparator<K: ?Sized> {
+    /// Returns the ordering of `a` relative to `b`.
+    fn compare(a: &K, b: &K) -> Ordering;
+}
+
+/// Key wrapper whose [`Ord`] implementation delegates to `C`.
+/// This lets the underlying `SgTree` route every descent, removal, and range seek through the
+/// comparator without knowing it exists.
+#[repr(transparent)]
+pub(crate) struct CmpKey<K, C> {
+    pub(crate) key: K,
+    cmp: PhantomData<fn() -> C>,
+}
+
+impl<K, C> CmpKey<K, C> {
+    pub(crate) const fn new(key: K) -> Self {
+        CmpKey {
+            key,
+            cmp: PhantomData,
+        }
+    }
+
+    // View a borrowed key as a borrowed wrapper, for lookups.
+    pub(crate) fn from_ref(key: &K) -> &Self {
+        // SAFETY: `CmpKey` is `#[repr(transparent)]` and its only non-key field is a zero-sized `PhantomData`,
+        // so `&K` and `&CmpKey<K, C>` have identical layout and the lifetime is carried over unchanged.
+        unsafe { &*(key as *const K as *const Self) }
+    }
+}
+
+impl<K: Clone, C> Clone for CmpKey<K, C> {
+    fn clone(&self) -> Self {
+        CmpKey::new(self.key.clone())
+    }
+}
+
+impl<K, C: Comparator<K>> PartialEq for CmpKey<K, C> {
+    fn eq(&self, other: &Self) -> bool {
+        C::compare(&self.key, &other.key) == Ordering::Equal
+    }
+}
+
+impl<K, C: Comparator<K>> Eq for CmpKey<K, C> {}
+
+impl<K, C: Comparator<K>> PartialOrd for CmpKey<K, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, C: Comparator<K>> Ord for CmpKey<K, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::compare(&self.key, &other.key)
+    }
+}
+
+// Map a key bound to a wrapper bound.
+fn cmp_bound<K, C>(bound: Bound<&K>) -> Bound<&CmpKey<K, C>> {
+    match bound {
+        Bound::Included(k) => Bound::Included(CmpKey::from_ref(k)),
+        Bound::Excluded(k) => Bound::Excluded(CmpKey::from_ref(k)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Safe, fallible, embedded-friendly ordered map whose keys are ordered by a [`Comparator`] `C` instead of [`Ord`].
+///
+/// Use this for keys that aren't [`Ord`] (e.g. `f64`) or that need a domain-specific ordering without a newtype.
+/// Maps over [`Ord`] keys should keep using [`SgMap`][crate::map::SgMap], which has the fuller API.
+///
+/// ### Fallible APIs
+///
+/// * [`try_insert`][crate::map_with::SgMapWith::try_insert]
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use escapegoat::{Comparator, SgMapWith};
+///
+/// struct TotalF64;
+///
+/// impl Comparator<f64> for TotalF64 {
+///     fn compare(a: &f64, b: &f64) -> Ordering {
+///         a.total_cmp(b)
+///     }
+/// }
+///
+/// let mut readings = SgMapWith::<f64, &str, 10, TotalF64>::new();
+/// readings.insert(2.5, "b");
+/// readings.insert(-1.0, "a");
+/// readings.insert(f64::INFINITY, "c");
+///
+/// assert_eq!(readings.get(&2.5), Some(&"b"));
+/// assert_eq!(readings.first_key_value(), Some((&-1.0, &"a")));
+/// assert!(readings.keys().eq([-1.0, 2.5, f64::INFINITY].iter()));
+/// ```
+pub struct SgMapWith<K, V, const N: usize, C: Comparator<K>> {
+    map: SgMap<CmpKey<K, C>, V, N>,
+}
+
+impl<K, V, const N: usize, C: Comparator<K>> SgMapWith<K, V, N, C> {
+    /// Makes a new, empty `SgMapWith`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use escapegoat::{Comparator, SgMapWith};
+    ///
+    /// struct Descending;
+    ///
+    /// impl Comparator<u32> for Descending {
+    ///     fn compare(a: &u32, b: &u32) -> Ordering {
+    ///         b.cmp(a)
+    ///     }
+    /// }
+    ///
+    /// let mut map = SgMapWith::<_, _, 10, Descending>::new();
+    ///
+    /// map.insert(1, "a");
+    /// ```
+    pub const fn new() -> Self {
+        SgMapWith { map: SgMap::new() }
+    }
+
+    /// Returns the maximum capacity of the map (the const generic `N`).
+    pub const fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if the map's capacity is filled.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Inserts a key-value pair into the map.
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the value is updated, and the old value is returned.
+    /// The key is not updated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if insert would cause the map to exceed its stack capacity, `N`.
+    pub fn insert(&mut self, key: K, val: V) -> Option<V> {
+        self.map.insert(CmpKey::new(key), val)
+    }
+
+    /// Insert a key-value pair into the map.
+    /// Returns `Err` if the operation can't be completed, else the `Ok` contains:
+    /// * `None` if the map did not have this key present.
+    /// * The old value if the map did have this key present (both the value and the key are updated, this accommodates types that can be `==` without being identical).
+    ///
+    /// ### Warning
+    ///
+    /// Unlike other APIs in this crate, the semantics and return type of this API are *NOT* the same as `BTreeMap`'s nightly [`try_insert`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.try_insert).
+    pub fn try_insert(&mut self, key: K, val: V) -> Result<Option<V>, SgError> {
+        self.map.try_insert(CmpKey::new(key), val)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.map.get(CmpKey::from_ref(key))
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    pub fn get_key_value(&self, key: &K) -> Option<(&K, &V)> {
+        self.map
+            .get_key_value(CmpKey::from_ref(key))
+            .map(|(k, v)| (&k.key, v))
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.map.get_mut(CmpKey::from_ref(key))
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(CmpKey::from_ref(key))
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(CmpKey::from_ref(key))
+    }
+
+    /// Removes a key from the map, returning the stored key and value if the key was previously in the map.
+    pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        self.map
+            .remove_entry(CmpKey::from_ref(key))
+            .map(|(k, v)| (k.key, v))
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.map.retain(|k, v| f(&k.key, v));
+    }
+
+    /// Clears the map, removing all elements.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns a reference to the first key-value pair in the map.
+    /// The key in this pair is the minimum key under `C`.
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.map.first_key_value().map(|(k, v)| (&k.key, v))
+    }
+
+    /// Returns a reference to the last key-value pair in the map.
+    /// The key in this pair is the maximum key under `C`.
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.map.last_key_value().map(|(k, v)| (&k.key, v))
+    }
+
+    /// Removes and returns the first element in the map.
+    /// The key of this element is the minimum key under `C`.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.map.pop_first().map(|(k, v)| (k.key, v))
+    }
+
+    /// Removes and returns the last element in the map.
+    /// The key of this element is the maximum key under `C`.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.map.pop_last().map(|(k, v)| (k.key, v))
+    }
+
+    /// Gets an iterator over the entries of the map, sorted by `C`.
+    pub fn iter(&self) -> Iter<'_, K, V, N, C> {
+        Iter::new(self.map.iter())
+    }
+
+    /// Gets an iterator over the keys of the map, sorted by `C`.
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Gets an iterator over the values of the map, in key order under `C`.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the map.
+    /// Bounds are interpreted under `C`, so for a descending comparator `range(8..3)` is non-empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end` under `C`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use escapegoat::{Comparator, SgMapWith};
+    ///
+    /// struct Descending;
+    ///
+    /// impl Comparator<u32> for Descending {
+    ///     fn compare(a: &u32, b: &u32) -> Ordering {
+    ///         b.cmp(a)
+    ///     }
+    /// }
+    ///
+    /// let map: SgMapWith<_, _, 10, Descending> = (1..=9).map(|i| (i, i * 10)).collect();
+    /// assert!(map.range(8..5).map(|(k, _)| *k).eq([8, 7, 6]));
+    /// assert_eq!(map.range(..=2).next_back(), Some((&2, &20)));
+    /// ```
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V, N, C>
+    where
+        R: RangeBounds<K>,
+    {
+        let bounds = (
+            cmp_bound::<K, C>(range.start_bound()),
+            cmp_bound::<K, C>(range.end_bound()),
+        );
+        Range::new(self.map.range(bounds))
+    }
+}
+
+// Convenience Traits --------------------------------------------------------------------------------------------------
+
+// Debug
+impl<K, V, const N: usize, C> Debug for SgMapWith<K, V, N, C>
+where
+    K: Debug,
+    V: Debug,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// Default
+impl<K, V, const N: usize, C: Comparator<K>> Default for SgMapWith<K, V, N, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Clone
+impl<K, V, const N: usize, C> Clone for SgMapWith<K, V, N, C>
+where
+    K: Clone,
+    V: Clone,
+    C: Comparator<K>,
+{
+    fn clone(&self) -> Self {
+        SgMapWith {
+            map: self.map.clone(),
+        }
+    }
+}
+
+// Construct from iterator.
+impl<K, V, const N: usize, C: Comparator<K>> FromIterator<(K, V)> for SgMapWith<K, V, N, C> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = SgMapWith::new();
+        map.extend(iter);
+        map
+    }
+}
+
+// Extension from iterator.
+impl<K, V, const N: usize, C: Comparator<K>> Extend<(K, V)> for SgMapWith<K, V, N, C> {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(move |(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+// Iterators -----------------------------------------------------------------------------------------------------------
+
+// Reference iterator
+impl<'a, K, V, const N: usize, C: Comparator<K>> IntoIterator for &'a SgMapWith<K, V, N, C> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, N, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Consuming iterator
+impl<K, V, const N: usize, C: Comparator<K>> IntoIterator for SgMapWith<K, V, N, C> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.map.into_iter())
+    }
+}
@@ -0,0 +1,117 @@
+use core::iter::FusedIterator;
+
+use crate::map_types::{IntoIter as MapIntoIter, Iter as MapIter, Range as MapRange};
+use crate::map_with::{CmpKey, Comparator};
+
+// General Iterators ---------------------------------------------------------------------------------------------------
+
+/// An iterator over the entries of a [`SgMapWith`][crate::map_with::SgMapWith].
+///
+/// This `struct` is created by the [`iter`][crate::map_with::SgMapWith::iter] method on [`SgMapWith`][crate::map_with::SgMapWith].
+/// See its documentation for more.
+pub struct Iter<'a, K, V, const N: usize, C: Comparator<K>> {
+    ref_iter: MapIter<'a, CmpKey<K, C>, V, N>,
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Iter<'a, K, V, N, C> {
+    /// Construct reference iterator.
+    pub(crate) fn new(ref_iter: MapIter<'a, CmpKey<K, C>, V, N>) -> Self {
+        Iter { ref_iter }
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Iterator for Iter<'a, K, V, N, C> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ref_iter.next().map(|(k, v)| (&k.key, v))
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> ExactSizeIterator for Iter<'a, K, V, N, C> {
+    fn len(&self) -> usize {
+        self.ref_iter.len()
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> FusedIterator for Iter<'a, K, V, N, C> {}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Clone for Iter<'a, K, V, N, C> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
+
+/// An owning iterator over the entries of a [`SgMapWith`][crate::map_with::SgMapWith].
+///
+/// This `struct` is created by the [`into_iter`][crate::map_with::SgMapWith::into_iter] method on [`SgMapWith`][crate::map_with::SgMapWith]
+/// (provided by the `IntoIterator` trait). See its documentation for more.
+pub struct IntoIter<K, V, const N: usize, C: Comparator<K>> {
+    cons_iter: MapIntoIter<CmpKey<K, C>, V, N>,
+}
+
+impl<K, V, const N: usize, C: Comparator<K>> IntoIter<K, V, N, C> {
+    /// Construct owning iterator.
+    pub(crate) fn new(cons_iter: MapIntoIter<CmpKey<K, C>, V, N>) -> Self {
+        IntoIter { cons_iter }
+    }
+}
+
+impl<K, V, const N: usize, C: Comparator<K>> Iterator for IntoIter<K, V, N, C> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next().map(|(k, v)| (k.key, v))
+    }
+}
+
+impl<K, V, const N: usize, C: Comparator<K>> ExactSizeIterator for IntoIter<K, V, N, C> {
+    fn len(&self) -> usize {
+        self.cons_iter.len()
+    }
+}
+
+impl<K, V, const N: usize, C: Comparator<K>> FusedIterator for IntoIter<K, V, N, C> {}
+
+// Range Iterators -----------------------------------------------------------------------------------------------------
+
+/// An iterator over a sub-range of entries in a [`SgMapWith`][crate::map_with::SgMapWith].
+///
+/// This `struct` is created by the [`range`][crate::map_with::SgMapWith::range] method on [`SgMapWith`][crate::map_with::SgMapWith].
+/// See its documentation for more.
+pub struct Range<'a, K, V, const N: usize, C: Comparator<K>> {
+    range_iter: MapRange<'a, CmpKey<K, C>, V, N>,
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Range<'a, K, V, N, C> {
+    /// Construct range iterator.
+    pub(crate) fn new(range_iter: MapRange<'a, CmpKey<K, C>, V, N>) -> Self {
+        Range { range_iter }
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Iterator for Range<'a, K, V, N, C> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range_iter.next().map(|(k, v)| (&k.key, v))
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> DoubleEndedIterator for Range<'a, K, V, N, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range_iter.next_back().map(|(k, v)| (&k.key, v))
+    }
+}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> FusedIterator for Range<'a, K, V, N, C> {}
+
+impl<'a, K, V, const N: usize, C: Comparator<K>> Clone for Range<'a, K, V, N, C> {
+    fn clone(&self) -> Self {
+        Range {
+            range_iter: self.range_iter.clone(),
+        }
+    }
+}
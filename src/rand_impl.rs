@@ -0,0 +1,58 @@
+use arrayvec::ArrayVec;
+use rand::Rng;
+
+use crate::map::SgMap;
+
+/*
+Note:
+
+Selection sampling (Knuth's Algorithm S) visits entries in order, keeping each with probability
+`still_needed / still_unvisited`. Every `k`-subset is equally likely, and the result comes out sorted for free -
+no reservoir, index set, or sort required.
+*/
+
+// Map -----------------------------------------------------------------------------------------------------------------
+
+impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
+    /// Returns up to `k` distinct entries chosen uniformly at random, in ascending key order.
+    /// If `k` is at least the map's length, all entries are returned.
+    ///
+    /// Runs a single in-order pass, in `O(n)` time.
+    ///
+    /// Picks are returned in an [`ArrayVec`](arrayvec::ArrayVec) rather than a `Vec`, so the `rand` feature stays
+    /// `#![no_std]` without an allocator. At most `N` entries exist to pick, so it can't overflow.
+    /// Where `std` is available, `.to_vec()` or `.into_iter().collect()` converts it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter((0..10).map(|i| (i, i * i)));
+    /// let mut rng = rand::rng();
+    ///
+    /// let picks = map.sample(&mut rng, 3);
+    /// assert_eq!(picks.len(), 3);
+    /// assert!(picks.windows(2).all(|w| w[0].0 < w[1].0));
+    /// assert!(picks.iter().all(|(k, v)| **v == **k * **k));
+    ///
+    /// assert!(map.sample(&mut rng, 20).into_iter().eq(map.iter()));
+    /// ```
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R, k: usize) -> ArrayVec<(&K, &V), N> {
+        let mut unvisited = self.len();
+        let mut picks = ArrayVec::new();
+
+        for entry in self.iter() {
+            if picks.len() == k {
+                break;
+            }
+
+            if rng.random_range(0..unvisited) < (k - picks.len()) {
+                picks.push(entry);
+            }
+            unvisited -= 1;
+        }
+
+        picks
+    }
+}
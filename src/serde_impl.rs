@@ -0,0 +1,233 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::map::SgMap;
+use crate::set::SgSet;
+use crate::tree::SortedBuilder;
+
+/*
+Note:
+
+Maps serialize as a serde map and sets as a serde sequence, in ascending key order, matching `BTreeMap`/`BTreeSet`.
+Plain deserialization accepts any order (later duplicates overwrite earlier ones) and inserts entry by entry.
+`SortedSeq` deserialization requires strictly ascending keys and streams them straight into the arena, linking the
+tree once at the end. Either way, exceeding capacity `N` is reported as a serde error, never as a panic.
+*/
+
+const ERR_CAPACITY_EXCEEDED: &str = "input length exceeds stack capacity";
+const ERR_WRONG_ORDER: &str = "keys were not serialized in strictly ascending order";
+
+/// Wrapper selecting `O(n)` deserialization of known-sorted input, for [`SgMap`] and [`SgSet`].
+///
+/// Plain deserialization inserts entries one by one, in `O(n log n)` time. When the input was produced by serializing
+/// an [`SgMap`]/[`SgSet`] (or a `BTreeMap`/`BTreeSet`), keys arrive in ascending order and `SortedSeq` instead builds
+/// the balanced tree directly as entries stream in, in `O(n)` time. Input with out-of-order or duplicate keys, or more
+/// than `N` entries, is rejected with a deserialization error.
+///
+/// Serializing a `SortedSeq` is identical to serializing the wrapped collection.
+///
+/// # Examples
+///
+/// ```
+/// use escapegoat::{SgMap, SortedSeq};
+///
+/// let map = SgMap::<_, _, 10>::from_iter([(3, 'c'), (1, 'a'), (2, 'b')]);
+/// let json = serde_json::to_string(&map).unwrap();
+/// assert_eq!(json, r#"{"1":"a","2":"b","3":"c"}"#);
+///
+/// let SortedSeq(map_2) = serde_json::from_str::<SortedSeq<SgMap<u8, char, 10>>>(&json).unwrap();
+/// assert_eq!(map_2, map);
+///
+/// // Out-of-order input is rejected
+/// assert!(serde_json::from_str::<SortedSeq<SgMap<u8, char, 10>>>(r#"{"2":"b","1":"a"}"#).is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedSeq<T>(pub T);
+
+impl<T> SortedSeq<T> {
+    /// Unwraps the deserialized collection.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> Serialize for SortedSeq<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+// Reject early if the format reports a length that can't fit.
+fn check_size_hint<E: Error, const N: usize>(opt_len: Option<usize>) -> Result<(), E> {
+    match opt_len {
+        Some(len) if len > N => Err(E::custom(ERR_CAPACITY_EXCEEDED)),
+        _ => Ok(()),
+    }
+}
+
+// Map -----------------------------------------------------------------------------------------------------------------
+
+impl<K, V, const N: usize> Serialize for SgMap<K, V, N>
+where
+    K: Serialize + Ord,
+    V: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (k, v) in self {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
+}
+
+struct MapVisitor<K, V, const N: usize, const SORTED: bool> {
+    marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, K, V, const N: usize, const SORTED: bool> Visitor<'de> for MapVisitor<K, V, N, SORTED>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Value = SgMap<K, V, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with at most {} entries", N)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        check_size_hint::<A::Error, N>(access.size_hint())?;
+
+        if SORTED {
+            let mut builder = SortedBuilder::new();
+            while let Some((k, v)) = access.next_entry::<K, V>()? {
+                if builder.last_key().is_some_and(|last| *last >= k) {
+                    return Err(A::Error::custom(ERR_WRONG_ORDER));
+                }
+                builder
+                    .try_push(k, v)
+                    .map_err(|_| A::Error::custom(ERR_CAPACITY_EXCEEDED))?;
+            }
+            Ok(SgMap {
+                bst: builder.build(),
+            })
+        } else {
+            let mut map = SgMap::new();
+            while let Some((k, v)) = access.next_entry::<K, V>()? {
+                map.try_insert(k, v)
+                    .map_err(|_| A::Error::custom(ERR_CAPACITY_EXCEEDED))?;
+            }
+            Ok(map)
+        }
+    }
+}
+
+impl<'de, K, V, const N: usize> Deserialize<'de> for SgMap<K, V, N>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(MapVisitor::<K, V, N, false> {
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<'de, K, V, const N: usize> Deserialize<'de> for SortedSeq<SgMap<K, V, N>>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_map(MapVisitor::<K, V, N, true> {
+                marker: PhantomData,
+            })
+            .map(SortedSeq)
+    }
+}
+
+// Set -----------------------------------------------------------------------------------------------------------------
+
+impl<T, const N: usize> Serialize for SgSet<T, N>
+where
+    T: Serialize + Ord,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self {
+            seq.serialize_element(e)?;
+        }
+        seq.end()
+    }
+}
+
+struct SetVisitor<T, const N: usize, const SORTED: bool> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T, const N: usize, const SORTED: bool> Visitor<'de> for SetVisitor<T, N, SORTED>
+where
+    T: Deserialize<'de> + Ord,
+{
+    type Value = SgSet<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence with at most {} elements", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        check_size_hint::<A::Error, N>(access.size_hint())?;
+
+        if SORTED {
+            let mut builder = SortedBuilder::new();
+            while let Some(e) = access.next_element::<T>()? {
+                if builder.last_key().is_some_and(|last| *last >= e) {
+                    return Err(A::Error::custom(ERR_WRONG_ORDER));
+                }
+                builder
+                    .try_push(e, ())
+                    .map_err(|_| A::Error::custom(ERR_CAPACITY_EXCEEDED))?;
+            }
+            Ok(SgSet {
+                bst: builder.build(),
+            })
+        } else {
+            let mut set = SgSet::new();
+            while let Some(e) = access.next_element::<T>()? {
+                set.try_insert(e)
+                    .map_err(|_| A::Error::custom(ERR_CAPACITY_EXCEEDED))?;
+            }
+            Ok(set)
+        }
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for SgSet<T, N>
+where
+    T: Deserialize<'de> + Ord,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(SetVisitor::<T, N, false> {
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for SortedSeq<SgSet<T, N>>
+where
+    T: Deserialize<'de> + Ord,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_seq(SetVisitor::<T, N, true> {
+                marker: PhantomData,
+            })
+            .map(SortedSeq)
+    }
+}
@@ -1,13 +1,18 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
-use core::iter::FromIterator;
-use core::ops::RangeBounds;
+use core::hash::{Hash, Hasher};
+use core::iter::{FromIterator, Rev};
 use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::{RangeBounds, RangeInclusive};
 
+use arrayvec::ArrayVec;
+
+use crate::map::SgMap;
 use crate::set_types::{
-    Difference, Intersection, IntoIter, Iter, Range, SymmetricDifference, Union,
+    Difference, Discrete, Gaps, Intersection, IntoIter, Iter, Range, SymmetricDifference, Union,
 };
-use crate::tree::{Alpha, SgError, SgTree};
+use crate::tree::{Alpha, ArenaStats, SgError, SgTree, group_runs};
 
 /// Safe, fallible, embedded-friendly ordered set.
 ///
@@ -15,9 +20,19 @@ use crate::tree::{Alpha, SgError, SgTree};
 ///
 /// * [`try_insert`][crate::set::SgSet::try_insert]
 /// * [`try_append`][crate::set::SgSet::try_append]
+/// * [`append_from`][crate::set::SgSet::append_from]
 /// * [`try_extend`][crate::set::SgSet::try_extend]
+/// * [`insert_many`][crate::set::SgSet::insert_many]
+/// * [`replace_all`][crate::set::SgSet::replace_all]
 /// * [`try_from_iter`][crate::set::SgSet::try_from_iter]
+/// * [`from_slice`][crate::set::SgSet::from_slice]
+/// * [`from_refs`][crate::set::SgSet::from_refs]
+/// * [`resize`][crate::set::SgSet::resize]
 /// * [`try_replace`][crate::set::SgSet::try_replace]
+/// * [`union_with`][crate::set::SgSet::union_with]
+/// * [`symmetric_difference_with`][crate::set::SgSet::symmetric_difference_with]
+/// * [`union_set`][crate::set::SgSet::union_set]
+/// * [`symmetric_difference_set`][crate::set::SgSet::symmetric_difference_set]
 ///
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
@@ -27,7 +42,7 @@ use crate::tree::{Alpha, SgError, SgTree};
 ///
 /// The majority of API examples and descriptions are adapted or directly copied from the standard library's [`BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html).
 /// The goal is to offer embedded developers familiar, ergonomic APIs on resource constrained systems that otherwise don't get the luxury of dynamic collections.
-#[derive(Default, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
 pub struct SgSet<T: Ord, const N: usize> {
     pub(crate) bst: SgTree<T, (), N>,
 }
@@ -46,6 +61,19 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         SgSet { bst: SgTree::new() }
     }
 
+    /// An empty `SgSet`, equivalent to [`new`][SgSet::new], for initializing `const` and `static` items
+    /// (or fields of `const`-initialized structs) without a function call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// static NONE_RESERVED: SgSet<u16, 8> = SgSet::EMPTY;
+    /// assert!(NONE_RESERVED.is_empty());
+    /// ```
+    pub const EMPTY: Self = Self::new();
+
     /// The [original scapegoat tree paper's](https://people.csail.mit.edu/rivest/pubs/GR93.pdf) alpha, `a`, can be chosen in the range `0.5 <= a < 1.0`.
     /// `a` tunes how "aggressively" the data structure self-balances.
     /// It controls the trade-off between total rebuild time and maximum height guarantees.
@@ -75,6 +103,34 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.set_rebal_param(alpha)
     }
 
+    /// Re-tunes alpha on a live instance to `numerator / denominator`, then immediately rebuilds the tree
+    /// (like [`optimize`][SgSet::optimize]), so subsequent operations start from a balanced tree under the new bound.
+    /// Lets adaptive systems trade insert cost against query depth as their workload shifts.
+    /// See [`set_rebal_param`][SgSet::set_rebal_param] for what alpha controls.
+    ///
+    /// Returns `Err` if `0.5 <= numerator / denominator < 1.0` isn't `true` (including a zero `denominator`),
+    /// in which case the set is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, Alpha, SgError};
+    ///
+    /// let mut set = SgSet::<_, 100>::from_iter(0..100);
+    ///
+    /// // Read-heavy phase: rebalance strictly
+    /// assert!(set.set_alpha(3, 5).is_ok());
+    /// assert_eq!(set.rebal_param(), Alpha::from_num(3) / Alpha::from_num(5));
+    /// assert_eq!(set.debug_stats().height, 7); // Minimum for 100 elements
+    ///
+    /// assert_eq!(set.set_alpha(1, 3), Err(SgError::RebalanceFactorOutOfRange));
+    /// assert_eq!(set.set_alpha(1, 0), Err(SgError::RebalanceFactorOutOfRange));
+    /// ```
+    #[doc(alias = "rebalance")]
+    pub fn set_alpha(&mut self, numerator: u32, denominator: u32) -> Result<(), SgError> {
+        self.bst.set_alpha(numerator, denominator)
+    }
+
     /// Get the current rebalance parameter, alpha.
     /// See [the corresponding setter method][SgSet::set_rebal_param] for more details.
     ///
@@ -113,6 +169,155 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.capacity()
     }
 
+    /// Returns `true` if `required` elements fit within the set's stack capacity, `N`.
+    /// Being a `const fn` without a receiver, it can be evaluated at compile time, e.g. within a `const { }` block.
+    /// See [`assert_capacity`][crate::assert_capacity] for a build-time assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// const FITS: bool = SgSet::<u8, 10>::fits(8);
+    /// assert!(FITS);
+    /// assert!(!SgSet::<u8, 10>::fits(11));
+    /// ```
+    pub const fn fits(required: usize) -> bool {
+        required <= N
+    }
+
+    /// Returns a read-only snapshot of the set's arena usage: live and free slots, capacity,
+    /// current tree height, and the number of rebuilds performed so far.
+    /// Computing the height visits every node, so this is intended for debugging and profiling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{ArenaStats, SgSet};
+    ///
+    /// let mut set = SgSet::<_, 10>::new();
+    /// for i in 0..4 {
+    ///     set.insert(i);
+    /// }
+    /// set.remove(&0);
+    ///
+    /// let stats: ArenaStats = set.debug_stats();
+    /// assert_eq!(stats.live, 3);
+    /// assert_eq!(stats.free, 1);
+    /// assert_eq!(stats.capacity, 10);
+    /// assert!(stats.height >= 2);
+    /// ```
+    pub fn debug_stats(&self) -> ArenaStats {
+        self.bst.debug_stats()
+    }
+
+    /// Rebuilds the set's entire tree into a perfectly balanced shape, in `O(n)` time.
+    ///
+    /// Scapegoat trees rebalance lazily, so a burst of removals can leave the tree taller than necessary
+    /// until a later operation triggers a rebuild. Calling this during a quiet period moves that cost
+    /// out of latency-sensitive code paths. Contents and iteration order are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 100>::from_iter(0..100);
+    /// set.retain(|k| *k % 3 == 0);
+    ///
+    /// set.optimize();
+    /// assert_eq!(set.len(), 34);
+    /// assert_eq!(set.debug_stats().height, 6); // Minimum for 34 elements
+    /// ```
+    pub fn optimize(&mut self) {
+        self.bst.optimize()
+    }
+
+    /// Returns a deterministic 64-bit hash of the set's contents, for cheap change detection
+    /// (e.g. invalidating a cached computation derived from the set).
+    ///
+    /// The length and all elements are hashed in sorted order using 64-bit
+    /// [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/index.html) with its standard, fixed offset basis.
+    /// Unlike hashing with `std`'s randomly-seeded `DefaultHasher`, the result is reproducible across runs.
+    /// It depends only on logical contents: capacity, insertion order, and internal tree shape don't affect it.
+    ///
+    /// **Not cryptographic:** collisions can be constructed deliberately, so don't use this to detect tampering.
+    /// Values are only comparable between builds of the same target, since integers are hashed in native byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    ///
+    /// // Same contents, different insertion order and capacity
+    /// let b = SgSet::<_, 20>::from_iter([3, 2, 1]);
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    ///
+    /// let before = a.content_hash();
+    /// a.remove(&2);
+    /// assert_ne!(a.content_hash(), before);
+    /// ```
+    pub fn content_hash(&self) -> u64
+    where
+        T: Hash,
+    {
+        self.bst.content_hash()
+    }
+
+    /// Feeds the set's length and elements, in sorted order, into `hasher`, decoupling the hash algorithm from the
+    /// container (e.g. to plug in SipHash or xxHash instead of [`content_hash`][Self::content_hash]'s FNV-1a).
+    /// The [`Hash`] implementation is built on this method, so both produce the same stream of writes.
+    ///
+    /// Since elements are fed in sorted order, the result doesn't depend on insertion order:
+    /// sets with equal contents feed identical input, regardless of capacity or internal tree shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::hash::Hasher;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 20>::from_iter([3, 2, 1]);
+    ///
+    /// let mut hasher_a = DefaultHasher::new();
+    /// let mut hasher_b = DefaultHasher::new();
+    /// a.hash_with(&mut hasher_a);
+    /// b.hash_with(&mut hasher_b);
+    /// assert_eq!(hasher_a.finish(), hasher_b.finish());
+    /// ```
+    pub fn hash_with<H: Hasher>(&self, hasher: &mut H)
+    where
+        T: Hash,
+    {
+        self.bst.hash_with(hasher)
+    }
+
+    /// Clones the set, for parity with fallible APIs: storage is fixed-capacity, so cloning can't fail to allocate
+    /// and this never returns `Err`.
+    ///
+    /// Like [`Clone::clone`], this is panic-safe: if cloning an element panics part way through, every element
+    /// already cloned is dropped during unwinding, nothing is leaked and the original set is untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let copy = set.try_clone().unwrap();
+    /// assert_eq!(copy, set);
+    /// ```
+    pub fn try_clone(&self) -> Result<Self, SgError>
+    where
+        T: Clone,
+    {
+        Ok(self.clone())
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
     /// # Examples
@@ -203,6 +408,34 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.try_append(&mut other.bst)
     }
 
+    /// Attempts to move all elements from `other`, a set of any capacity, into `self`, leaving `other` empty.
+    ///
+    /// Returns [`SgError::StackCapacityExceeded`] if `self` cannot hold the union of both sets.
+    /// The check is done up front, so on failure neither `self` nor `other` is modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let mut a = SgSet::<_, 3>::from_iter([1, 2]);
+    /// let mut b = SgSet::<_, 10>::from_iter([2, 3]);
+    ///
+    /// assert!(a.append_from(&mut b).is_ok());
+    /// assert!(b.is_empty());
+    /// assert!(a.into_iter().eq([1, 2, 3]));
+    ///
+    /// let mut small = SgSet::<_, 2>::from_iter([1]);
+    /// let mut big = SgSet::<_, 10>::from_iter([2, 3]);
+    ///
+    /// assert_eq!(small.append_from(&mut big), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(small.len(), 1);
+    /// assert_eq!(big.len(), 2);
+    /// ```
+    pub fn append_from<const M: usize>(&mut self, other: &mut SgSet<T, M>) -> Result<(), SgError> {
+        self.bst.try_append_from(&mut other.bst)
+    }
+
     /// Adds a value to the set.
     /// If the set did not have this value present, `true` is returned.
     /// If the set did have this value present, `false` is returned, and the entry is overwritten.
@@ -225,6 +458,38 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.insert(value, ()).is_none()
     }
 
+    /// Adds a value to the set, unless an equal value is already present.
+    /// Returns `true` if the value was newly inserted, `false` if it was already present.
+    ///
+    /// Unlike [`insert`][crate::set::SgSet::insert], an existing equal value is left untouched, not overwritten.
+    /// The check and insertion share a single tree descent, rather than a [`contains`][crate::set::SgSet::contains]
+    /// followed by an `insert`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is absent and the set is already at capacity.
+    /// See [`try_insert`][crate::set::SgSet::try_insert] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut seen = SgSet::<_, 10>::new();
+    ///
+    /// assert!(seen.insert_if_absent(7));
+    /// assert!(!seen.insert_if_absent(7));
+    /// assert_eq!(seen.len(), 1);
+    /// ```
+    pub fn insert_if_absent(&mut self, value: T) -> bool
+    where
+        T: Ord,
+    {
+        let old_len = self.len();
+        self.bst.upsert(value, (), |_| {});
+        self.len() > old_len
+    }
+
     /// Adds a value to the set.
     /// Returns `Err` if the operation can't be completed, else the `Ok` contains:
     /// * `true` if the set did not have this value present.
@@ -298,13 +563,67 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
     ) -> Result<(), SgError> {
         // Derp :P
         if iter.len() <= (self.capacity() - self.len()) {
-            let map: crate::SgMap<T, (), N> = iter.into_iter().map(|e| (e, ())).collect();
+            let map: SgMap<T, (), N> = iter.into_iter().map(|e| (e, ())).collect();
             self.bst.try_extend(map.into_iter())
         } else {
             Err(SgError::StackCapacityExceeded)
         }
     }
 
+    /// Insert all values from an iterator in bulk, returning the number of new values added.
+    /// If a value appears more than once, the last one wins (as with [`replace`][SgSet::replace]).
+    ///
+    /// Faster than repeated [`insert`][SgSet::insert] for large batches of unsorted input:
+    /// values are sorted once, merged with the existing elements, and the tree is rebuilt balanced in a single pass.
+    ///
+    /// All-or-nothing: returns `Err`, leaving the set unmodified, if the new values don't fit within capacity.
+    /// The set is likewise left unmodified if the iterator or a comparison panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 4>::new();
+    /// set.insert(2);
+    ///
+    /// assert_eq!(set.insert_many([3, 1, 2, 3]), Ok(2));
+    /// assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    ///
+    /// let mut set = SgSet::<_, 2>::new();
+    /// assert_eq!(set.insert_many([1, 2, 3]), Err(SgError::StackCapacityExceeded));
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<usize, SgError> {
+        self.bst.insert_many(iter.into_iter().map(|e| (e, ())))
+    }
+
+    /// Replaces the set's entire contents with values from an iterator, transactionally.
+    ///
+    /// New contents are built in a temporary set on the stack and only swapped in once every value fits,
+    /// so the original contents are dropped on success but left intact on failure.
+    /// Useful for a "refresh from source" pattern, where a partial load would be worse than a stale one.
+    ///
+    /// Returns `Err` if the values don't fit within capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 3>::from_iter([1, 2]);
+    ///
+    /// assert_eq!(set.replace_all([9, 8, 7, 8]), Ok(()));
+    /// assert!(set.iter().eq([7, 8, 9].iter()));
+    ///
+    /// // Overflow, original contents kept
+    /// assert_eq!(set.replace_all(1..=4), Err(SgError::StackCapacityExceeded));
+    /// assert!(set.iter().eq([7, 8, 9].iter()));
+    /// ```
+    pub fn replace_all<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), SgError> {
+        self.bst.try_replace_all(iter.into_iter().map(|e| (e, ())))
+    }
+
     /// Attempt conversion from an iterator.
     /// Will fail if iterator length exceeds `u16::MAX`.
     ///
@@ -335,6 +654,156 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Creates a set whose capacity `N` is exactly the length of `arr`, so construction can never overflow.
+    /// Useful when an array literal is the complete dataset: capacity is inferred, not spelled out.
+    ///
+    /// Equivalent to the [`From`] array conversion, but names the intent at the call site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::from_exact([3, 4, 1, 2, 5, 6]);
+    /// assert_eq!(set.capacity(), 6);
+    /// assert!(set.is_full());
+    /// assert!(set.into_iter().eq([1, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn from_exact(arr: [T; N]) -> Self {
+        Self::from(arr)
+    }
+
+    /// Creates a set by cloning the elements of `slice`, which may be unsorted and contain duplicates.
+    /// When the slice fits in the set, elements are sorted in a fixed-size scratch buffer and the tree is built
+    /// balanced in a single pass, with no per-element rebalancing.
+    ///
+    /// Returns `Err` if the number of unique elements exceeds the set's capacity `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgSet};
+    ///
+    /// let set = SgSet::<_, 4>::from_slice(&[3, 1, 3, 2, 1]).unwrap();
+    /// assert!(set.into_iter().eq([1, 2, 3]));
+    ///
+    /// assert_eq!(
+    ///     SgSet::<_, 2>::from_slice(&[1, 2, 3]),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Result<Self, SgError>
+    where
+        T: Clone,
+    {
+        // Too long for the scratch buffer, but duplicates might still let it fit
+        if slice.len() > N {
+            let mut set = Self::new();
+            for elem in slice {
+                set.try_insert(elem.clone())?;
+            }
+            return Ok(set);
+        }
+
+        let mut sorted: ArrayVec<&T, N> = slice.iter().collect();
+        sorted.sort_unstable();
+
+        let mut prev = None;
+        let unique = sorted
+            .into_iter()
+            .filter(|elem| prev.replace(*elem) != Some(*elem));
+
+        SgTree::try_from_sorted_iter(unique.map(|elem| (elem.clone(), ()))).map(|bst| SgSet { bst })
+    }
+
+    /// Creates a set from an iterator of borrowed elements, cloning each one as it's inserted.
+    /// Avoids collecting the borrowed data into an owned buffer just to call [`from_iter`][Self::from_iter].
+    /// Duplicates are ignored.
+    ///
+    /// Returns `Err` ([`SgError::StackCapacityExceeded`]) if the iterator yields more distinct elements than capacity `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let vec = vec![3, 1, 3, 2];
+    ///
+    /// let set = SgSet::<_, 10>::from_refs(&vec).unwrap();
+    /// assert!(set.into_iter().eq([1, 2, 3]));
+    ///
+    /// assert_eq!(
+    ///     SgSet::<_, 2>::from_refs(&vec),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn from_refs<'a, I>(iter: I) -> Result<Self, SgError>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: Clone + 'a,
+    {
+        let mut set = Self::new();
+        for elem in iter {
+            set.try_insert(elem.clone())?;
+        }
+        Ok(set)
+    }
+
+    /// Moves all elements into a new set with capacity `M`.
+    /// Since elements are already sorted, the new set's tree is built balanced in a single pass.
+    ///
+    /// Returns `Err` containing the original, unmodified set if `M` is less than the current length
+    /// (or `M` exceeds `u16::MAX`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let set = SgSet::<_, 10>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    ///
+    /// // Grow
+    /// let set = set.resize::<20>().unwrap();
+    /// assert_eq!(set.capacity(), 20);
+    /// assert!(set.contains(&10));
+    ///
+    /// // Too small, original set returned
+    /// let (set, err) = set.resize::<5>().unwrap_err();
+    /// assert_eq!(err, SgError::StackCapacityExceeded);
+    /// assert_eq!(set.len(), 10);
+    /// ```
+    pub fn resize<const M: usize>(self) -> Result<SgSet<T, M>, (Self, SgError)> {
+        match self.bst.resize::<M>() {
+            Ok(bst) => Ok(SgSet { bst }),
+            Err((bst, err)) => Err((SgSet { bst }, err)),
+        }
+    }
+
+    /// Consumes the set, returning a map with each element as a key and `f(element)` as its value,
+    /// `f` being called in ascending order.
+    /// The sorted tree structure is reused as is, so no re-sorting or rebalancing is needed.
+    ///
+    /// The reverse conversion, dropping values, is `SgSet::from(map)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter(["bb", "a", "ccc"]);
+    /// let lens = set.into_map_with(|s| s.len());
+    ///
+    /// assert!(lens.into_iter().eq([("a", 1), ("bb", 2), ("ccc", 3)]));
+    /// ```
+    pub fn into_map_with<V, F>(self, mut f: F) -> SgMap<T, V, N>
+    where
+        F: FnMut(&T) -> V,
+    {
+        SgMap {
+            bst: self.bst.map_entries(|k, _| f(k)),
+        }
+    }
+
     /// Gets an iterator that visits the values in the `SgSet` in ascending order.
     ///
     /// # Examples
@@ -366,54 +835,124 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         Iter::new(self)
     }
 
-    /// Removes a value from the set. Returns whether the value was
-    /// present in the set.
-    ///
-    /// The value may be any borrowed form of the set's value type,
-    /// but the ordering on the borrowed form *must* match the
-    /// ordering on the value type.
+    /// Gets an iterator over owned clones of the set's values, in ascending order.
+    /// Shorthand for `set.iter().cloned()`.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgSet;
     ///
-    /// let mut set = SgSet::<_, 10>::new();
+    /// let set = SgSet::<_, 10>::from_iter([String::from("b"), String::from("a")]);
     ///
-    /// set.insert(2);
-    /// assert_eq!(set.remove(&2), true);
-    /// assert_eq!(set.remove(&2), false);
+    /// let owned: Vec<String> = set.cloned_iter().collect();
+    /// assert_eq!(owned, ["a", "b"]);
     /// ```
-    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    pub fn cloned_iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator
     where
-        T: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
+        T: Clone,
     {
-        self.bst.remove(value).is_some()
+        self.iter().cloned()
     }
 
-    /// Splits the collection into two at the given value. Returns everything after the given value,
-    /// including the value.
+    /// Gets an iterator over copies of the set's values, in ascending order.
+    /// Shorthand for `set.iter().copied()`, for [`Copy`] values.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgSet;
     ///
-    /// let mut a = SgSet::<_, 5>::new();
-    /// a.insert(1);
-    /// a.insert(2);
-    /// a.insert(3);
-    /// a.insert(17);
-    /// a.insert(41);
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 2]);
     ///
-    /// let b = a.split_off(&3);
+    /// assert!(set.copied_iter().eq([1, 2, 3]));
+    /// ```
+    pub fn copied_iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator
+    where
+        T: Copy,
+    {
+        self.iter().copied()
+    }
+
+    /// Calls `f` on each element in ascending order, stopping at and returning the first `Err`.
     ///
-    /// assert_eq!(a.len(), 2);
-    /// assert_eq!(b.len(), 3);
+    /// A fallible `for_each`, e.g. for custom serialization to a fixed buffer or peripheral without `serde`,
+    /// aborting as soon as a write fails.
     ///
-    /// assert!(a.contains(&1));
-    /// assert!(a.contains(&2));
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 2, 4]);
+    ///
+    /// let mut out = [0; 3];
+    /// let mut cnt = 0;
+    /// let res = set.for_each_sorted(|e| {
+    ///     let slot = out.get_mut(cnt).ok_or(*e)?;
+    ///     *slot = *e;
+    ///     cnt += 1;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(res, Err(4)); // First element that didn't fit
+    /// assert_eq!(out, [1, 2, 3]);
+    /// ```
+    pub fn for_each_sorted<E, F>(&self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        self.iter().try_for_each(f)
+    }
+
+    /// Removes a value from the set. Returns whether the value was
+    /// present in the set.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 10>::new();
+    ///
+    /// set.insert(2);
+    /// assert_eq!(set.remove(&2), true);
+    /// assert_eq!(set.remove(&2), false);
+    /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove(value).is_some()
+    }
+
+    /// Splits the collection into two at the given value. Returns everything after the given value,
+    /// including the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut a = SgSet::<_, 5>::new();
+    /// a.insert(1);
+    /// a.insert(2);
+    /// a.insert(3);
+    /// a.insert(17);
+    /// a.insert(41);
+    ///
+    /// let b = a.split_off(&3);
+    ///
+    /// assert_eq!(a.len(), 2);
+    /// assert_eq!(b.len(), 3);
+    ///
+    /// assert!(a.contains(&1));
+    /// assert!(a.contains(&2));
     ///
     /// assert!(b.contains(&3));
     /// assert!(b.contains(&17));
@@ -429,6 +968,30 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Consumes the set, splitting it into `(less_than_value, greater_or_equal_to_value)`.
+    /// Both halves keep capacity `N`, so this can't overflow.
+    /// Unlike [`split_off`][crate::set::SgSet::split_off], each half is built balanced directly from its sorted partition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 2, 3, 17, 41]);
+    /// let (lo, hi) = set.split_at_key(&3);
+    ///
+    /// assert!(lo.into_iter().eq([1, 2]));
+    /// assert!(hi.into_iter().eq([3, 17, 41]));
+    /// ```
+    pub fn split_at_key<Q>(self, value: &Q) -> (SgSet<T, N>, SgSet<T, N>)
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let (lt_bst, ge_bst) = self.bst.split_at_key(value);
+        (SgSet { bst: lt_bst }, SgSet { bst: ge_bst })
+    }
+
     /// Adds a value to the set, replacing the existing value, if any, that is equal to the given
     /// one. Returns the replaced value.
     ///
@@ -512,6 +1075,55 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.retain(|k, _| f(k));
     }
 
+    /// Retains only the elements specified by the predicate, returning the number of elements removed.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns `false`.
+    /// The elements are visited in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let xs = [1, 2, 3, 4, 5, 6];
+    /// let mut set: SgSet<i32, 10> = xs.iter().cloned().collect();
+    /// // Keep only the even numbers.
+    /// assert_eq!(set.retain_count(|&k| k % 2 == 0), 3);
+    /// assert!(set.iter().eq([2, 4, 6].iter()));
+    /// ```
+    pub fn retain_count<F>(&mut self, mut f: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> bool,
+    {
+        self.bst.retain_count(|k, _| f(k))
+    }
+
+    /// Removes every value yielded by `values` from the set, returning the number of values removed.
+    /// Values not in the set are ignored.
+    ///
+    /// Rather than descending once per value, the set is merged against `values` in a single pass and
+    /// rebuilt once. This is fastest when `values` are in ascending order; unsorted input is still
+    /// handled correctly, but each out-of-order value costs an `O(log n)` search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 10>::from_iter(0..8);
+    /// assert_eq!(set.remove_many(&[1, 3, 5, 9]), 3);
+    /// assert!(set.iter().eq([0, 2, 4, 6, 7].iter()));
+    /// ```
+    pub fn remove_many<'a, Q, I>(&mut self, values: I) -> usize
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        self.bst.remove_many(values)
+    }
+
     /// Returns a reference to the value in the set, if any, that is equal to the given value.
     ///
     /// The value may be any borrowed form of the set's value type,
@@ -535,6 +1147,53 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.get_key_value(value).map(|(k, _)| k)
     }
 
+    /// Returns a reference to the value in the set that is equal to the given value, or `default` if there is none.
+    /// The set is never modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter(["info", "warn"]);
+    /// assert_eq!(set.get_or(&"warn", &"info"), &"warn");
+    /// assert_eq!(set.get_or(&"trace", &"info"), &"info");
+    /// ```
+    pub fn get_or<'a, Q>(&'a self, value: &Q, default: &'a T) -> &'a T
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.get(value).unwrap_or(default)
+    }
+
+    /// Initializes every arena slot up front, for deterministic insert timing afterwards.
+    ///
+    /// Arena slots are initialized lazily, the first time an insert needs one, so early inserts are the first to
+    /// write (and, on targets with caches or paged memory, the first to touch) that part of the set's storage.
+    /// Calling this once, e.g. during startup, moves that cost out of latency-sensitive code paths.
+    /// The gain is target-dependent: on desktop hosts, the `sgs_first_insert_*` benchmarks show no measurable difference.
+    /// Contents, capacity, and the order slots are handed out in are unchanged. Calling it again is a no-op.
+    ///
+    /// Operations that reset the set (e.g. [`clear`][SgSet::clear]) return its arena to the lazily initialized state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<u32, 64>::new();
+    /// set.prewarm();
+    /// assert_eq!(set.debug_stats().free, 64);
+    ///
+    /// set.insert(1);
+    /// assert_eq!(set.len(), 1);
+    /// assert_eq!(set.debug_stats().free, 63);
+    /// ```
+    pub fn prewarm(&mut self) {
+        self.bst.prewarm()
+    }
+
     /// Clears the set, removing all values.
     ///
     /// # Examples
@@ -574,6 +1233,144 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.contains_key(value)
     }
 
+    /// Returns `true` if the set contains every one of `items`, stopping at the first miss.
+    /// Each item is a separate `O(log n)` lookup, so `items` may be in any order.
+    /// Vacuously `true` for no items.
+    ///
+    /// For items already in ascending order, [`contains_all_sorted`][SgSet::contains_all_sorted] checks them
+    /// in a single merge pass instead. Stable Rust can't pick that path automatically (no specialization).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let caps = SgSet::<_, 10>::from_iter(["net", "fs", "time"]);
+    /// assert!(caps.contains_all(["time", "net"].iter()));
+    /// assert!(!caps.contains_all(&["net", "gpu"]));
+    /// ```
+    pub fn contains_all<'a, Q, I>(&self, items: I) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        items.into_iter().all(|item| self.contains(item))
+    }
+
+    /// Returns `true` if the set contains at least one of `items`, stopping at the first hit.
+    /// Each item is a separate `O(log n)` lookup, so `items` may be in any order.
+    /// Always `false` for no items.
+    ///
+    /// For items already in ascending order, [`contains_any_sorted`][SgSet::contains_any_sorted] checks them
+    /// in a single merge pass instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let caps = SgSet::<_, 10>::from_iter(["net", "fs", "time"]);
+    /// assert!(caps.contains_any(&["gpu", "fs"]));
+    /// assert!(!caps.contains_any(&["gpu", "usb"]));
+    /// ```
+    pub fn contains_any<'a, Q, I>(&self, items: I) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        items.into_iter().any(|item| self.contains(item))
+    }
+
+    /// Like [`contains_all`][SgSet::contains_all], but for `items` in ascending order (duplicates allowed),
+    /// e.g. another set's [`iter`][SgSet::iter] or a sorted slice. Walks both sequences once, so it runs in
+    /// `O(n + m)` time instead of `O(m log n)`, and stops at the first miss.
+    ///
+    /// The result is unspecified (but memory-safe) if `items` isn't sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter(1..=8);
+    /// let required = SgSet::<_, 4>::from_iter([2, 3, 5, 7]);
+    ///
+    /// assert!(set.contains_all_sorted(required.iter()));
+    /// assert!(!set.contains_all_sorted(&[2, 9]));
+    /// ```
+    pub fn contains_all_sorted<'a, Q, I>(&self, items: I) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        let mut self_iter = self.iter().peekable();
+        items.into_iter().all(|item| {
+            while self_iter.next_if(|v| (*v).borrow() < item).is_some() {}
+            self_iter.peek().is_some_and(|v| (*v).borrow() == item)
+        })
+    }
+
+    /// Like [`contains_any`][SgSet::contains_any], but for `items` in ascending order (duplicates allowed).
+    /// Walks both sequences once, so it runs in `O(n + m)` time instead of `O(m log n)`, and stops at the first hit.
+    ///
+    /// The result is unspecified (but memory-safe) if `items` isn't sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([10, 20, 30]);
+    ///
+    /// assert!(set.contains_any_sorted(&[5, 15, 30]));
+    /// assert!(!set.contains_any_sorted(&[5, 15, 25, 35]));
+    /// ```
+    pub fn contains_any_sorted<'a, Q, I>(&self, items: I) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        let mut self_iter = self.iter().peekable();
+        items.into_iter().any(|item| {
+            while self_iter.next_if(|v| (*v).borrow() < item).is_some() {}
+            self_iter.peek().is_some_and(|v| (*v).borrow() == item)
+        })
+    }
+
+    /// Returns the values bracketing `value`: the largest value `<= value`, and the smallest value `> value`.
+    /// Both are found in a single descent.
+    ///
+    /// An exact match is returned as the lower bracket, paired with its successor.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([10, 20, 30]);
+    ///
+    /// assert_eq!(set.bracket(&25), (Some(&20), Some(&30)));
+    /// assert_eq!(set.bracket(&20), (Some(&20), Some(&30)));
+    /// assert_eq!(set.bracket(&5), (None, Some(&10)));
+    /// assert_eq!(set.bracket(&35), (Some(&30), None));
+    /// ```
+    pub fn bracket<Q>(&self, value: &Q) -> (Option<&T>, Option<&T>)
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let (opt_lower, opt_upper) = self.bst.bracket(value);
+        (opt_lower.map(|(k, _)| k), opt_upper.map(|(k, _)| k))
+    }
+
     /// Returns a reference to the first/minium value in the set, if any.
     ///
     /// # Examples
@@ -618,6 +1415,51 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.pop_first().map(|(k, _)| k)
     }
 
+    /// Removes the first value from the set and returns it, only if `pred` returns `true` for it.
+    /// Otherwise the set is left unchanged and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// assert_eq!(set.pop_first_if(|n| *n > 1), None);
+    /// assert_eq!(set.pop_first_if(|n| *n == 1), Some(1));
+    /// assert_eq!(set.first(), Some(&2));
+    /// ```
+    pub fn pop_first_if<F>(&mut self, pred: F) -> Option<T>
+    where
+        T: Ord,
+        F: FnOnce(&T) -> bool,
+    {
+        self.bst.pop_first_if(|k, _| pred(k)).map(|(k, _)| k)
+    }
+
+    /// Consumes the set, returning its first (minimum) value and a set of the remaining values,
+    /// or `None` if the set is empty. The remainder is the same set with one value popped, so no rebuild is needed.
+    ///
+    /// Suits "process the head, recurse on the tail" decomposition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// fn sum(set: SgSet<u32, 10>) -> u32 {
+    ///     match set.split_first() {
+    ///         Some((head, tail)) => head + sum(tail),
+    ///         None => 0,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum(SgSet::from_iter([3, 1, 2])), 6);
+    /// ```
+    pub fn split_first(mut self) -> Option<(T, SgSet<T, N>)> {
+        let first = self.pop_first()?;
+        Some((first, self))
+    }
+
     /// Returns the last/maximum value in the set, if any.
     ///
     /// # Examples
@@ -662,42 +1504,249 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         self.bst.pop_last().map(|(k, _)| k)
     }
 
-    /// Returns the number of elements in the set.
+    /// Removes the last value from the set and returns it, only if `pred` returns `true` for it.
+    /// Otherwise the set is left unchanged and `None` is returned.
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgSet;
     ///
-    /// let mut v = SgSet::<_, 10>::new();
-    /// assert_eq!(v.len(), 0);
-    /// v.insert(1);
-    /// assert_eq!(v.len(), 1);
+    /// let mut set = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// assert_eq!(set.pop_last_if(|n| *n < 3), None);
+    /// assert_eq!(set.pop_last_if(|n| *n == 3), Some(3));
+    /// assert_eq!(set.last(), Some(&2));
     /// ```
-    pub fn len(&self) -> usize {
-        self.bst.len()
+    pub fn pop_last_if<F>(&mut self, pred: F) -> Option<T>
+    where
+        T: Ord,
+        F: FnOnce(&T) -> bool,
+    {
+        self.bst.pop_last_if(|k, _| pred(k)).map(|(k, _)| k)
     }
 
-    /// Constructs a double-ended iterator over a sub-range of elements in the set.
-    /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
-    /// yield elements from min (inclusive) to max (exclusive).
-    /// The range may also be entered as `(Bound<T>, Bound<T>)`, so for example
-    /// `range((Excluded(4), Included(10)))` will yield a left-exclusive, right-inclusive
-    /// range from 4 to 10.
-    ///
-    /// # Panics
-    ///
-    /// Panics if range `start > end`.
-    /// Panics if range `start == end` and both bounds are `Excluded`.
+    /// Consumes the set, returning its last (maximum) value and a set of the remaining values,
+    /// or `None` if the set is empty. See [`split_first`][crate::set::SgSet::split_first].
     ///
     /// # Examples
     ///
     /// ```
     /// use escapegoat::SgSet;
-    /// use core::ops::Bound::Included;
     ///
-    /// let mut set = SgSet::<_, 5>::new();
-    /// set.insert(3);
+    /// let set = SgSet::<_, 10>::from_iter([2, 1, 3]);
+    ///
+    /// let (last, rest) = set.split_last().unwrap();
+    /// assert_eq!(last, 3);
+    /// assert!(rest.into_iter().eq([1, 2]));
+    /// ```
+    pub fn split_last(mut self) -> Option<(T, SgSet<T, N>)> {
+        let last = self.pop_last()?;
+        Some((last, self))
+    }
+
+    /// Keeps the `k` smallest elements, removing the rest. Does nothing if `k` is at least [`len`][crate::set::SgSet::len].
+    ///
+    /// Unlike calling [`pop_last`][crate::set::SgSet::pop_last] in a loop, the surplus is removed in one pass
+    /// and the survivors are rebuilt once, into a balanced tree. Useful for capping the size of a sorted cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut cache = SgSet::<_, 10>::from_iter([3, 1, 4, 2]);
+    /// cache.truncate_first(2);
+    /// assert!(cache.into_iter().eq([1, 2]));
+    /// ```
+    pub fn truncate_first(&mut self, k: usize)
+    where
+        T: Ord,
+    {
+        self.bst.truncate_first(k)
+    }
+
+    /// Keeps the `k` largest elements, removing the rest. Does nothing if `k` is at least [`len`][crate::set::SgSet::len].
+    ///
+    /// Unlike calling [`pop_first`][crate::set::SgSet::pop_first] in a loop, the surplus is removed in one pass
+    /// and the survivors are rebuilt once, into a balanced tree. Useful for capping the size of a sorted cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut cache = SgSet::<_, 10>::from_iter([3, 1, 4, 2]);
+    /// cache.truncate_last(2);
+    /// assert!(cache.into_iter().eq([3, 4]));
+    /// ```
+    pub fn truncate_last(&mut self, k: usize)
+    where
+        T: Ord,
+    {
+        self.bst.truncate_last(k)
+    }
+
+    /// Returns an iterator over up to `k` of the smallest elements in the set, in ascending order.
+    /// Traversal stops after `k` elements, and a `k` greater than [`len`][crate::set::SgSet::len] yields every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 4, 2]);
+    /// assert!(set.first_n(2).eq([&1, &2]));
+    /// assert_eq!(set.first_n(10).count(), 4);
+    /// ```
+    pub fn first_n(&self, k: usize) -> impl Iterator<Item = &T>
+    where
+        T: Ord,
+    {
+        self.bst.first_n(k).map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over up to `k` of the largest elements in the set, in ascending order.
+    /// Traversal stops after `k` elements, and a `k` greater than [`len`][crate::set::SgSet::len] yields every element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 4, 2]);
+    /// assert!(set.last_n(2).eq([&3, &4]));
+    /// assert_eq!(set.last_n(10).count(), 4);
+    /// ```
+    pub fn last_n(&self, k: usize) -> impl Iterator<Item = &T>
+    where
+        T: Ord,
+    {
+        self.bst.last_n(k).map(|(k, _)| k)
+    }
+
+    /// Returns the element at sorted position `index` (0-based), or `None` if `index >= len`.
+    ///
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise it walks the tree in order, taking `O(log n + index)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([30, 10, 20]);
+    /// assert_eq!(set.get_index(0), Some(&10));
+    /// assert_eq!(set.get_index(2), Some(&30));
+    /// assert_eq!(set.get_index(3), None);
+    /// ```
+    pub fn get_index(&self, index: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.bst.get_index(index).map(|(k, _)| k)
+    }
+
+    /// Returns the sorted position (0-based) of `value` in the set, if present.
+    /// This is the inverse of [`get_index`][crate::set::SgSet::get_index].
+    ///
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise smaller subtrees are counted node by node, taking `O(log n + position)` time.
+    /// A missing value costs `O(log n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([30, 10, 20]);
+    /// assert_eq!(set.position(&10), Some(0));
+    /// assert_eq!(set.position(&30), Some(2));
+    /// assert_eq!(set.position(&25), None);
+    /// ```
+    pub fn position<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.position(value)
+    }
+
+    /// Returns the sorted position (0-based) of the first element for which `pred` returns `true`, if any.
+    /// Elements are tested in ascending order, so this runs in `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([30, 10, 20]);
+    /// assert_eq!(set.find_index(|n| n % 20 == 0), Some(1));
+    /// assert_eq!(set.find_index(|n| *n > 30), None);
+    /// ```
+    pub fn find_index<F>(&self, mut pred: F) -> Option<usize>
+    where
+        T: Ord,
+        F: FnMut(&T) -> bool,
+    {
+        self.bst.find_index(|k, _| pred(k))
+    }
+
+    /// Returns the number of elements for which `pred` returns `true`.
+    /// Elements are tested in ascending order, so this runs in `O(n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(set.count_where(|n| n % 2 == 0), 3);
+    /// assert_eq!(set.count_where(|n| *n > 6), 0);
+    /// ```
+    pub fn count_where<F>(&self, mut pred: F) -> usize
+    where
+        T: Ord,
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().filter(|v| pred(v)).count()
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut v = SgSet::<_, 10>::new();
+    /// assert_eq!(v.len(), 0);
+    /// v.insert(1);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.bst.len()
+    }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the set.
+    /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
+    /// yield elements from min (inclusive) to max (exclusive).
+    /// The range may also be entered as `(Bound<T>, Bound<T>)`, so for example
+    /// `range((Excluded(4), Included(10)))` will yield a left-exclusive, right-inclusive
+    /// range from 4 to 10.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    /// use core::ops::Bound::Included;
+    ///
+    /// let mut set = SgSet::<_, 5>::new();
+    /// set.insert(3);
     /// set.insert(5);
     /// set.insert(8);
     /// for &elem in set.range((Included(&4), Included(&8))) {
@@ -718,6 +1767,96 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Constructs an iterator over a sub-range of elements in the set, in descending order.
+    /// Equivalent to `range(range).rev()`, e.g. for "most recent first" reads within a window of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 5, 8, 13]);
+    /// assert!(set.range_rev(4..=13).eq([&13, &8, &5]));
+    /// assert_eq!(set.range_rev(..5).next(), Some(&3));
+    /// ```
+    pub fn range_rev<K, R>(&self, range: R) -> Rev<Range<'_, T, N>>
+    where
+        K: Ord + ?Sized,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+    {
+        self.range(range).rev()
+    }
+
+    /// Returns the number of elements within `range` and, if it's non-empty, the arena slots of its first and last elements.
+    /// Slots are only valid until the set is next mutated.
+    ///
+    /// A cheap pre-check, e.g. to decide whether iterating a range is worthwhile before building the iterator.
+    /// Runs in `O(log n)` time with feature `fast_rebalance`, which caches subtree sizes.
+    /// Otherwise the count prunes out-of-range subtrees like [`range_len`][crate::set::SgSet::range_len],
+    /// taking `O(log n + len)` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 3, 5, 7]);
+    ///
+    /// let (len, slots) = set.range_bounds_resolved(2..7);
+    /// assert_eq!(len, set.range(2..7).count());
+    /// assert!(slots.is_some());
+    ///
+    /// assert_eq!(set.range_bounds_resolved(..1), (0, None));
+    /// ```
+    pub fn range_bounds_resolved<K, R>(&self, range: R) -> (usize, Option<(usize, usize)>)
+    where
+        K: Ord + ?Sized,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+    {
+        self.bst.range_bounds_resolved(&range)
+    }
+
+    /// Returns the number of elements within `range`, without building a [`Range`][crate::set_types::Range] iterator.
+    /// Subtrees entirely outside the range are skipped, so this runs in `O(log n + len)` time without allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    /// use core::ops::Bound::{Excluded, Included};
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 3, 5, 7]);
+    ///
+    /// assert_eq!(set.range_len(2..7), 2);
+    /// assert_eq!(set.range_len((Excluded(1), Included(7))), 3);
+    /// assert_eq!(set.range_len(8..), 0);
+    /// ```
+    pub fn range_len<K, R>(&self, range: R) -> usize
+    where
+        K: Ord + ?Sized,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+    {
+        self.bst.range_len(&range)
+    }
+
     /// Returns an iterator over values representing set difference, e.g., values in `self` but not in `other`, in ascending order.
     ///
     /// # Examples
@@ -835,6 +1974,322 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
         Union::new(self, other)
     }
 
+    /// Moves all values of `other` that are not already in `self` into `self`, making `self` the union of both sets.
+    /// Values already in `self` are left untouched.
+    ///
+    /// Returns `Err` if the union would exceed the set's stack capacity, `N`.
+    /// Capacity is checked before mutating, so on `Err` the set is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgSet};
+    ///
+    /// let mut a = SgSet::<_, 4>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 4>::from_iter([2, 3, 4]);
+    /// assert!(a.union_with(&b).is_ok());
+    /// assert!(a.iter().eq([1, 2, 3, 4].iter()));
+    ///
+    /// let c = SgSet::<_, 4>::from_iter([5]);
+    /// assert_eq!(a.union_with(&c), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(a.len(), 4);
+    /// ```
+    pub fn union_with(&mut self, other: &SgSet<T, N>) -> Result<(), SgError>
+    where
+        T: Ord + Clone,
+    {
+        let new_cnt = other.len() - other.bst.intersect_cnt(&self.bst);
+        if self.len() + new_cnt > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        for e in other.iter() {
+            if !self.contains(e) {
+                self.insert(e.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retains only the values also in `other`, making `self` the intersection of both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    /// a.intersection_with(&b);
+    /// assert!(a.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn intersection_with(&mut self, other: &SgSet<T, N>)
+    where
+        T: Ord,
+    {
+        self.retain(|e| other.contains(e));
+    }
+
+    /// Removes all values also in `other`, making `self` the difference `self - other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    /// a.difference_with(&b);
+    /// assert!(a.iter().eq([1].iter()));
+    /// ```
+    pub fn difference_with(&mut self, other: &SgSet<T, N>)
+    where
+        T: Ord,
+    {
+        self.retain(|e| !other.contains(e));
+    }
+
+    /// Removes values shared with `other` and inserts values only in `other`,
+    /// making `self` the symmetric difference of both sets.
+    ///
+    /// Returns `Err` if the symmetric difference would exceed the set's stack capacity, `N`.
+    /// Capacity is checked before mutating, so on `Err` the set is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgSet};
+    ///
+    /// let mut a = SgSet::<_, 4>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 4>::from_iter([2, 3, 4]);
+    /// assert!(a.symmetric_difference_with(&b).is_ok());
+    /// assert!(a.iter().eq([1, 4].iter()));
+    ///
+    /// let c = SgSet::<_, 4>::from_iter([5, 6, 7]);
+    /// assert_eq!(a.symmetric_difference_with(&c), Err(SgError::StackCapacityExceeded));
+    /// assert!(a.iter().eq([1, 4].iter()));
+    /// ```
+    pub fn symmetric_difference_with(&mut self, other: &SgSet<T, N>) -> Result<(), SgError>
+    where
+        T: Ord + Clone,
+    {
+        let shared_cnt = other.bst.intersect_cnt(&self.bst);
+        let new_cnt = other.len() - shared_cnt;
+        if self.len() - shared_cnt + new_cnt > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        // Remove before inserting, so intermediate length never exceeds the final length
+        let mut other_only = ArrayVec::<&T, N>::new();
+        for e in other.iter() {
+            if !self.remove(e) {
+                other_only.push(e);
+            }
+        }
+
+        for e in other_only {
+            self.insert(e.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Returns a new set containing the values in both `self` and `other`.
+    /// Equivalent to `a.intersection(&b).cloned().collect()`, but built via a linear merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// let intersection = a.intersection_set(&b).unwrap();
+    /// assert!(intersection.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn intersection_set(&self, other: &SgSet<T, N>) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.merge_to_set(other, false, true, false)
+    }
+
+    /// Returns a new set containing the values in `self` or `other`.
+    /// Equivalent to `a.union(&b).cloned().collect()`, but built via a linear merge.
+    ///
+    /// Returns `Err` if the union would exceed the stack capacity, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgError, SgSet};
+    ///
+    /// let a = SgSet::<_, 4>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 4>::from_iter([2, 3, 4]);
+    ///
+    /// let union = a.union_set(&b).unwrap();
+    /// assert!(union.iter().eq([1, 2, 3, 4].iter()));
+    ///
+    /// let c = SgSet::<_, 4>::from_iter([5, 6]);
+    /// assert_eq!(a.union_set(&c), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn union_set(&self, other: &SgSet<T, N>) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.merge_to_set(other, true, true, true)
+    }
+
+    /// Returns a new set containing the values in `self` but not in `other`.
+    /// Equivalent to `a.difference(&b).cloned().collect()`, but built via a linear merge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// let diff = a.difference_set(&b).unwrap();
+    /// assert!(diff.iter().eq([1].iter()));
+    /// ```
+    pub fn difference_set(&self, other: &SgSet<T, N>) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.merge_to_set(other, true, false, false)
+    }
+
+    /// Returns a new set containing the values in `self` or `other`, but not in both.
+    /// Equivalent to `a.symmetric_difference(&b).cloned().collect()`, but built via a linear merge.
+    ///
+    /// Returns `Err` if the symmetric difference would exceed the stack capacity, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// let sym_diff = a.symmetric_difference_set(&b).unwrap();
+    /// assert!(sym_diff.iter().eq([1, 4].iter()));
+    /// ```
+    pub fn symmetric_difference_set(&self, other: &SgSet<T, N>) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        self.merge_to_set(other, true, false, true)
+    }
+
+    /// Returns the number of values in `self` or `other`, `|a ∪ b|`, without building the union.
+    /// Computed by a linear merge in `O(n + m)` time, e.g. to check that [`union_set`][crate::set::SgSet::union_set]
+    /// or [`union_with`][crate::set::SgSet::union_with] will fit in `N` before attempting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 4>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 4>::from_iter([2, 3, 4, 5]);
+    ///
+    /// assert_eq!(a.union_len(&b), 5);
+    /// assert!(a.union_len(&b) > a.capacity());
+    /// assert!(a.union_set(&b).is_err());
+    /// ```
+    pub fn union_len(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() + other.len() - self.intersection_len(other)
+    }
+
+    /// Returns the number of values in both `self` and `other`, `|a ∩ b|`, without building the intersection.
+    /// Computed by a linear merge in `O(n + m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// assert_eq!(a.intersection_len(&b), 2);
+    /// ```
+    pub fn intersection_len(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        let mut self_iter = self.iter().peekable();
+        let mut other_iter = other.iter().peekable();
+        let mut both_cnt = 0;
+
+        while let (Some(a), Some(b)) = (self_iter.peek(), other_iter.peek()) {
+            match a.cmp(b) {
+                Ordering::Less => {
+                    self_iter.next();
+                }
+                Ordering::Greater => {
+                    other_iter.next();
+                }
+                Ordering::Equal => {
+                    self_iter.next();
+                    other_iter.next();
+                    both_cnt += 1;
+                }
+            }
+        }
+
+        both_cnt
+    }
+
+    /// Returns the number of values in `self` but not in `other`, `|a - b|`, without building the difference.
+    /// Computed by a linear merge in `O(n + m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// assert_eq!(a.difference_len(&b), 1);
+    /// assert_eq!(b.difference_len(&a), 1);
+    /// ```
+    pub fn difference_len(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() - self.intersection_len(other)
+    }
+
+    /// Returns the number of values in `self` or `other`, but not in both, without building the symmetric difference.
+    /// Computed by a linear merge in `O(n + m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([2, 3, 4]);
+    ///
+    /// assert_eq!(a.symmetric_difference_len(&b), 2);
+    /// ```
+    pub fn symmetric_difference_len(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        self.len() + other.len() - (2 * self.intersection_len(other))
+    }
+
     /// Returns `true` if the set contains no elements.
     ///
     /// # Examples
@@ -887,7 +2342,44 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
     where
         T: Ord,
     {
-        self.intersection(other).count() == 0
+        self.is_disjoint_from(other)
+    }
+
+    /// Returns `true` if `self` has no elements in common with `other`, a set of any capacity.
+    /// Computed by a linear merge of the two sorted sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let small = SgSet::<_, 3>::from_iter([1, 3, 5]);
+    /// let large = SgSet::<_, 100>::from_iter((0..100).step_by(2));
+    ///
+    /// assert!(small.is_disjoint_from(&large));
+    /// assert!(large.is_disjoint_from(&small));
+    /// assert!(!small.is_disjoint_from(&SgSet::<_, 1>::from_iter([3])));
+    /// ```
+    pub fn is_disjoint_from<const M: usize>(&self, other: &SgSet<T, M>) -> bool
+    where
+        T: Ord,
+    {
+        let mut self_iter = self.iter().peekable();
+        let mut other_iter = other.iter().peekable();
+
+        while let (Some(a), Some(b)) = (self_iter.peek(), other_iter.peek()) {
+            match a.cmp(b) {
+                Ordering::Less => {
+                    self_iter.next();
+                }
+                Ordering::Greater => {
+                    other_iter.next();
+                }
+                Ordering::Equal => return false,
+            }
+        }
+
+        true
     }
 
     /// Returns `true` if `self` is a subset of `other`, e.g., `other` contains at least all the values in `self`.
@@ -910,7 +2402,35 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
     where
         T: Ord,
     {
-        self.intersection(other).count() == self.len()
+        self.is_subset_of(other)
+    }
+
+    /// Returns `true` if `self` is a subset of `other`, a set of any capacity.
+    /// Computed by a linear merge of the two sorted sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 3>::from_iter([2, 4, 8]);
+    /// let sup = SgSet::<_, 16>::from_iter(0..16);
+    ///
+    /// assert!(set.is_subset_of(&sup));
+    /// assert!(!sup.is_subset_of(&set));
+    /// ```
+    pub fn is_subset_of<const M: usize>(&self, other: &SgSet<T, M>) -> bool
+    where
+        T: Ord,
+    {
+        if self.len() > other.len() {
+            return false;
+        }
+
+        // Each element must be the first of `other`'s remaining elements not less than it
+        let mut other_iter = other.iter();
+        self.iter()
+            .all(|a| other_iter.by_ref().find(|b| *b >= a) == Some(a))
     }
 
     /// Returns `true` if `self` is a superset of `other`, e.g., `self` contains at least all the values in `other`.
@@ -936,7 +2456,171 @@ impl<T: Ord, const N: usize> SgSet<T, N> {
     where
         T: Ord,
     {
-        other.is_subset(self)
+        other.is_subset_of(self)
+    }
+
+    /// Returns `true` if `self` is a superset of `other`, a set of any capacity.
+    /// Computed by a linear merge of the two sorted sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 16>::from_iter(0..16);
+    /// let sub = SgSet::<_, 3>::from_iter([2, 4, 8]);
+    ///
+    /// assert!(set.is_superset_of(&sub));
+    /// assert!(!sub.is_superset_of(&set));
+    /// ```
+    pub fn is_superset_of<const M: usize>(&self, other: &SgSet<T, M>) -> bool
+    where
+        T: Ord,
+    {
+        other.is_subset_of(self)
+    }
+
+    /// Returns an iterator over the maximal contiguous ranges of `domain` not present in the set, in ascending order.
+    /// Values outside of `domain` are ignored.
+    ///
+    /// Useful for allocator bookkeeping, e.g. finding free ranges in a set of allocated IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let allocated = SgSet::<u32, 10>::from_iter([2, 3, 4, 7, 9, 20]);
+    ///
+    /// let free: Vec<_> = allocated.gaps(0..=10).collect();
+    /// assert_eq!(free, [0..=1, 5..=6, 8..=8, 10..=10]);
+    ///
+    /// // Empty set, the whole domain is free
+    /// let empty = SgSet::<u32, 10>::new();
+    /// assert_eq!(empty.gaps(0..=10).collect::<Vec<_>>(), [0..=10]);
+    ///
+    /// // Fully allocated domain, nothing is free
+    /// assert_eq!(allocated.gaps(2..=4).count(), 0);
+    /// ```
+    pub fn gaps(&self, domain: RangeInclusive<T>) -> Gaps<'_, T, N>
+    where
+        T: Discrete,
+    {
+        Gaps::new(self, domain)
+    }
+
+    /// Returns the longest range of consecutive values present in the set, or `None` if the set is empty.
+    /// If multiple runs share the maximum length, the first (lowest) is returned.
+    ///
+    /// Computed by a single ordered scan, without allocation.
+    /// Useful for defragmentation heuristics, e.g. finding the largest contiguous block of allocated IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let allocated = SgSet::<u64, 10>::from_iter([2, 3, 4, 7, 8, 9, 20]);
+    /// assert_eq!(allocated.longest_run(), Some(2..=4)); // Tie with 7..=9, first wins
+    ///
+    /// let single = SgSet::<u64, 10>::from_iter([42]);
+    /// assert_eq!(single.longest_run(), Some(42..=42));
+    ///
+    /// let empty = SgSet::<u64, 10>::new();
+    /// assert_eq!(empty.longest_run(), None);
+    /// ```
+    pub fn longest_run(&self) -> Option<RangeInclusive<T>>
+    where
+        T: Discrete,
+    {
+        let mut iter = self.iter();
+        let first = *iter.next()?;
+
+        let (mut best_start, mut best_end, mut best_len) = (first, first, 1);
+        let (mut run_start, mut run_len) = (first, 1);
+        let mut prev = first;
+
+        for &curr in iter {
+            match prev.checked_succ() == Some(curr) {
+                true => run_len += 1,
+                false => {
+                    run_start = curr;
+                    run_len = 1;
+                }
+            }
+
+            if run_len > best_len {
+                (best_start, best_end, best_len) = (run_start, curr, run_len);
+            }
+
+            prev = curr;
+        }
+
+        Some(best_start..=best_end)
+    }
+
+    /// Returns an iterator yielding `(bucket, count)` for each maximal run of consecutive elements, in order,
+    /// that map to the same bucket under `key_fn`. Useful for range-bucketed aggregation without allocation.
+    ///
+    /// Runs of a bucket are only contiguous if `key_fn` is monotone in the element.
+    /// A non-monotone bucket function can produce multiple runs (and thus multiple yielded pairs) per bucket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let ports = SgSet::<u16, 10>::from_iter([22, 80, 443, 8080, 8443]);
+    ///
+    /// // Count by number of digits
+    /// assert!(ports.group_runs(|p| p.ilog10() + 1).eq([(2, 2), (3, 1), (4, 2)]));
+    /// ```
+    pub fn group_runs<B, F>(&self, key_fn: F) -> impl Iterator<Item = (B, usize)>
+    where
+        T: Ord,
+        B: PartialEq,
+        F: FnMut(&T) -> B,
+    {
+        group_runs(self.iter().map(key_fn))
+    }
+
+    // Linear merge of two sorted sets into a new one, keeping values based on which set(s) they're in.
+    fn merge_to_set(
+        &self,
+        other: &SgSet<T, N>,
+        keep_this_only: bool,
+        keep_both: bool,
+        keep_other_only: bool,
+    ) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Ord + Clone,
+    {
+        let mut this_iter = self.iter().peekable();
+        let mut other_iter = other.iter().peekable();
+
+        let merged = core::iter::from_fn(|| {
+            loop {
+                let (val, keep) = match (this_iter.peek(), other_iter.peek()) {
+                    (Some(this_val), Some(other_val)) => match this_val.cmp(other_val) {
+                        Ordering::Less => (this_iter.next()?, keep_this_only),
+                        Ordering::Greater => (other_iter.next()?, keep_other_only),
+                        Ordering::Equal => {
+                            let _ = other_iter.next();
+                            (this_iter.next()?, keep_both)
+                        }
+                    },
+                    (Some(_), None) => (this_iter.next()?, keep_this_only),
+                    (None, Some(_)) => (other_iter.next()?, keep_other_only),
+                    (None, None) => return None,
+                };
+
+                if keep {
+                    return Some((val.clone(), ()));
+                }
+            }
+        });
+
+        SgTree::try_from_sorted_iter(merged).map(|bst| SgSet { bst })
     }
 }
 
@@ -954,6 +2638,13 @@ where
     }
 }
 
+// Default
+impl<T: Ord, const N: usize> Default for SgSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // From array.
 impl<T, const N: usize> From<[T; N]> for SgSet<T, N>
 where
@@ -980,6 +2671,24 @@ where
     }
 }
 
+// From map, dropping values.
+impl<K: Ord, V, const N: usize> From<SgMap<K, V, N>> for SgSet<K, N> {
+    /// Keeps the keys of the map, reusing its sorted tree structure (no re-sort or rebalance).
+    ///
+    /// ```
+    /// use escapegoat::{SgMap, SgSet};
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(3, "c"), (1, "a"), (2, "b")]);
+    /// let set = SgSet::from(map);
+    /// assert!(set.into_iter().eq([1, 2, 3]));
+    /// ```
+    fn from(map: SgMap<K, V, N>) -> Self {
+        SgSet {
+            bst: map.bst.map_values(|_| ()),
+        }
+    }
+}
+
 // Construct from iterator.
 impl<T, const N: usize> FromIterator<T> for SgSet<T, N>
 where
@@ -1,8 +1,11 @@
 use core::cmp::Ordering;
 use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
 
 use crate::set::SgSet;
-use crate::tree::{Idx, IntoIter as TreeIntoIter, Iter as TreeIter, SmallNode};
+use crate::tree::{
+    CollectError, Idx, IntoIter as TreeIntoIter, Iter as TreeIter, SmallNode, collect_array,
+};
 
 use arrayvec::ArrayVec;
 use smallnum::SmallUnsigned;
@@ -34,6 +37,12 @@ impl<'a, T: Ord, const N: usize> Iterator for Iter<'a, T, N> {
     }
 }
 
+impl<'a, T: Ord, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ref_iter.next_back().map(|(k, _)| k)
+    }
+}
+
 impl<'a, T: Ord, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
     fn len(&self) -> usize {
         self.ref_iter.len()
@@ -42,6 +51,14 @@ impl<'a, T: Ord, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
 
 impl<'a, T: Ord, const N: usize> FusedIterator for Iter<'a, T, N> {}
 
+impl<'a, T: Ord, const N: usize> Clone for Iter<'a, T, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the items of a [`SgSet`][crate::set::SgSet].
 ///
 /// This `struct` is created by the [`into_iter`][crate::set::SgSet::into_iter] method on [`SgSet`][crate::set::SgSet]
@@ -57,6 +74,23 @@ impl<T: Ord, const N: usize> IntoIter<T, N> {
             cons_iter: TreeIntoIter::new(set.bst),
         }
     }
+
+    /// Moves exactly `M` remaining elements into an array, without heap allocation.
+    ///
+    /// Returns `Err` if fewer or more than `M` elements remain. Elements already moved out are dropped on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, CollectError};
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 2]);
+    /// assert_eq!(set.clone().into_iter().collect_array(), Ok([1, 2, 3]));
+    /// assert_eq!(set.into_iter().collect_array::<4>(), Err(CollectError::TooFew));
+    /// ```
+    pub fn collect_array<const M: usize>(self) -> Result<[T; M], CollectError> {
+        collect_array(self)
+    }
 }
 
 impl<T: Ord, const N: usize> Iterator for IntoIter<T, N> {
@@ -67,6 +101,12 @@ impl<T: Ord, const N: usize> Iterator for IntoIter<T, N> {
     }
 }
 
+impl<T: Ord, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next_back().map(|(k, _)| k)
+    }
+}
+
 impl<T: Ord, const N: usize> ExactSizeIterator for IntoIter<T, N> {
     fn len(&self) -> usize {
         self.cons_iter.len()
@@ -425,6 +465,25 @@ pub struct Range<'a, T: Ord, const N: usize> {
     pub(crate) node_idx_iter: <ArrayVec<usize, N> as IntoIterator>::IntoIter,
 }
 
+impl<'a, T: Ord, const N: usize> Range<'a, T, N> {
+    /// Collects exactly `M` remaining elements into an array, without heap allocation.
+    ///
+    /// Returns `Err` if fewer or more than `M` elements remain in the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::{SgSet, CollectError};
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 5, 8, 13]);
+    /// assert_eq!(set.range(4..=8).collect_array(), Ok([&5, &8]));
+    /// assert_eq!(set.range(4..).collect_array::<2>(), Err(CollectError::TooMany));
+    /// ```
+    pub fn collect_array<const M: usize>(self) -> Result<[&'a T; M], CollectError> {
+        collect_array(self)
+    }
+}
+
 impl<'a, T: Ord, const N: usize> Iterator for Range<'a, T, N> {
     type Item = &'a T;
 
@@ -444,3 +503,118 @@ impl<'a, T: Ord, const N: usize> DoubleEndedIterator for Range<'a, T, N> {
 }
 
 impl<'a, T: Ord, const N: usize> FusedIterator for Range<'a, T, N> {}
+
+impl<'a, T: Ord, const N: usize> Clone for Range<'a, T, N> {
+    fn clone(&self) -> Self {
+        Range {
+            table: self.table,
+            node_idx_iter: self.node_idx_iter.clone(),
+        }
+    }
+}
+
+// Gap APIs ------------------------------------------------------------------------------------------------------------
+
+/// Integer-like types with a well-defined successor and predecessor.
+///
+/// Implemented for all primitive integers.
+/// Required by [`gaps`][crate::set::SgSet::gaps] and [`longest_run`][crate::set::SgSet::longest_run] to compute
+/// contiguous runs of values.
+pub trait Discrete: Ord + Copy {
+    /// Returns the next value, or `None` if `self` is the maximum value.
+    fn checked_succ(&self) -> Option<Self>;
+
+    /// Returns the previous value, or `None` if `self` is the minimum value.
+    fn checked_pred(&self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                #[inline]
+                fn checked_succ(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn checked_pred(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// An iterator over the maximal ranges of values, within a domain, not present in a [`SgSet`].
+///
+/// This `struct` is created by the [`gaps`] method on [`SgSet`].
+/// See its documentation for more.
+///
+/// [`gaps`]: SgSet::gaps
+pub struct Gaps<'a, T: Ord + Discrete, const N: usize> {
+    range_iter: Range<'a, T, N>,
+    opt_next_start: Option<T>,
+    end: T,
+}
+
+impl<'a, T: Ord + Discrete, const N: usize> Gaps<'a, T, N> {
+    /// Construct gap iterator.
+    pub(crate) fn new(set: &'a SgSet<T, N>, domain: RangeInclusive<T>) -> Self {
+        let (start, end) = domain.into_inner();
+
+        // Empty domain, nothing to walk
+        if start > end {
+            return Gaps {
+                range_iter: Range {
+                    table: set,
+                    node_idx_iter: ArrayVec::new().into_iter(),
+                },
+                opt_next_start: None,
+                end,
+            };
+        }
+
+        Gaps {
+            range_iter: set.range(start..=end),
+            opt_next_start: Some(start),
+            end,
+        }
+    }
+}
+
+impl<'a, T: Ord + Discrete, const N: usize> Iterator for Gaps<'a, T, N> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.opt_next_start?;
+            match self.range_iter.next() {
+                Some(&elem) => {
+                    // Next candidate follows this element, unless it's the last value in the domain
+                    self.opt_next_start = match elem < self.end {
+                        true => elem.checked_succ(),
+                        false => None,
+                    };
+
+                    if start < elem {
+                        // Can't underflow: `elem` is strictly greater than some other value
+                        if let Some(gap_end) = elem.checked_pred() {
+                            return Some(start..=gap_end);
+                        }
+                    }
+                }
+                None => {
+                    self.opt_next_start = None;
+                    return Some(start..=self.end);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T: Ord + Discrete, const N: usize> FusedIterator for Gaps<'a, T, N> {}
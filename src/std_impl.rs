@@ -0,0 +1,192 @@
+use core::convert::TryFrom;
+use core::fmt::{Debug, Write};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::map::SgMap;
+use crate::set::SgSet;
+use crate::tree::{SgError, SgTree, SmallNode};
+
+/*
+Note:
+
+`BTreeMap`/`BTreeSet` iterate in ascending order with unique keys, exactly the precondition for building a balanced
+tree directly. Length is checked up front, so an oversized source is rejected before any element is moved.
+*/
+
+// Move a sorted source into a new tree, failing if it won't fit.
+fn tree_from_sorted<K: Ord, V, I, const N: usize>(
+    len: usize,
+    iter: I,
+) -> Result<SgTree<K, V, N>, SgError>
+where
+    I: IntoIterator<Item = (K, V)>,
+{
+    match len <= N {
+        true => SgTree::try_from_sorted_iter(iter),
+        false => Err(SgError::StackCapacityExceeded),
+    }
+}
+
+// Render the tree's shape as `(left key@depth right)`, recursively, with empty subtrees omitted.
+fn structure_string<K: Ord + Debug, V, const N: usize>(tree: &SgTree<K, V, N>) -> String {
+    fn write_subtree<K: Ord + Debug, V, const N: usize>(
+        tree: &SgTree<K, V, N>,
+        opt_idx: Option<usize>,
+        depth: usize,
+        out: &mut String,
+    ) {
+        if let Some(idx) = opt_idx {
+            let node = &tree.arena[idx];
+            out.push('(');
+            write_subtree(tree, node.left_idx(), depth + 1, out);
+            // Writing to a `String` can't fail
+            let _ = write!(out, "{:?}@{}", node.key(), depth);
+            write_subtree(tree, node.right_idx(), depth + 1, out);
+            out.push(')');
+        }
+    }
+
+    let mut out = String::new();
+    write_subtree(tree, tree.opt_root_idx, 0, &mut out);
+    out
+}
+
+// Map -----------------------------------------------------------------------------------------------------------------
+
+impl<K: Ord, V, const N: usize> SgMap<K, V, N> {
+    /// Converts a [`BTreeMap`] into a map, consuming it.
+    /// Since the source is already sorted, the balanced tree is built directly in `O(n)` time.
+    ///
+    /// Returns `Err` if the source has more than `N` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use escapegoat::{SgMap, SgError};
+    ///
+    /// let btm = BTreeMap::from([(2, "b"), (1, "a"), (3, "c")]);
+    ///
+    /// let sgm = SgMap::<_, _, 10>::try_from_btree(btm.clone()).unwrap();
+    /// assert!(sgm.into_iter().eq(btm.clone()));
+    ///
+    /// assert_eq!(
+    ///     SgMap::<_, _, 2>::try_from_btree(btm),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn try_from_btree(src: BTreeMap<K, V>) -> Result<Self, SgError> {
+        tree_from_sorted(src.len(), src).map(|bst| SgMap { bst })
+    }
+
+    /// Renders the tree's shape as nested, parenthesized in-order text, for readable diffs in invariant tests.
+    /// Each node appears as `(left key@depth right)`, with the root at depth `0` and empty subtrees omitted.
+    ///
+    /// Unlike [`Debug`], which lists entries in sorted order whatever the shape, this reveals balance.
+    /// Values aren't rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgMap;
+    ///
+    /// let mut sgm = SgMap::<_, _, 10>::from_iter([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// sgm.optimize();
+    /// assert_eq!(sgm.structure_string(), "((1@1)2@0(3@1))");
+    /// ```
+    pub fn structure_string(&self) -> String
+    where
+        K: Debug,
+    {
+        structure_string(&self.bst)
+    }
+}
+
+impl<K: Ord, V, const N: usize> TryFrom<BTreeMap<K, V>> for SgMap<K, V, N> {
+    type Error = SgError;
+
+    /// See [`SgMap::try_from_btree`].
+    fn try_from(src: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        SgMap::try_from_btree(src)
+    }
+}
+
+// Set -----------------------------------------------------------------------------------------------------------------
+
+impl<T: Ord, const N: usize> SgSet<T, N> {
+    /// Converts a [`BTreeSet`] into a set, consuming it.
+    /// Since the source is already sorted, the balanced tree is built directly in `O(n)` time.
+    ///
+    /// Returns `Err` if the source has more than `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use escapegoat::{SgSet, SgError};
+    ///
+    /// let bts = BTreeSet::from([2, 1, 3]);
+    ///
+    /// let sgs = SgSet::<_, 10>::try_from_btree(bts.clone()).unwrap();
+    /// assert!(sgs.into_iter().eq(bts.clone()));
+    ///
+    /// assert_eq!(
+    ///     SgSet::<_, 2>::try_from_btree(bts),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn try_from_btree(src: BTreeSet<T>) -> Result<Self, SgError> {
+        tree_from_sorted(src.len(), src.into_iter().map(|e| (e, ()))).map(|bst| SgSet { bst })
+    }
+
+    /// Clones the set's elements into a [`Vec`], in ascending order, e.g. to pass a contiguous sorted buffer over FFI.
+    ///
+    /// There's no borrowing `as_sorted_slice` equivalent: the arena stores elements in insertion (not sorted) order,
+    /// with gaps left by removals, so sorted elements are never contiguous in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let sgs = SgSet::<_, 10>::from_iter([3, 1, 2]);
+    /// let vec = sgs.to_sorted_vec();
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn to_sorted_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Renders the tree's shape as nested, parenthesized in-order text, for readable diffs in invariant tests.
+    /// Each node appears as `(left value@depth right)`, with the root at depth `0` and empty subtrees omitted.
+    ///
+    /// Unlike [`Debug`], which lists elements in sorted order whatever the shape, this reveals balance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use escapegoat::SgSet;
+    ///
+    /// let mut sgs = SgSet::<_, 10>::from_iter(['a', 'b', 'c', 'd', 'e']);
+    /// sgs.optimize();
+    /// assert_eq!(sgs.structure_string(), "(('a'@1('b'@2))'c'@0('d'@1('e'@2)))");
+    /// ```
+    pub fn structure_string(&self) -> String
+    where
+        T: Debug,
+    {
+        structure_string(&self.bst)
+    }
+}
+
+impl<T: Ord, const N: usize> TryFrom<BTreeSet<T>> for SgSet<T, N> {
+    type Error = SgError;
+
+    /// See [`SgSet::try_from_btree`].
+    fn try_from(src: BTreeSet<T>) -> Result<Self, Self::Error> {
+        SgSet::try_from_btree(src)
+    }
+}
@@ -90,7 +90,9 @@ impl<K, V, U: Copy + SmallUnsigned + Ord + PartialEq + PartialOrd, const N: usiz
                 free_idx.usize()
             }
             None => {
-                self.vec.push(Some(node));
+                self.vec
+                    .try_push(Some(node))
+                    .expect("Stack-storage capacity exceeded!");
                 self.vec.len() - 1
             }
         }
@@ -128,6 +130,25 @@ impl<K, V, U: Copy + SmallUnsigned + Ord + PartialEq + PartialOrd, const N: usiz
         }
     }
 
+    /// Swap the values of the nodes at two known-good indexes, keeping keys and links in place.
+    pub fn swap_vals(&mut self, idx_1: usize, idx_2: usize) {
+        if idx_1 == idx_2 {
+            return;
+        }
+
+        // Disjoint mutable borrows of both slots
+        let (lo_idx, hi_idx) = (idx_1.min(idx_2), idx_1.max(idx_2));
+        let (head, tail) = self.vec.split_at_mut(hi_idx);
+        match (&mut head[lo_idx], &mut tail[0]) {
+            (Some(lo_node), Some(hi_node)) => {
+                core::mem::swap(lo_node.get_mut().1, hi_node.get_mut().1)
+            }
+            _ => {
+                panic!("Internal invariant failed: attempted value swap of node at invalid index.")
+            }
+        }
+    }
+
     /// Sort the arena in caller-requested order and update all tree metadata accordingly
     /// `unwraps` will never panic if caller invariants upheld (checked via `debug_assert`)
     pub fn sort(
@@ -178,11 +199,74 @@ impl<K, V, U: Copy + SmallUnsigned + Ord + PartialEq + PartialOrd, const N: usiz
         swap_history.curr_idx(root_idx)
     }
 
+    /// Initialize every never-used slot to `None` (and, if applicable, list it as free), so the arena spans all `N`
+    /// slots. Slot assignment order is unchanged: previously freed slots are still reused first, then untouched
+    /// slots in ascending index order. No-op if all slots were already initialized.
+    pub fn prewarm(&mut self) {
+        let start = self.vec.len();
+
+        #[cfg(not(feature = "low_mem_insert"))]
+        {
+            let freed = core::mem::take(&mut self.free_list);
+            self.free_list
+                .extend((start..N).rev().map(|idx| U::checked_from(idx)));
+            self.free_list.extend(freed);
+        }
+
+        self.vec.extend((start..N).map(|_| None));
+    }
+
     /// Returns the number of entries in the arena, some of which may be `None`.
     pub fn len(&self) -> usize {
         self.vec.len()
     }
 
+    /// Returns the number of freed entries awaiting reuse.
+    #[cfg(not(feature = "low_mem_insert"))]
+    pub fn free_len(&self) -> usize {
+        self.free_list.len()
+    }
+
+    /// Returns the number of freed entries awaiting reuse.
+    #[cfg(feature = "low_mem_insert")]
+    pub fn free_len(&self) -> usize {
+        self.vec.iter().filter(|x| x.is_none()).count()
+    }
+
+    /// Convert every node's value (given its key), visiting nodes in the given index order, without moving any node.
+    /// Indexes, child links, and free list are all preserved. `order` must cover every occupied index.
+    pub fn map_vals<W, F: FnMut(&K, V) -> W>(
+        mut self,
+        order: &[usize],
+        mut f: F,
+    ) -> Arena<K, W, U, N> {
+        let mut vec: ArrayVec<Option<Node<K, W, U>>, N> = self.vec.iter().map(|_| None).collect();
+        for &idx in order {
+            vec[idx] = self.vec[idx].take().map(|node| node.map_val(&mut f));
+        }
+        debug_assert!(self.vec.iter().all(Option::is_none));
+
+        Arena {
+            vec,
+
+            #[cfg(not(feature = "low_mem_insert"))]
+            free_list: self.free_list,
+        }
+    }
+
+    /// Move every slot, as-is, into an arena with capacity `M`.
+    /// Indexes, child links, and free list are all preserved. `M` must be at least the current slot count.
+    pub fn into_capacity<const M: usize>(self) -> Arena<K, V, U, M> {
+        debug_assert!(self.vec.len() <= M);
+
+        Arena {
+            vec: self.vec.into_iter().collect(),
+
+            #[cfg(not(feature = "low_mem_insert"))]
+            free_list: self.free_list.into_iter().collect(),
+        }
+    }
+
     /// Returns true if the index is occupied, e.g. `Some(node)`.
     pub fn is_occupied(&self, idx: usize) -> bool {
         (idx < self.vec.len()) && (self.vec[idx].is_some())
@@ -441,4 +525,33 @@ mod tests {
         assert_eq!(&0, iter_mut.next_back().unwrap().as_ref().unwrap().key());
         assert!(iter_mut.next_back().is_none());
     }
+
+    #[test]
+    fn test_prewarm() {
+        let mut lazy: Arena<usize, usize, small_unsigned!(CAPACITY), CAPACITY> = Arena::new();
+        let mut warm = lazy.clone();
+
+        for arena in [&mut lazy, &mut warm] {
+            for i in 0..4 {
+                arena.add(i, i);
+            }
+            arena.remove(1);
+        }
+
+        warm.prewarm();
+        assert_eq!(warm.len(), CAPACITY);
+        assert_eq!(warm.free_len(), CAPACITY - 3);
+        assert_eq!(warm.iter().filter(|x| x.is_some()).count(), 3);
+
+        // Idempotent
+        warm.prewarm();
+        assert_eq!(warm.len(), CAPACITY);
+        assert_eq!(warm.free_len(), CAPACITY - 3);
+
+        // Same slot assignment order: freed slot first, then ascending
+        for i in 4..CAPACITY + 1 {
+            assert_eq!(lazy.add(i, i), warm.add(i, i));
+        }
+        assert_eq!(warm.free_len(), 0);
+    }
 }
@@ -1,4 +1,9 @@
+use core::fmt;
+
 /// Errors for fallible operations.
+///
+/// Implements [`Display`](fmt::Display) and [`core::error::Error`], so it composes with `?` into downstream error
+/// types (e.g. via `impl From<SgError> for MyError`, `thiserror`'s `#[from]`, or `Box<dyn Error>`).
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[non_exhaustive]
 pub enum SgError {
@@ -12,17 +17,14 @@ pub enum SgError {
     /// Requested operation cannot complete, heap storage is full.
     HeapCapacityExceeded,
     */
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved3,
+    /// Parallel key and value inputs have different lengths, cannot pair them.
+    SliceLengthMismatch,
 
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved4,
+    /// Input keys are not in strictly ascending order (unsorted or duplicated), cannot build from sorted data.
+    KeysNotSorted,
 
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved5,
+    /// Updated key no longer sorts strictly between its neighbors, cannot keep it in place.
+    OrderViolation,
 
     /// Reserved for future use
     #[doc(hidden)]
@@ -36,6 +38,49 @@ pub enum SgError {
     RebalanceFactorOutOfRange,
 }
 
+impl fmt::Display for SgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            SgError::MaximumCapacityExceeded => "maximum supported capacity exceeded",
+            SgError::StackCapacityExceeded => "stack storage capacity exceeded",
+            SgError::SliceLengthMismatch => "key and value inputs have different lengths",
+            SgError::KeysNotSorted => "input keys are not in strictly ascending order",
+            SgError::OrderViolation => "updated key no longer sorts between its neighbors",
+            SgError::Reserved6 | SgError::Reserved7 => "reserved error",
+            SgError::RebalanceFactorOutOfRange => "rebalance factor out of range",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for SgError {}
+
+/// Error for collecting an iterator into a fixed-size array, e.g. via `collect_array`.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[non_exhaustive]
+pub enum CollectError {
+    /// Iterator yielded fewer elements than the array's length.
+    TooFew,
+
+    /// Iterator yielded more elements than the array's length.
+    TooMany,
+}
+
+impl fmt::Display for CollectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectError::TooFew => {
+                f.write_str("iterator yielded fewer elements than the array's length")
+            }
+            CollectError::TooMany => {
+                f.write_str("iterator yielded more elements than the array's length")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CollectError {}
+
 /*
 
 Requires nightly feature:
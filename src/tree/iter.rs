@@ -2,6 +2,7 @@ use core::iter::FusedIterator;
 
 use arrayvec::ArrayVec;
 
+use super::error::CollectError;
 use super::node::Node;
 use super::node_dispatch::SmallNode;
 use super::tree::{Idx, SgTree};
@@ -10,9 +11,12 @@ use super::tree::{Idx, SgTree};
 
 /// Uses iterative in-order tree traversal algorithm.
 /// Maintains a small stack of arena indexes (won't contain all indexes simultaneously for a balanced tree).
+/// From the back, each step is instead a single `O(log n)` predecessor descent, so only one stack is needed.
+/// A shared count of yielded elements stops the two traversals once they meet.
 pub struct Iter<'a, K, V, const N: usize> {
     bst: &'a SgTree<K, V, N>,
     idx_stack: ArrayVec<usize, N>,
+    back_idx: usize,
     total_cnt: usize,
     spent_cnt: usize,
 }
@@ -22,61 +26,72 @@ impl<'a, K: Ord, V, const N: usize> Iter<'a, K, V, N> {
         let mut ordered_iter = Iter {
             bst,
             idx_stack: ArrayVec::<usize, N>::new_const(),
+            back_idx: bst.max_idx,
             total_cnt: bst.len(),
             spent_cnt: 0,
         };
 
         if let Some(root_idx) = ordered_iter.bst.opt_root_idx {
-            let mut curr_idx = root_idx;
-            loop {
-                let node = &ordered_iter.bst.arena[curr_idx];
-                match node.left_idx() {
-                    Some(lt_idx) => {
-                        ordered_iter.idx_stack.push(curr_idx);
-                        curr_idx = lt_idx;
-                    }
-                    None => {
-                        ordered_iter.idx_stack.push(curr_idx);
-                        break;
-                    }
-                }
-            }
+            ordered_iter.push_left_spine(root_idx);
         }
 
         ordered_iter
     }
+
+    // Push the path to the subtree's minimum onto the stack.
+    fn push_left_spine(&mut self, mut curr_idx: usize) {
+        loop {
+            self.idx_stack.push(curr_idx);
+            match self.bst.arena[curr_idx].left_idx() {
+                Some(lt_idx) => curr_idx = lt_idx,
+                None => break,
+            }
+        }
+    }
 }
 
 impl<'a, K: Ord, V, const N: usize> Iterator for Iter<'a, K, V, N> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.idx_stack.pop() {
-            Some(pop_idx) => {
-                let node = &self.bst.arena[pop_idx];
-                if let Some(gt_idx) = node.right_idx() {
-                    let mut curr_idx = gt_idx;
-                    loop {
-                        let node = &self.bst.arena[curr_idx];
-                        match node.left_idx() {
-                            Some(lt_idx) => {
-                                self.idx_stack.push(curr_idx);
-                                curr_idx = lt_idx;
-                            }
-                            None => {
-                                self.idx_stack.push(curr_idx);
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                let node = &self.bst.arena[pop_idx];
-                self.spent_cnt += 1;
-                Some((node.key(), node.val()))
-            }
-            None => None,
+        if self.spent_cnt == self.total_cnt {
+            return None;
         }
+
+        let pop_idx = self.idx_stack.pop()?;
+        if let Some(gt_idx) = self.bst.arena[pop_idx].right_idx() {
+            self.push_left_spine(gt_idx);
+        }
+
+        let node = &self.bst.arena[pop_idx];
+        self.spent_cnt += 1;
+        Some((node.key(), node.val()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.total_cnt - self.spent_cnt;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, K: Ord, V, const N: usize> DoubleEndedIterator for Iter<'a, K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.spent_cnt == self.total_cnt {
+            return None;
+        }
+
+        let node = &self.bst.arena[self.back_idx];
+        self.spent_cnt += 1;
+
+        // Only look up the predecessor if one will be yielded
+        if self.spent_cnt < self.total_cnt {
+            self.back_idx = self
+                .bst
+                .strict_neighbor_idx(node.key(), core::cmp::Ordering::Less)
+                .expect("Internal invariant failed: unyielded entries must have a predecessor");
+        }
+
+        Some((node.key(), node.val()))
     }
 }
 
@@ -89,6 +104,18 @@ impl<'a, K: Ord, V, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
 
 impl<'a, K: Ord, V, const N: usize> FusedIterator for Iter<'a, K, V, N> {}
 
+impl<'a, K, V, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            bst: self.bst,
+            idx_stack: self.idx_stack.clone(),
+            back_idx: self.back_idx,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
+
 // Mutable Reference Iterator ------------------------------------------------------------------------------------------
 
 pub struct IterMut<'a, K, V, const N: usize> {
@@ -135,25 +162,34 @@ impl<'a, K: Ord, V, const N: usize> FusedIterator for IterMut<'a, K, V, N> {}
 // Consuming Iterator --------------------------------------------------------------------------------------------------
 
 /// Cheats a little by using internal flattening logic to sort, instead of re-implementing proper traversal.
-/// Maintains a shrinking list of arena indexes, initialized with all of them.
+/// Maintains a shrinking list of arena indexes, initialized with all of them, consumed from either end.
 pub struct IntoIter<K, V, const N: usize> {
     bst: SgTree<K, V, N>,
-    sorted_idxs: ArrayVec<usize, N>,
+    sorted_idxs: arrayvec::IntoIter<usize, N>,
 }
 
 impl<K: Ord, V, const N: usize> IntoIter<K, V, N> {
     pub fn new(bst: SgTree<K, V, N>) -> Self {
-        let mut ordered_iter = IntoIter {
-            bst,
-            sorted_idxs: ArrayVec::<usize, N>::new(),
+        let sorted_idxs = match bst.opt_root_idx {
+            Some(root_idx) => bst.flatten_subtree_to_sorted_idxs(root_idx),
+            None => ArrayVec::<usize, N>::new(),
         };
 
-        if let Some(root_idx) = ordered_iter.bst.opt_root_idx {
-            ordered_iter.sorted_idxs = ordered_iter.bst.flatten_subtree_to_sorted_idxs(root_idx);
-            ordered_iter.sorted_idxs.reverse();
+        IntoIter {
+            bst,
+            sorted_idxs: sorted_idxs.into_iter(),
         }
+    }
 
-        ordered_iter
+    // Remove a node by index, which must still be valid.
+    fn take(&mut self, idx: usize) -> Option<(K, V)> {
+        match self.bst.priv_remove_by_idx(idx) {
+            Some((key, val)) => Some((key, val)),
+            None => {
+                debug_assert!(false, "Use of invalid index in consuming iterator!");
+                None
+            }
+        }
     }
 }
 
@@ -161,16 +197,19 @@ impl<K: Ord, V, const N: usize> Iterator for IntoIter<K, V, N> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.sorted_idxs.pop() {
-            Some(idx) => match self.bst.priv_remove_by_idx(idx) {
-                Some((key, val)) => Some((key, val)),
-                None => {
-                    debug_assert!(false, "Use of invalid index in consuming iterator!");
-                    None
-                }
-            },
-            None => None,
-        }
+        let idx = self.sorted_idxs.next()?;
+        self.take(idx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sorted_idxs.size_hint()
+    }
+}
+
+impl<K: Ord, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.sorted_idxs.next_back()?;
+        self.take(idx)
     }
 }
 
@@ -181,3 +220,37 @@ impl<K: Ord, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
 }
 
 impl<K: Ord, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+// Fixed-size Collection -----------------------------------------------------------------------------------------------
+
+/// Collect exactly `M` elements into an array.
+/// Drains one element past `M` to detect overflow, elements already taken are dropped on error.
+pub(crate) fn collect_array<T, I: Iterator<Item = T>, const M: usize>(
+    mut iter: I,
+) -> Result<[T; M], CollectError> {
+    let arr: ArrayVec<T, M> = iter.by_ref().take(M).collect();
+
+    if iter.next().is_some() {
+        return Err(CollectError::TooMany);
+    }
+
+    arr.into_inner().map_err(|_| CollectError::TooFew)
+}
+
+// Run Grouping --------------------------------------------------------------------------------------------------------
+
+/// Collapse maximal runs of equal consecutive items into `(item, run length)` pairs, without allocation.
+pub(crate) fn group_runs<B: PartialEq, I: Iterator<Item = B>>(
+    iter: I,
+) -> impl Iterator<Item = (B, usize)> {
+    let mut iter = iter.peekable();
+    core::iter::from_fn(move || {
+        let bucket = iter.next()?;
+        let mut cnt = 1;
+        while iter.next_if(|next| *next == bucket).is_some() {
+            cnt += 1;
+        }
+
+        Some((bucket, cnt))
+    })
+}
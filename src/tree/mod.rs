@@ -14,10 +14,18 @@ pub use node::{Node, NodeGetHelper, NodeRebuildHelper};
 
 mod iter;
 pub use iter::{IntoIter, Iter, IterMut};
+pub(crate) use iter::{collect_array, group_runs};
 
 mod error;
-pub use error::SgError;
+pub use error::{CollectError, SgError};
+
+mod stats;
+pub use stats::ArenaStats;
+
+mod fnv;
 
 #[allow(clippy::module_inception)]
 mod tree;
+#[cfg(any(feature = "serde", feature = "borsh"))]
+pub(crate) use tree::SortedBuilder;
 pub use tree::{Alpha, Idx, SgTree};
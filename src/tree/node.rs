@@ -1,4 +1,4 @@
-use core::{mem::MaybeUninit, ops::Sub};
+use core::ops::Sub;
 
 use super::node_dispatch::SmallNode;
 
@@ -28,6 +28,9 @@ pub struct Node<K, V, U> {
 
     #[cfg(feature = "fast_rebalance")]
     subtree_size: U,
+
+    #[cfg(feature = "access_tracking")]
+    last_access: u32,
 }
 
 impl<K, V, U: SmallUnsigned> Node<K, V, U> {
@@ -43,6 +46,36 @@ impl<K, V, U: SmallUnsigned> Node<K, V, U> {
             //         This is equivalent to U::checked_from(1) but const.
             #[cfg(feature = "fast_rebalance")]
             subtree_size: unsafe { core::mem::transmute_copy(&1) },
+
+            #[cfg(feature = "access_tracking")]
+            last_access: 0,
+        }
+    }
+
+    /// Destructure into owned key and value.
+    pub fn into_key_val(self) -> (K, V) {
+        (self.key, self.val)
+    }
+
+    /// Exchange key and value with another node, keeping both nodes' child links and any cached metadata.
+    pub fn swap_key_val(&mut self, other: &mut Self) {
+        core::mem::swap(&mut self.key, &mut other.key);
+        core::mem::swap(&mut self.val, &mut other.val);
+    }
+
+    /// Convert the value (given the key), keeping key, child links, and any cached metadata.
+    pub fn map_val<W, F: FnOnce(&K, V) -> W>(self, f: F) -> Node<K, W, U> {
+        Node {
+            val: f(&self.key, self.val),
+            key: self.key,
+            left_idx: self.left_idx,
+            right_idx: self.right_idx,
+
+            #[cfg(feature = "fast_rebalance")]
+            subtree_size: self.subtree_size,
+
+            #[cfg(feature = "access_tracking")]
+            last_access: self.last_access,
         }
     }
 }
@@ -56,13 +89,6 @@ impl<K, V, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K, V, U> {
         self.key = key;
     }
 
-    fn take_key(&mut self) -> K {
-        // SAFETY: MaybeUninit::zeroed() is valid for all types, and we don't care about the value.
-        core::mem::replace(&mut self.key, unsafe {
-            MaybeUninit::zeroed().assume_init()
-        })
-    }
-
     fn val(&self) -> &V {
         &self.val
     }
@@ -71,13 +97,6 @@ impl<K, V, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K, V, U> {
         (&self.key, &mut self.val)
     }
 
-    fn take_val(&mut self) -> V {
-        // SAFETY: MaybeUninit::zeroed() is valid for all types, and we don't care about the value.
-        core::mem::replace(&mut self.val, unsafe {
-            MaybeUninit::zeroed().assume_init()
-        })
-    }
-
     fn set_val(&mut self, val: V) {
         self.val = val;
     }
@@ -113,6 +132,16 @@ impl<K, V, U: SmallUnsigned + Copy> SmallNode<K, V> for Node<K, V, U> {
     fn set_subtree_size(&mut self, size: usize) {
         self.subtree_size = U::checked_from(size);
     }
+
+    #[cfg(feature = "access_tracking")]
+    fn last_access(&self) -> u32 {
+        self.last_access
+    }
+
+    #[cfg(feature = "access_tracking")]
+    fn set_last_access(&mut self, tick: u32) {
+        self.last_access = tick;
+    }
 }
 
 // Retrieval Helper ----------------------------------------------------------------------------------------------------
@@ -275,6 +304,7 @@ mod tests {
         // No features
         #[cfg(target_pointer_width = "64")]
         #[cfg(not(feature = "fast_rebalance"))]
+        #[cfg(not(feature = "access_tracking"))]
         {
             assert_eq!(size_of::<Node<u32, u32, small_unsigned!(1024)>>(), 16);
         }
@@ -282,6 +312,15 @@ mod tests {
         // fast_rebalance only
         #[cfg(target_pointer_width = "64")]
         #[cfg(feature = "fast_rebalance")]
+        #[cfg(not(feature = "access_tracking"))]
+        {
+            assert_eq!(size_of::<Node<u32, u32, small_unsigned!(1024)>>(), 20);
+        }
+
+        // access_tracking only
+        #[cfg(target_pointer_width = "64")]
+        #[cfg(not(feature = "fast_rebalance"))]
+        #[cfg(feature = "access_tracking")]
         {
             assert_eq!(size_of::<Node<u32, u32, small_unsigned!(1024)>>(), 20);
         }
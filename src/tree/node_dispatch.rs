@@ -8,9 +8,6 @@ pub trait SmallNode<K, V> {
     /// Set key.
     fn set_key(&mut self, key: K);
 
-    // Take key, replacing current with `MaybeUninit::zeroed()`.
-    fn take_key(&mut self) -> K;
-
     /// Get value.
     fn val(&self) -> &V;
 
@@ -20,9 +17,6 @@ pub trait SmallNode<K, V> {
     /// Set value.
     fn set_val(&mut self, val: V);
 
-    // Take value, replacing current with `MaybeUninit::zeroed()`.
-    fn take_val(&mut self) -> V;
-
     /// Get left index as `usize`.
     fn left_idx(&self) -> Option<usize>;
 
@@ -42,6 +36,14 @@ pub trait SmallNode<K, V> {
     /// Set subtree size.
     #[cfg(feature = "fast_rebalance")]
     fn set_subtree_size(&mut self, size: usize);
+
+    /// Get last access tick.
+    #[cfg(feature = "access_tracking")]
+    fn last_access(&self) -> u32;
+
+    /// Set last access tick.
+    #[cfg(feature = "access_tracking")]
+    fn set_last_access(&mut self, tick: u32);
 }
 
 /*
@@ -150,10 +152,6 @@ impl<K: Default, V: Default> SmallNode<K, V> for SmallNodeDispatch<K, V> {
         dispatch!(self, set_key, key);
     }
 
-    fn take_key(&mut self) -> K {
-        dispatch!(self, take_key)
-    }
-
     fn val(&self) -> &V {
         dispatch!(self, val)
     }
@@ -166,10 +164,6 @@ impl<K: Default, V: Default> SmallNode<K, V> for SmallNodeDispatch<K, V> {
         dispatch!(self, set_val, val);
     }
 
-    fn take_val(&mut self) -> V {
-        dispatch!(self, take_val)
-    }
-
     fn left_idx(&self) -> Option<usize> {
         dispatch!(self, left_idx)
     }
@@ -0,0 +1,20 @@
+/// Read-only snapshot of a tree's arena usage, for debugging and profiling.
+///
+/// Created by the `debug_stats` method on [`SgMap`][crate::SgMap] and [`SgSet`][crate::SgSet].
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct ArenaStats {
+    /// Number of arena slots holding a live node, e.g. the number of elements.
+    pub live: usize,
+
+    /// Number of previously used arena slots now on the free list, awaiting reuse.
+    pub free: usize,
+
+    /// Total arena slots available, the const `N`.
+    pub capacity: usize,
+
+    /// Current tree height, counted in nodes along the longest root-to-leaf path (`0` if empty).
+    pub height: usize,
+
+    /// Number of subtree rebuilds performed so far. Wraps if `usize::MAX` is exceeded.
+    pub rebuilds: usize,
+}
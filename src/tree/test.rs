@@ -1,5 +1,6 @@
 use core::fmt::Debug;
 use core::iter::FromIterator;
+use core::ops::Bound::{Excluded, Unbounded};
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use super::SgError;
@@ -14,6 +15,20 @@ const CAPACITY: usize = 1024;
 
 // Test Helpers --------------------------------------------------------------------------------------------------------
 
+// Height of a subtree, in nodes, computed recursively.
+fn subtree_height<K: Ord, V, const N: usize>(
+    sgt: &SgTree<K, V, N>,
+    opt_idx: Option<usize>,
+) -> usize {
+    match opt_idx {
+        Some(idx) => {
+            let node = &sgt.arena[idx];
+            1 + subtree_height(sgt, node.left_idx()).max(subtree_height(sgt, node.right_idx()))
+        }
+        None => 0,
+    }
+}
+
 // Build a small tree for testing.
 pub fn get_test_tree_and_keys() -> (SgTree<usize, &'static str, CAPACITY>, Vec<usize>) {
     let keys = vec![2, 1, 6, 5, 15, 4, 12, 16, 3, 9, 13, 17, 7, 11, 14, 18, 10];
@@ -40,6 +55,7 @@ pub fn get_test_tree_and_keys() -> (SgTree<usize, &'static str, CAPACITY>, Vec<u
 // 1. A right child node's key is always greater than it's parent's key.
 // 2. A left child node's key is always less than it's parent's key.
 // 3. Every node has at most 1 parent.
+// 4. With `fast_rebalance`, every node's cached subtree size is accurate.
 fn assert_logical_invariants<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>) {
     if let Some(root_idx) = sgt.opt_root_idx {
         let mut child_idxs = vec![root_idx]; // Count as "child" to make sure there's no other ref to this index
@@ -53,7 +69,7 @@ fn assert_logical_invariants<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>) {
                     "Internal invariant failed: left child >= parent!"
                 );
                 child_idxs.push(left_idx);
-                subtree_worklist.push(&left_child_node);
+                subtree_worklist.push(left_child_node);
             }
 
             if let Some(right_idx) = node.right_idx() {
@@ -63,7 +79,7 @@ fn assert_logical_invariants<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>) {
                     "Internal invariant failed: right child <= parent!"
                 );
                 child_idxs.push(right_idx);
-                subtree_worklist.push(&right_child_node);
+                subtree_worklist.push(right_child_node);
             }
         }
 
@@ -74,9 +90,27 @@ fn assert_logical_invariants<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>) {
             dedup_child_idxs.len() == child_idxs.len(),
             "Internal invariant failed: node with multiple parents present!"
         );
+
+        #[cfg(feature = "fast_rebalance")]
+        assert_subtree_sizes(sgt, root_idx);
     }
 }
 
+// Recursively verify cached subtree sizes, returning the size of the subtree at `idx`.
+#[cfg(feature = "fast_rebalance")]
+fn assert_subtree_sizes<K: Ord, V, const N: usize>(sgt: &SgTree<K, V, N>, idx: usize) -> usize {
+    let node = &sgt.arena[idx];
+    let size = 1
+        + node.left_idx().map_or(0, |i| assert_subtree_sizes(sgt, i))
+        + node.right_idx().map_or(0, |i| assert_subtree_sizes(sgt, i));
+    assert_eq!(
+        node.subtree_size(),
+        size,
+        "Internal invariant failed: stale cached subtree size!"
+    );
+    size
+}
+
 // Inserts random `usize` keys, and randomly removes 20%.
 fn logical_fuzz<const N: usize>(
     sgt: &mut SgTree<usize, &str, N>,
@@ -88,12 +122,11 @@ fn logical_fuzz<const N: usize>(
     let mut slow_rng = rand::rng();
 
     for i in 0..iter_cnt {
-        let rand_key: usize;
-        if check_invars {
-            rand_key = slow_rng.random_range(0..usize::MAX);
+        let rand_key: usize = if check_invars {
+            slow_rng.random_range(0..usize::MAX)
         } else {
-            rand_key = fast_rng.random_range(0..usize::MAX);
-        }
+            fast_rng.random_range(0..usize::MAX)
+        };
 
         // Rand value insert
         shadow_keys.insert(rand_key);
@@ -101,7 +134,7 @@ fn logical_fuzz<const N: usize>(
 
         // Verify internal state post-insert
         if check_invars {
-            assert_logical_invariants(&sgt);
+            assert_logical_invariants(sgt);
             assert_eq!(
                 sgt.len(),
                 shadow_keys.len(),
@@ -121,7 +154,7 @@ fn logical_fuzz<const N: usize>(
 
             // Verify internal state post-remove
             if check_invars {
-                assert_logical_invariants(&sgt);
+                assert_logical_invariants(sgt);
                 assert_eq!(
                     sgt.len(),
                     shadow_keys.len(),
@@ -227,6 +260,7 @@ fn test_tree_sizing() {
     #[cfg(target_pointer_width = "64")]
     #[cfg(not(feature = "low_mem_insert"))]
     #[cfg(not(feature = "fast_rebalance"))]
+    #[cfg(not(feature = "access_tracking"))]
     {
         assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 18_504);
     }
@@ -235,27 +269,55 @@ fn test_tree_sizing() {
     #[cfg(target_pointer_width = "64")]
     #[cfg(feature = "low_mem_insert")]
     #[cfg(feature = "fast_rebalance")]
+    #[cfg(not(feature = "access_tracking"))]
     {
-        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 20_552);
+        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 20_544);
     }
 
     // low_mem_insert only
     #[cfg(target_pointer_width = "64")]
     #[cfg(feature = "low_mem_insert")]
     #[cfg(not(feature = "fast_rebalance"))]
+    #[cfg(not(feature = "access_tracking"))]
     {
-        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 16_456);
+        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 16_448);
     }
 
     // fast_rebalance only
     #[cfg(target_pointer_width = "64")]
     #[cfg(not(feature = "low_mem_insert"))]
     #[cfg(feature = "fast_rebalance")]
+    #[cfg(not(feature = "access_tracking"))]
+    {
+        assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 22_600);
+    }
+
+    // access_tracking only
+    #[cfg(target_pointer_width = "64")]
+    #[cfg(not(feature = "low_mem_insert"))]
+    #[cfg(not(feature = "fast_rebalance"))]
+    #[cfg(feature = "access_tracking")]
     {
         assert_eq!(core::mem::size_of::<SgTree<u32, u32, CAPACITY>>(), 22_600);
     }
 }
 
+#[test]
+fn test_iter_sizing() {
+    // A single index stack, regardless of features: reverse iteration mustn't add a second one
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(
+            core::mem::size_of::<super::Iter<u32, u32, CAPACITY>>(),
+            8_232
+        );
+        assert_eq!(
+            core::mem::size_of::<super::Iter<u32, u32, CAPACITY>>(),
+            core::mem::size_of::<ArrayVec<usize, CAPACITY>>() + (4 * core::mem::size_of::<usize>())
+        );
+    }
+}
+
 #[test]
 fn test_ref_iter() {
     let (sgt, keys) = get_test_tree_and_keys();
@@ -288,12 +350,9 @@ fn test_iter() {
 
 #[test]
 fn test_from_iter() {
-    let mut key_val_tuples = Vec::new();
-    key_val_tuples.push((1, "1"));
-    key_val_tuples.push((2, "2"));
-    key_val_tuples.push((3, "3"));
+    let key_val_tuples = vec![(1, "1"), (2, "2"), (3, "3")];
 
-    let sgt = SgTree::<_, _, CAPACITY>::from_iter(key_val_tuples.into_iter());
+    let sgt = SgTree::<_, _, CAPACITY>::from_iter(key_val_tuples);
 
     assert!(sgt.len() == 3);
     assert_eq!(
@@ -570,6 +629,25 @@ fn test_retain() {
     assert!(sg_map.iter().eq(bt_map.iter()));
 }
 
+#[test]
+fn test_retain_count() {
+    let mut sgt: SgTree<usize, usize, CAPACITY> = (0..100).map(|i| (i, i)).collect();
+    let mut bt_map: BTreeMap<usize, usize> = (0..100).map(|i| (i, i)).collect();
+
+    assert_eq!(sgt.retain_count(|&k, _| k % 3 == 0), 66);
+    bt_map.retain(|&k, _| k % 3 == 0);
+    assert!(sgt.iter().eq(bt_map.iter()));
+
+    // Nothing removed
+    assert_eq!(sgt.retain_count(|_, _| true), 0);
+    assert_eq!(sgt.len(), 34);
+
+    // Everything removed
+    assert_eq!(sgt.retain_count(|_, _| false), 34);
+    assert!(sgt.is_empty());
+    assert_eq!(sgt.retain_count(|_, _| false), 0);
+}
+
 #[test]
 fn test_extend() {
     let mut sgt_1 = SgTree::<_, _, CAPACITY>::new();
@@ -657,7 +735,7 @@ fn test_extend_panic() {
 
     // Attempt to extend already full tree
     assert_eq!(sgt.len(), sgt.capacity());
-    sgt.extend(sgt_2.into_iter()); // Should panic
+    sgt.extend(sgt_2); // Should panic
 }
 
 #[test]
@@ -708,19 +786,19 @@ fn test_clone() {
 fn test_set_rebal_param() {
     use crate::tree::Alpha;
 
-    assert!(CAPACITY >= 100);
+    const { assert!(CAPACITY >= 100) };
     let data: Vec<(usize, usize)> = (0..100).map(|x| (x, x)).collect();
-    let sgt_1 = SgTree::<_, _, CAPACITY>::from_iter(data.clone().into_iter());
+    let sgt_1 = SgTree::<_, _, CAPACITY>::from_iter(data.clone());
 
     // Lax rebalancing
     let mut sgt_2 = SgTree::<_, _, CAPACITY>::new();
     assert!(sgt_2.set_rebal_param(Alpha::lit("0.9")).is_ok());
-    sgt_2.extend(data.clone().into_iter());
+    sgt_2.extend(data.clone());
 
     // Strict rebalancing
     let mut sgt_3 = SgTree::<_, _, CAPACITY>::new();
     assert!(sgt_3.set_rebal_param(Alpha::lit("0.5")).is_ok());
-    sgt_3.extend(data.into_iter());
+    sgt_3.extend(data);
 
     // Invalid rebalance factor
     assert_eq!(
@@ -739,6 +817,45 @@ fn test_set_rebal_param() {
     assert_eq!(sgt_3.rebal_cnt(), 93);
 }
 
+#[test]
+fn test_set_alpha() {
+    use crate::tree::Alpha;
+
+    const { assert!(CAPACITY >= 100) };
+    let mut sgt = SgTree::<_, _, CAPACITY>::new();
+    sgt.set_alpha(9, 10).unwrap();
+    sgt.extend((0..100).map(|x| (x, x)));
+
+    // Lax alpha leaves the tree taller than minimal, re-tuning rebuilds it
+    let rebal_cnt = sgt.rebal_cnt();
+    sgt.set_alpha(1, 2).unwrap();
+    assert_eq!(sgt.rebal_param(), Alpha::lit("0.5"));
+    assert_eq!(sgt.debug_stats().height, 7);
+    assert!(sgt.rebal_cnt() > rebal_cnt);
+    assert!(sgt.iter().map(|(k, _)| *k).eq(0..100));
+
+    // Matches the default for the same ratio
+    sgt.set_alpha(2, 3).unwrap();
+    assert_eq!(
+        sgt.rebal_param(),
+        SgTree::<usize, usize, CAPACITY>::new().rebal_param()
+    );
+
+    // Out of range, alpha unchanged
+    for (num, denom) in [(1, 3), (1, 1), (3, 2), (0, 0), (1, 0)] {
+        assert_eq!(
+            sgt.set_alpha(num, denom),
+            Err(SgError::RebalanceFactorOutOfRange)
+        );
+    }
+    assert_eq!(sgt.rebal_param(), Alpha::lit("2") / Alpha::lit("3"));
+
+    // Empty tree
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    assert!(sgt.set_alpha(3, 4).is_ok());
+    assert!(sgt.is_empty());
+}
+
 #[test]
 fn test_intersect_cnt() {
     let mut sgt_1 = SgTree::from([(3, 4), (1, 2), (5, 6)]);
@@ -760,7 +877,53 @@ fn test_intersect_cnt() {
 #[test]
 fn test_capacity_exceed() {
     const OVER_CAP: usize = (Idx::MAX as usize) + 1;
-    let _ = SgTree::<u8, u8, OVER_CAP>::new();
+
+    // Large arena (more so with per-node metadata features), so use a dedicated thread with a roomy stack
+    let result = std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(|| {
+            let _ = SgTree::<u8, u8, OVER_CAP>::new();
+        })
+        .unwrap()
+        .join();
+
+    if let Err(panic) = result {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+#[test]
+fn test_max_capacity_insert() {
+    const MAX_CAP: usize = Idx::MAX as usize;
+
+    // Large arena, so use a dedicated thread with a roomy stack
+    std::thread::Builder::new()
+        .stack_size(64 * 1024 * 1024)
+        .spawn(|| {
+            let mut sgt = SgTree::<u16, u16, MAX_CAP>::new();
+            assert_eq!(sgt.capacity(), MAX_CAP);
+
+            // Descending order, to exercise rebalancing at every size
+            for k in (1..=Idx::MAX).rev() {
+                assert_eq!(sgt.try_insert(k, k), Ok(None));
+            }
+            assert!(sgt.is_full());
+            assert_eq!(sgt.first_key(), Some(&1));
+            assert_eq!(sgt.last_key(), Some(&Idx::MAX));
+
+            // Updates still succeed when full, new keys fail gracefully
+            assert_eq!(sgt.try_insert(Idx::MAX, 0), Ok(Some(Idx::MAX)));
+            assert_eq!(sgt.try_insert(0, 0), Err(SgError::StackCapacityExceeded));
+
+            // Free up a slot and fill it with the only remaining key
+            assert_eq!(sgt.remove(&(Idx::MAX / 2)), Some(Idx::MAX / 2));
+            assert_eq!(sgt.try_insert(0, 0), Ok(None));
+            assert!(sgt.is_full());
+            assert_eq!(sgt.len(), MAX_CAP);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
 }
 
 #[test]
@@ -778,3 +941,690 @@ fn test_double_ended_iter_mut() {
     assert_eq!(None, iter.next());
     assert_eq!(None, iter.next_back());
 }
+
+#[test]
+fn test_double_ended_iter() {
+    let sgt = SgTree::from([(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)]);
+    let mut iter = sgt.iter();
+
+    assert_eq!(Some((&1, &1)), iter.next());
+    assert_eq!(Some((&6, &6)), iter.next_back());
+    assert_eq!(Some((&5, &5)), iter.next_back());
+    assert_eq!(iter.len(), 3);
+    assert_eq!(Some((&2, &2)), iter.next());
+    assert_eq!(Some((&3, &3)), iter.next());
+    assert_eq!(Some((&4, &4)), iter.next_back());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+
+    let mut iter = sgt.clone().into_iter();
+    assert_eq!(Some((6, 6)), iter.next_back());
+    assert_eq!(Some((1, 1)), iter.next());
+    assert_eq!(Some((5, 5)), iter.next_back());
+    assert_eq!(iter.len(), 3);
+    assert!(iter.eq([(2, 2), (3, 3), (4, 4)]));
+
+    assert!(
+        sgt.iter()
+            .rev()
+            .eq(sgt.iter().collect::<Vec<_>>().into_iter().rev())
+    );
+}
+
+#[test]
+fn test_double_ended_iter_interleaved() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let sgt: SgTree<usize, usize, CAPACITY> = (0..CAPACITY)
+        .map(|_| rng.random::<u32>() as usize)
+        .map(|k| (k, k))
+        .collect();
+    let expected: Vec<usize> = sgt.iter().map(|(k, _)| *k).collect();
+
+    for _ in 0..16 {
+        let directions: Vec<bool> = (0..sgt.len()).map(|_| rng.random_bool(0.5)).collect();
+
+        // Borrowing
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        let mut iter = sgt.iter();
+        for &forward in &directions {
+            match forward {
+                true => front.push(*iter.next().unwrap().0),
+                false => back.push(*iter.next_back().unwrap().0),
+            }
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+
+        // Consuming
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        let mut iter = sgt.clone().into_iter();
+        for &forward in &directions {
+            match forward {
+                true => front.push(iter.next().unwrap().0),
+                false => back.push(iter.next_back().unwrap().0),
+            }
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+    }
+}
+
+#[test]
+fn test_resize() {
+    let (sgt, keys) = get_test_tree_and_keys();
+    let rebal_cnt = sgt.rebal_cnt();
+
+    // Grow
+    let sgt_grown = sgt.clone().resize::<{ CAPACITY * 2 }>().unwrap();
+    assert_logical_invariants(&sgt_grown);
+    assert_eq!(sgt_grown.capacity(), CAPACITY * 2);
+    assert_eq!(sgt_grown.rebal_cnt(), rebal_cnt);
+    assert!(sgt_grown.iter().eq(sgt.iter()));
+    assert_eq!(sgt_grown.first_key(), sgt.first_key());
+    assert_eq!(sgt_grown.last_key(), sgt.last_key());
+
+    // Shrink to exact fit
+    let mut sgt_exact = sgt.clone().resize::<17>().unwrap();
+    assert_logical_invariants(&sgt_exact);
+    assert!(sgt_exact.is_full());
+    assert!(sgt_exact.iter().eq(sgt.iter()));
+
+    // Fully functional after resize
+    for k in &keys {
+        assert_eq!(sgt_exact.remove(k), Some("n/a"));
+        assert_logical_invariants(&sgt_exact);
+    }
+    assert!(sgt_exact.is_empty());
+
+    // Too small, original returned untouched
+    let (sgt_orig, err) = sgt.clone().resize::<16>().unwrap_err();
+    assert_eq!(err, SgError::StackCapacityExceeded);
+    assert!(sgt_orig.iter().eq(sgt.iter()));
+
+    // Empty
+    let sgt_empty = SgTree::<usize, usize, CAPACITY>::new()
+        .resize::<1>()
+        .unwrap();
+    assert!(sgt_empty.is_empty());
+}
+
+#[test]
+fn test_ensure_capacity() {
+    let (sgt, keys) = get_test_tree_and_keys();
+
+    // Same capacity: moved as-is, shape and arena layout untouched
+    let mut sgt_same = sgt.clone().ensure_capacity::<CAPACITY>().unwrap();
+    assert_logical_invariants(&sgt_same);
+    assert_eq!(sgt_same.opt_root_idx, sgt.opt_root_idx);
+    assert_eq!(sgt_same.arena.len(), sgt.arena.len());
+    assert_eq!(sgt_same.rebal_cnt(), sgt.rebal_cnt());
+    assert!(sgt_same.iter().eq(sgt.iter()));
+
+    // Fully functional after move
+    sgt_same.insert(usize::MAX, "new");
+    for k in &keys {
+        assert_eq!(sgt_same.remove(k), Some("n/a"));
+        assert_logical_invariants(&sgt_same);
+    }
+    assert_eq!(sgt_same.pop_first(), Some((usize::MAX, "new")));
+
+    // Grow, rebuilt balanced
+    let sgt_grown = sgt.clone().ensure_capacity::<{ CAPACITY * 2 }>().unwrap();
+    assert_logical_invariants(&sgt_grown);
+    assert_eq!(sgt_grown.capacity(), CAPACITY * 2);
+    assert!(sgt_grown.iter().eq(sgt.iter()));
+
+    // Too small
+    assert_eq!(
+        sgt.ensure_capacity::<16>().unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+}
+
+#[test]
+fn test_insert_many() {
+    let mut rng = SmallRng::from_os_rng();
+    let mut sgt = SgTree::<u16, u64, CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    // Batches with duplicates, both within a batch and against existing keys
+    for _ in 0..8 {
+        let batch: Vec<(u16, u64)> = (0..(CAPACITY / 8))
+            .map(|_| (rng.random_range(0..(CAPACITY as u16)), rng.random()))
+            .collect();
+
+        let old_len = btm.len();
+        btm.extend(batch.iter().cloned());
+
+        let rebal_cnt = sgt.rebal_cnt();
+        assert_eq!(sgt.insert_many(batch), Ok(btm.len() - old_len));
+        assert_eq!(sgt.rebal_cnt(), rebal_cnt + 1);
+        assert_logical_invariants(&sgt);
+        assert!(sgt.iter().eq(btm.iter()));
+        assert_eq!(sgt.first_key_value(), btm.first_key_value());
+        assert_eq!(sgt.last_key_value(), btm.last_key_value());
+    }
+
+    // Still a functional tree
+    for k in btm.keys() {
+        assert!(sgt.remove(k).is_some());
+        assert_logical_invariants(&sgt);
+    }
+    assert!(sgt.is_empty());
+
+    // Empty input
+    assert_eq!(sgt.insert_many(Vec::new()), Ok(0));
+    assert!(sgt.is_empty());
+}
+
+#[test]
+fn test_insert_many_full() {
+    let mut sgt = SgTree::<usize, &str, 4>::new();
+    assert_eq!(sgt.insert_many([(1, "a"), (2, "b"), (3, "c")]), Ok(3));
+
+    // Updates of existing keys, and in-batch duplicates, don't consume capacity
+    assert_eq!(
+        sgt.insert_many([(1, "A"), (2, "B"), (4, "d"), (4, "D"), (3, "C"), (1, "AA")]),
+        Ok(1)
+    );
+    assert!(sgt.is_full());
+    assert_logical_invariants(&sgt);
+    assert_eq!(
+        sgt.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        vec![(1, "AA"), (2, "B"), (3, "C"), (4, "D")]
+    );
+
+    // Overflow is all-or-nothing: nothing is added
+    let mut sgt = SgTree::<usize, &str, 4>::new();
+    sgt.insert(1, "x");
+    assert_eq!(
+        sgt.insert_many([
+            (1, "a"),
+            (1, "a"),
+            (2, "b"),
+            (3, "c"),
+            (4, "d"),
+            (5, "e"),
+            (6, "f")
+        ]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_logical_invariants(&sgt);
+    assert!(sgt.iter().eq([(&1, &"x")]));
+
+    // Fits once updates are accounted for against existing keys, even with an over-capacity input
+    assert_eq!(
+        sgt.insert_many([(2, "b"), (1, "a"), (3, "c"), (4, "d"), (2, "B"), (3, "C")]),
+        Ok(3)
+    );
+    assert_eq!(
+        sgt.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+        vec![(1, "a"), (2, "B"), (3, "C"), (4, "d")]
+    );
+
+    // More distinct new keys than free slots, detected only after the batch is compacted
+    let mut sgt = SgTree::<usize, &str, 4>::new();
+    sgt.insert(1, "x");
+    sgt.insert(2, "y");
+    assert_eq!(
+        sgt.insert_many([(3, "c"), (4, "d"), (5, "e")]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_logical_invariants(&sgt);
+    assert_eq!(sgt.len(), 2);
+}
+
+#[test]
+fn test_insert_many_panic_safety() {
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    // Key whose comparison panics when either side is the poison value
+    #[derive(Debug, PartialEq, Eq)]
+    struct Touchy(usize);
+
+    impl PartialOrd for Touchy {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Touchy {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            assert!(self.0 != 999 && other.0 != 999, "poisoned comparison");
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let mut sgt = SgTree::<usize, usize, 16>::new();
+    for k in 0..8 {
+        sgt.insert(k, k);
+    }
+    let before: Vec<(usize, usize)> = sgt.iter().map(|(k, v)| (*k, *v)).collect();
+
+    // Iterator panics mid-batch, after some pairs were staged
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        sgt.insert_many((4..12).map(|k| {
+            assert!(k != 10, "iterator failed");
+            (k, k * 10)
+        }))
+    }));
+    assert!(res.is_err());
+    assert_logical_invariants(&sgt);
+    assert!(sgt.iter().map(|(k, v)| (*k, *v)).eq(before.iter().cloned()));
+
+    // Free slots weren't leaked: the tree still fills to capacity
+    for k in 100..108 {
+        sgt.insert(k, k);
+    }
+    assert!(sgt.is_full());
+    assert_logical_invariants(&sgt);
+
+    // Key comparison panics while staging
+    let mut sgt = SgTree::<Touchy, usize, 16>::new();
+    for k in 0..8 {
+        sgt.insert(Touchy(k), k);
+    }
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        sgt.insert_many([(Touchy(20), 0), (Touchy(999), 0), (Touchy(21), 0)])
+    }));
+    assert!(res.is_err());
+    assert_logical_invariants(&sgt);
+    assert_eq!(sgt.len(), 8);
+    assert!(sgt.iter().map(|(k, _)| k.0).eq(0..8));
+    assert_eq!(sgt.insert_many((8..16).map(|k| (Touchy(k), k))), Ok(8));
+    assert!(sgt.is_full());
+}
+
+#[test]
+fn test_first_last_n() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    assert_eq!(sgt.first_n(3).count(), 0);
+    assert_eq!(sgt.last_n(3).count(), 0);
+
+    let mut rng = SmallRng::from_os_rng();
+    let keys: Vec<usize> = (0..CAPACITY / 2)
+        .map(|_| rng.random::<u32>() as usize)
+        .collect();
+    for k in &keys {
+        sgt.insert(*k, *k);
+    }
+
+    let mut sorted = keys;
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    for k in [0, 1, 7, sorted.len() - 1, sorted.len(), sorted.len() + 10] {
+        let k_clamped = k.min(sorted.len());
+        assert!(
+            sgt.first_n(k)
+                .map(|(k, _)| *k)
+                .eq(sorted[..k_clamped].iter().copied())
+        );
+        assert!(
+            sgt.last_n(k)
+                .map(|(k, _)| *k)
+                .eq(sorted[sorted.len() - k_clamped..].iter().copied())
+        );
+    }
+}
+
+#[test]
+fn test_k_nearest_by() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    for k in keys.iter().step_by(4) {
+        sgt.remove(k);
+    }
+    let dist = |k: &usize, q: &usize| k.abs_diff(*q);
+
+    // Query both present keys and gaps, walking out to (and past) every entry
+    let sorted: Vec<usize> = sgt.iter().map(|(k, _)| *k).collect();
+    let queries = sorted
+        .iter()
+        .flat_map(|k| [*k, k.saturating_add(1)])
+        .chain([0, usize::MAX]);
+    for query in queries.step_by(7) {
+        let mut expected = sorted.clone();
+        expected.sort_by_key(|k| (dist(k, &query), *k));
+
+        for k in [1, 3, sorted.len(), sorted.len() + 2] {
+            assert!(
+                sgt.k_nearest_by(&query, k, dist)
+                    .map(|(k, _)| *k)
+                    .eq(expected.iter().copied().take(k))
+            );
+        }
+    }
+
+    assert_eq!(sgt.k_nearest_by(&sorted[0], 0, dist).count(), 0);
+}
+
+#[test]
+fn test_get_index() {
+    let (mut sgt, mut keys) = get_test_tree_and_keys();
+    keys.sort_unstable();
+    keys.dedup();
+
+    for (i, k) in keys.iter().enumerate() {
+        assert_eq!(sgt.get_index(i).map(|(k, _)| k), Some(k));
+    }
+    assert_eq!(sgt.get_index(keys.len()), None);
+
+    let mid = keys.len() / 2;
+    let (k, v) = sgt.get_index_mut(mid).unwrap();
+    assert_eq!(*k, keys[mid]);
+    *v = "updated";
+    assert_eq!(sgt.get(&keys[mid]), Some(&"updated"));
+
+    // Still exact after removals (cached subtree sizes, if any, are kept in sync)
+    for k in keys.iter().step_by(3) {
+        sgt.remove(k);
+    }
+    let remaining: Vec<usize> = sgt.iter().map(|(k, _)| *k).collect();
+    for (i, k) in remaining.iter().enumerate() {
+        assert_eq!(sgt.get_index(i).map(|(k, _)| k), Some(k));
+    }
+    assert_eq!(sgt.get_index(remaining.len()), None);
+
+    sgt.clear();
+    assert_eq!(sgt.get_index(0), None);
+    assert_eq!(sgt.get_index_mut(0), None);
+}
+
+#[test]
+fn test_range_bounds_resolved() {
+    let (mut sgt, keys) = get_test_tree_and_keys();
+    for k in keys.iter().step_by(5) {
+        sgt.remove(k);
+    }
+
+    let sorted: Vec<usize> = sgt.iter().map(|(k, _)| *k).collect();
+    let check = |resolved: (usize, Option<(usize, usize)>), expected: Vec<usize>| {
+        assert_eq!(resolved.0, expected.len());
+        match resolved.1 {
+            Some((first_idx, last_idx)) => {
+                assert_eq!(sgt.arena[first_idx].key(), expected.first().unwrap());
+                assert_eq!(sgt.arena[last_idx].key(), expected.last().unwrap());
+            }
+            None => assert!(expected.is_empty()),
+        }
+    };
+
+    let gap = sorted[5] + 1; // Between two keys, unless they're adjacent integers
+    for (lo, hi) in [
+        (0, usize::MAX),
+        (sorted[1], sorted[sorted.len() - 2]),
+        (gap, gap),
+        (sorted[7], sorted[7]),
+    ] {
+        let in_range =
+            |pred: &dyn Fn(usize) -> bool| sorted.iter().copied().filter(|k| pred(*k)).collect();
+        check(
+            sgt.range_bounds_resolved(&(lo..=hi)),
+            in_range(&|k| lo <= k && k <= hi),
+        );
+        check(
+            sgt.range_bounds_resolved(&(lo..hi)),
+            in_range(&|k| lo <= k && k < hi),
+        );
+        check(sgt.range_bounds_resolved(&(lo..)), in_range(&|k| lo <= k));
+        check(sgt.range_bounds_resolved(&(..hi)), in_range(&|k| k < hi));
+        check(
+            sgt.range_bounds_resolved(&(Excluded(lo), Unbounded)),
+            in_range(&|k| lo < k),
+        );
+    }
+
+    sgt.clear();
+    assert_eq!(sgt.range_bounds_resolved(&(..)), (0, None));
+}
+
+#[test]
+fn test_position_and_find_index() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    assert_eq!(sgt.position(&0), None);
+    assert_eq!(sgt.find_index(|_, _| true), None);
+
+    for _ in 0..(CAPACITY / 2) {
+        let k = rng.random_range(0..CAPACITY);
+        sgt.insert(k, k % 7);
+    }
+    for _ in 0..(CAPACITY / 8) {
+        sgt.remove(&rng.random_range(0..CAPACITY));
+    }
+
+    let sorted: Vec<(usize, usize)> = sgt.iter().map(|(k, v)| (*k, *v)).collect();
+    for k in 0..CAPACITY {
+        let expected = sorted.iter().position(|(sk, _)| *sk == k);
+        assert_eq!(sgt.position(&k), expected);
+        if let Some(pos) = expected {
+            assert_eq!(sgt.get_index(pos).map(|(k, _)| *k), Some(k));
+        }
+    }
+
+    for target in 0..8 {
+        let expected = sorted.iter().position(|(_, v)| *v == target);
+        assert_eq!(sgt.find_index(|_, v| *v == target), expected);
+    }
+
+    // Still exact after removals (cached subtree sizes, if any, are kept in sync)
+    for k in (0..CAPACITY).step_by(3) {
+        sgt.remove(&k);
+    }
+    for (pos, (k, _)) in sgt.iter().enumerate() {
+        assert_eq!(sgt.position(k), Some(pos));
+    }
+    assert_eq!(sgt.position(&0), None);
+}
+
+#[test]
+fn test_split_at_key() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    for k in 0..500 {
+        sgt.insert(k * 2, k);
+    }
+
+    for split_key in [0, 1, 250, 501, 998, 999, 5000] {
+        let (lt, ge) = sgt.clone().split_at_key(&split_key);
+        assert_logical_invariants(&lt);
+        assert_logical_invariants(&ge);
+        assert!(lt.iter().all(|(k, _)| *k < split_key));
+        assert!(ge.iter().all(|(k, _)| *k >= split_key));
+        assert!(lt.iter().chain(ge.iter()).eq(sgt.iter()));
+
+        // Rebuilt from sorted partitions, so both halves are perfectly balanced
+        for half in [&lt, &ge] {
+            let min_height = (usize::BITS - half.len().leading_zeros()) as usize;
+            assert_eq!(half.height(), min_height);
+        }
+    }
+
+    let (lt, ge) = SgTree::<usize, usize, CAPACITY>::new().split_at_key(&0);
+    assert!(lt.is_empty() && ge.is_empty());
+}
+
+#[test]
+fn test_truncate_first_last() {
+    let sgt: SgTree<usize, usize, CAPACITY> = (0..200).map(|i| (i, i * 2)).collect();
+
+    for k in [0, 1, 2, 50, 199, 200, 500] {
+        let kept = k.min(sgt.len());
+
+        let mut first = sgt.clone();
+        first.truncate_first(k);
+        assert_logical_invariants(&first);
+        assert!(first.iter().eq(sgt.iter().take(kept)));
+
+        let mut last = sgt.clone();
+        last.truncate_last(k);
+        assert_logical_invariants(&last);
+        assert!(last.iter().eq(sgt.iter().skip(sgt.len() - kept)));
+
+        // Survivors relinked balanced, freed slots re-usable
+        for t in [&mut first, &mut last] {
+            if k < sgt.len() {
+                let min_height = (usize::BITS - t.len().leading_zeros()) as usize;
+                assert_eq!(t.height(), min_height);
+            }
+            assert_eq!(t.first_key_value(), t.iter().next());
+            assert_eq!(t.last_key_value(), t.iter().next_back());
+            while t.len() < t.capacity() {
+                t.insert(1_000 + t.len(), 0);
+            }
+            assert_logical_invariants(t);
+        }
+    }
+}
+
+#[test]
+fn test_optimize() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    sgt.optimize();
+    assert_eq!(sgt.height(), 0);
+
+    // Sorted inserts followed by removals from one side, staying under the global rebuild threshold
+    sgt.extend((0..CAPACITY).map(|k| (k, k)));
+    for k in 0..(CAPACITY / 2 - 1) {
+        sgt.remove(&k);
+    }
+    assert_logical_invariants(&sgt);
+    let skewed_height = sgt.height();
+    let rebal_cnt = sgt.rebal_cnt();
+
+    sgt.optimize();
+    assert_logical_invariants(&sgt);
+    let min_height = (usize::BITS - sgt.len().leading_zeros()) as usize;
+    assert!(skewed_height > min_height);
+    assert_eq!(sgt.height(), min_height);
+    assert_eq!(sgt.rebal_cnt(), rebal_cnt + 1);
+    assert!(sgt.iter().map(|(k, _)| *k).eq((CAPACITY / 2 - 1)..CAPACITY));
+
+    // Still behaves normally afterwards
+    sgt.insert(0, 0);
+    assert_eq!(sgt.first_key(), Some(&0));
+    assert_logical_invariants(&sgt);
+}
+
+#[test]
+fn test_remove_many() {
+    let mut rng = SmallRng::seed_from_u64(0);
+
+    for _ in 0..50 {
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        let mut btm = BTreeMap::new();
+        for _ in 0..rng.random_range(0..CAPACITY) {
+            let k = rng.random_range(0..CAPACITY);
+            sgt.insert(k, k);
+            btm.insert(k, k);
+        }
+
+        let mut doomed: Vec<usize> = (0..rng.random_range(0..CAPACITY))
+            .map(|_| rng.random_range(0..CAPACITY))
+            .collect();
+        if rng.random_bool(0.5) {
+            doomed.sort_unstable();
+        }
+
+        let expected = doomed.iter().filter(|k| btm.remove(*k).is_some()).count();
+        assert_eq!(sgt.remove_many(&doomed), expected);
+        assert_logical_invariants(&sgt);
+        assert!(sgt.iter().eq(btm.iter()));
+        assert_eq!(sgt.len(), btm.len());
+
+        // Tree stays usable after the rebuild
+        sgt.insert(CAPACITY, 0);
+        assert_eq!(sgt.last_key(), Some(&CAPACITY));
+        assert_logical_invariants(&sgt);
+    }
+
+    // Single survivor and total removal
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::from_iter((0..10).map(|k| (k, k)));
+    assert_eq!(sgt.remove_many(&[0, 1, 2, 3, 4, 6, 7, 8, 9, 42]), 9);
+    assert_logical_invariants(&sgt);
+    assert!(sgt.iter().eq([(&5, &5)]));
+    assert_eq!(sgt.remove_many(&[5, 5]), 1);
+    assert!(sgt.is_empty());
+    assert_eq!(sgt.remove_many(&[5]), 0);
+}
+
+#[test]
+fn test_debug_stats() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    let stats = sgt.debug_stats();
+    assert_eq!(
+        (stats.live, stats.free, stats.height, stats.rebuilds),
+        (0, 0, 0, 0)
+    );
+    assert_eq!(stats.capacity, CAPACITY);
+
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..5_000 {
+        let k = rng.random_range(0..CAPACITY);
+        if rng.random_bool(0.6) {
+            sgt.insert(k, k);
+        } else {
+            sgt.remove(&k);
+        }
+
+        let stats = sgt.debug_stats();
+        assert_eq!(stats.live, sgt.len());
+        assert_eq!(stats.live + stats.free, sgt.arena.len());
+        assert_eq!(stats.height, subtree_height(&sgt, sgt.opt_root_idx));
+        assert_eq!(stats.rebuilds, sgt.rebal_cnt());
+    }
+
+    // Sorted insertion of a full tree rebuilds, but stays within the alpha height bound
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    sgt.extend((0..CAPACITY).map(|k| (k, k)));
+    let stats = sgt.debug_stats();
+    assert_eq!((stats.live, stats.free), (CAPACITY, 0));
+    assert!(stats.rebuilds > 0);
+    assert!(stats.height <= 2 * (usize::BITS - CAPACITY.leading_zeros()) as usize);
+}
+
+#[test]
+fn test_bracket() {
+    let (sgt, keys) = get_test_tree_and_keys();
+    let btm: BTreeMap<usize, &str> = keys.iter().map(|k| (*k, "n/a")).collect();
+
+    let probes = keys
+        .iter()
+        .flat_map(|k| [k.saturating_sub(1), *k, k + 1])
+        .chain([0, usize::MAX]);
+
+    for probe in probes {
+        let lower = btm.range(..=probe).next_back();
+        let upper = btm.range((Excluded(probe), Unbounded)).next();
+        assert_eq!(sgt.bracket(&probe), (lower, upper));
+    }
+
+    let empty = SgTree::<usize, usize, CAPACITY>::new();
+    assert_eq!(empty.bracket(&1), (None, None));
+}
+
+#[cfg(feature = "access_tracking")]
+#[test]
+fn test_access_clock_overflow() {
+    let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+    for i in 0..10 {
+        sgt.insert(i, i);
+    }
+
+    // Recency: 3, 7, then the rest
+    sgt.get_touch(&7);
+    sgt.get_touch(&3);
+
+    // Force a wrap, recency order must survive the renumbering
+    sgt.access_tick = u32::MAX;
+    sgt.get_touch(&5);
+    assert!(sgt.access_tick <= 11);
+
+    let evicted: Vec<usize> = core::iter::from_fn(|| sgt.evict_lru().map(|(k, _)| k)).collect();
+    assert_eq!(evicted, [0, 1, 2, 4, 6, 8, 9, 7, 3, 5]);
+}
@@ -2,20 +2,23 @@ use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt::{self, Debug};
 use core::hash::{Hash, Hasher};
-use core::iter::FromIterator;
+use core::iter::{FromIterator, Take};
 use core::mem;
 use core::ops::{
-    Bound::{Excluded, Included},
+    Bound::{Excluded, Included, Unbounded},
     Index, RangeBounds, Sub,
 };
+use core::ptr;
 
 use branches::{assume, likely, unlikely};
 
 use super::arena::Arena;
 use super::error::SgError;
+use super::fnv::Fnv1a;
 use super::iter::{IntoIter, Iter, IterMut};
-use super::node::{NodeGetHelper, NodeRebuildHelper};
+use super::node::{Node, NodeGetHelper, NodeRebuildHelper};
 use super::node_dispatch::SmallNode;
+use super::stats::ArenaStats;
 
 use arrayvec::ArrayVec;
 use const_panic::concat_panic;
@@ -46,6 +49,10 @@ pub struct SgTree<K, V, const N: usize> {
     alpha: Alpha,
     max_size: usize,
     rebal_cnt: usize,
+
+    // Recency clock, last tick handed out
+    #[cfg(feature = "access_tracking")]
+    pub(crate) access_tick: u32,
 }
 
 impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
@@ -53,6 +60,15 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
 
     /// Makes a new, empty `SgTree`.
     pub const fn new() -> Self {
+        // Rust objects can't exceed `isize::MAX` bytes, which 16 and 32-bit targets hit well before `Idx::MAX` items.
+        // Rejected at compile time, when the tree type is instantiated.
+        const {
+            assert!(
+                N <= isize::MAX as usize / mem::size_of::<Option<Node<K, V, Idx>>>(),
+                "Stack capacity `N` exceeds the target's maximum object size!"
+            )
+        };
+
         if N > SgTree::<K, V, N>::max_capacity() {
             concat_panic!("Max stack item capacity (0x", {X}: Idx::MAX, ") exceeded!");
         }
@@ -66,6 +82,9 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
             alpha: DEFAULT_ALPHA,
             max_size: 0,
             rebal_cnt: 0,
+
+            #[cfg(feature = "access_tracking")]
+            access_tick: 0,
         }
     }
 
@@ -92,6 +111,20 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Set alpha to `numerator / denominator` (rounded down to `Alpha` precision), then rebuild the whole tree
+    /// so the new bound applies from the next operation on.
+    pub fn set_alpha(&mut self, numerator: u32, denominator: u32) -> Result<(), SgError> {
+        // Also rejects a zero denominator. Below one, the quotient's fractional bits always fit.
+        if numerator >= denominator {
+            return Err(SgError::RebalanceFactorOutOfRange);
+        }
+
+        let bits = (u64::from(numerator) << Alpha::FRAC_NBITS) / u64::from(denominator);
+        self.set_rebal_param(Alpha::from_bits(bits as u32))?;
+        self.optimize();
+        Ok(())
+    }
+
     /// Get the current rebalance parameter, alpha.
     /// See [the corresponding setter method][SgTree::set_rebal_param] for more details.
     #[inline]
@@ -129,12 +162,13 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
 
         // Rip elements directly out of other's arena and clear it
         for arena_idx in 0..other.arena.len() {
-            if let Some(mut node) = other.arena.remove(arena_idx) {
+            if let Some(node) = other.arena.remove(arena_idx) {
                 // Prefetch write location for better cache performance
                 if let Some(root_idx) = self.opt_root_idx {
                     branches::prefetch_write_data::<_, 1>(&self.arena[root_idx]);
                 }
-                self.insert(node.take_key(), node.take_val());
+                let (key, val) = node.into_key_val();
+                self.insert(key, val);
             }
         }
         other.clear();
@@ -156,12 +190,13 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         // Rip elements directly out of other's arena and clear it
         if (self.len() + other.len() - self.intersect_cnt(other)) <= self.capacity() {
             for arena_idx in 0..other.arena.len() {
-                if let Some(mut node) = other.arena.remove(arena_idx) {
+                if let Some(node) = other.arena.remove(arena_idx) {
                     // Prefetch write location for better cache performance
                     if let Some(root_idx) = self.opt_root_idx {
                         branches::prefetch_write_data::<_, 1>(&self.arena[root_idx]);
                     }
-                    self.try_insert(node.take_key(), node.take_val())?;
+                    let (key, val) = node.into_key_val();
+                    self.try_insert(key, val)?;
                 }
             }
             other.clear();
@@ -174,6 +209,59 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         Ok(())
     }
 
+    /// Attempts to move all elements from `other`, a tree of any capacity, into `self`, leaving `other` empty.
+    /// On failure, neither `self` nor `other` is modified.
+    pub fn try_append_from<const M: usize>(
+        &mut self,
+        other: &mut SgTree<K, V, M>,
+    ) -> Result<(), SgError>
+    where
+        K: Ord,
+    {
+        let common_cnt = other.iter().filter(|(k, _)| self.contains_key(k)).count();
+
+        // Preemptive - we haven't mutated `self` or `other`!
+        if (self.len() + other.len() - common_cnt) > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        // Rip elements directly out of other's arena and clear it
+        for arena_idx in 0..other.arena.len() {
+            if let Some(node) = other.arena.remove(arena_idx) {
+                let (key, val) = node.into_key_val();
+                self.try_insert(key, val)?;
+            }
+        }
+        other.clear();
+
+        Ok(())
+    }
+
+    /// Attempts to move all elements from `other` into `self`, leaving `other` empty.
+    /// Returns the keys already present in `self`, whose values were overwritten, in sorted order.
+    /// On failure, neither `self` nor `other` is modified.
+    pub fn try_append_reporting(
+        &mut self,
+        other: &mut SgTree<K, V, N>,
+    ) -> Result<ArrayVec<K, N>, SgError>
+    where
+        K: Ord + Clone,
+    {
+        let conflicts: ArrayVec<K, N> = other
+            .iter()
+            .filter(|(k, _)| self.contains_key(k))
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        // Preemptive - we haven't mutated `self` or `other`!
+        if (self.len() + other.len() - conflicts.len()) > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        self.try_append(other)?;
+        Ok(conflicts)
+    }
+
     /// Insert a key-value pair into the tree.
     /// If the tree did not have this key present, `None` is returned.
     /// If the tree did have this key present, the value is updated, the old value is returned,
@@ -185,6 +273,74 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         self.internal_balancing_insert::<Idx>(key, val).0
     }
 
+    /// Insert a key-value pair into the tree, like [`insert`][SgTree::insert].
+    /// Also returns `true` if the insertion triggered a subtree rebuild.
+    pub fn insert_tracked(&mut self, key: K, val: V) -> (Option<V>, bool)
+    where
+        K: Ord,
+    {
+        let rebal_cnt = self.rebal_cnt;
+        let opt_val = self.insert(key, val);
+        (opt_val, self.rebal_cnt != rebal_cnt)
+    }
+
+    /// Insert `default` if `key` is absent, else apply `update` to the present value, in a single descent.
+    /// Returns a mutable reference to the inserted or updated value. The stored key is left unchanged on update.
+    pub fn upsert<F>(&mut self, key: K, default: V, update: F) -> &mut V
+    where
+        K: Ord,
+        F: FnOnce(&mut V),
+    {
+        let (_, idx) = self.internal_balancing_upsert::<Idx, _>(key, default, |node, _, _| {
+            update(node.get_mut().1);
+            None
+        });
+
+        self.arena[idx].get_mut().1
+    }
+
+    /// Insert `val` if `key` is absent, else replace the present value with `merge(old, val)`, in a single descent.
+    /// Returns `Err` only if `key` is absent and the tree's stack capacity is full. The stored key is left unchanged.
+    ///
+    /// On a hit, the value is merged in place, so the tree's shape is untouched (no rebuild).
+    /// If `merge` panics, the vacated node is unlinked during unwinding: the tree stays valid, without that entry.
+    pub fn insert_with<F>(&mut self, key: K, val: V, merge: F) -> Result<(), SgError>
+    where
+        K: Ord,
+        F: FnOnce(V, V) -> V,
+    {
+        let (idx, opt_incoming) = match self.len() < self.capacity() {
+            // Room for a new node: insert or hand back the incoming value on collision
+            true => {
+                let mut opt_incoming = None;
+                let (_, idx) = self.internal_balancing_upsert::<Idx, _>(key, val, |_, _, val| {
+                    opt_incoming = Some(val);
+                    None
+                });
+                (idx, opt_incoming)
+            }
+            // Full: only an existing key can be merged
+            false => match self.internal_get::<K, Idx>(None, &key).node_idx() {
+                Some(idx) => (idx, Some(val)),
+                None => return Err(SgError::StackCapacityExceeded),
+            },
+        };
+
+        if let Some(val) = opt_incoming {
+            // SAFETY: `idx` is occupied. The value moved out here is replaced by the write below, or, if `merge`
+            // unwinds first, the guard unlinks the node and forgets the stale bits so they're never dropped twice.
+            let old = unsafe { ptr::read(self.arena[idx].val()) };
+            let guard = VacatedSlotGuard { tree: self, idx };
+            let merged = merge(old, val);
+            mem::forget(guard);
+
+            // SAFETY: The slot's value was moved out above, so it's overwritten without being dropped.
+            unsafe { ptr::write(self.arena[idx].get_mut().1, merged) };
+        }
+
+        Ok(())
+    }
+
     /// Insert a key-value pair into the tree.
     /// Returns `Err` if tree's stack capacity is full, else the `Ok` contains:
     /// * `None` if the tree did not have this key present.
@@ -217,6 +373,72 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Replace the entire contents with pairs from an iterator, transactionally.
+    /// Pairs are bulk-loaded into a temporary tree, only swapped in once all of them fit.
+    /// Returns `Err`, leaving `self` unmodified, if they don't. The rebalance factor is preserved.
+    pub fn try_replace_all<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError>
+    where
+        K: Ord,
+    {
+        let mut staged = SgTree::new();
+        staged.alpha = self.alpha;
+        staged.insert_many(iter)?;
+
+        *self = staged;
+        Ok(())
+    }
+
+    /// Insert all pairs from an iterator in bulk, returning the number of new keys added.
+    /// If a key appears more than once, the last pair wins (both key and value are updated, as with `insert`).
+    ///
+    /// Pairs are staged in a scratch arena, sorted once, merged with existing entries,
+    /// and the whole tree is rebuilt balanced in a single pass - instead of a descent (and potential rebalance) per pair.
+    ///
+    /// All-or-nothing: returns `Err`, leaving the tree unmodified, if the new keys don't fit within capacity.
+    /// The tree is likewise left unmodified if the iterator or a key comparison panics.
+    pub fn insert_many<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<usize, SgError> {
+        let mut scratch = Arena::<K, V, Idx, N>::new();
+        let mut staged = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut is_compact = true;
+
+        for (k, v) in iter {
+            // Out of scratch slots, try to reclaim any taken by duplicates
+            if !is_compact && staged.is_full() {
+                Self::compact_staged(&mut scratch, &mut staged);
+                is_compact = true;
+            }
+
+            if !staged.is_full() {
+                staged.push(Idx::checked_from(scratch.add(k, v)));
+                is_compact = false;
+                continue;
+            }
+
+            // Still full of distinct keys, only a staged key's update can succeed.
+            // Otherwise there are more than `N` distinct keys, which can never fit.
+            match staged.binary_search_by(|idx| scratch[idx.usize()].key().cmp(&k)) {
+                Ok(pos) => {
+                    let node = &mut scratch[staged[pos].usize()];
+                    node.set_key(k);
+                    node.set_val(v);
+                }
+                Err(_) => return Err(SgError::StackCapacityExceeded),
+            }
+        }
+
+        if !is_compact {
+            Self::compact_staged(&mut scratch, &mut staged);
+        }
+
+        self.merge_staged(scratch, &staged)
+    }
+
     // Attempt conversion from an iterator.
     /// Will fail if iterator length exceeds `u16::MAX`.
     #[inline]
@@ -229,6 +451,247 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Moves all elements into a new tree with capacity `M`.
+    /// Returns `Err` containing the original, unmodified tree if `M` is less than the current length
+    /// or exceeds the maximum supported capacity.
+    pub fn resize<const M: usize>(mut self) -> Result<SgTree<K, V, M>, (Self, SgError)> {
+        if M > SgTree::<K, V, M>::max_capacity() {
+            return Err((self, SgError::MaximumCapacityExceeded));
+        }
+
+        if self.len() > M {
+            return Err((self, SgError::StackCapacityExceeded));
+        }
+
+        let mut resized = SgTree::<K, V, M>::new();
+        resized.alpha = self.alpha;
+        resized.rebal_cnt = self.rebal_cnt;
+
+        let root_idx = match self.opt_root_idx {
+            Some(root_idx) => root_idx,
+            None => return Ok(resized),
+        };
+
+        // Source is already sorted, so nodes land in the destination arena in key order.
+        // Linking them directly avoids the per-element traversal (and rebalancing) of insertion.
+        let mut sorted_idxs = ArrayVec::<usize, M>::new_const();
+        for idx in self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx) {
+            let (k, v) = self.arena.hard_remove(idx.usize()).into_key_val();
+            sorted_idxs.push(resized.arena.add(k, v));
+        }
+
+        resized.link_sorted_idxs(&sorted_idxs);
+        Ok(resized)
+    }
+
+    /// Moves all elements into a new tree with capacity `M`, for growing ahead of future inserts.
+    /// If `M == N`, the tree is moved as-is (no rebuild). Otherwise, it's rebuilt balanced, like [`Self::resize`].
+    /// Unlike [`Self::resize`], the tree is consumed on failure.
+    pub fn ensure_capacity<const M: usize>(self) -> Result<SgTree<K, V, M>, SgError> {
+        if M != N {
+            return self.resize::<M>().map_err(|(_, err)| err);
+        }
+
+        Ok(SgTree {
+            arena: self.arena.into_capacity::<M>(),
+            opt_root_idx: self.opt_root_idx,
+            max_idx: self.max_idx,
+            min_idx: self.min_idx,
+            curr_size: self.curr_size,
+            alpha: self.alpha,
+            max_size: self.max_size,
+            rebal_cnt: self.rebal_cnt,
+
+            #[cfg(feature = "access_tracking")]
+            access_tick: self.access_tick,
+        })
+    }
+
+    /// Consumes the tree, partitioning it into `(less_than_key, greater_or_equal_to_key)`.
+    /// Each half is built balanced from its sorted partition, rather than via repeated insertion.
+    pub fn split_at_key<Q>(mut self, key: &Q) -> (Self, Self)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut lt_tree = SgTree::<K, V, N>::new();
+        let mut ge_tree = SgTree::<K, V, N>::new();
+        lt_tree.alpha = self.alpha;
+        ge_tree.alpha = self.alpha;
+
+        let root_idx = match self.opt_root_idx {
+            Some(root_idx) => root_idx,
+            None => return (lt_tree, ge_tree),
+        };
+
+        let mut lt_idxs = ArrayVec::<usize, N>::new_const();
+        let mut ge_idxs = ArrayVec::<usize, N>::new_const();
+        for idx in self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx) {
+            let (k, v) = self.arena.hard_remove(idx.usize()).into_key_val();
+            match k.borrow() < key {
+                true => lt_idxs.push(lt_tree.arena.add(k, v)),
+                false => ge_idxs.push(ge_tree.arena.add(k, v)),
+            }
+        }
+
+        lt_tree.link_sorted_idxs(&lt_idxs);
+        ge_tree.link_sorted_idxs(&ge_idxs);
+        (lt_tree, ge_tree)
+    }
+
+    /// Clones the entries within `range` into a new, balanced tree with the same alpha, leaving `self` unchanged.
+    pub fn subrange<T, R>(&self, range: &R) -> Result<Self, SgError>
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Clone,
+        V: Clone,
+        R: RangeBounds<T>,
+    {
+        Self::assert_valid_range(range);
+
+        let mut sub =
+            Self::try_from_sorted_iter(self.range_search(range).into_iter().map(|idx| {
+                let node = &self.arena[idx];
+                (node.key().clone(), node.val().clone())
+            }))?;
+
+        sub.alpha = self.alpha;
+        Ok(sub)
+    }
+
+    /// Consumes the tree, converting each value (in key order) while keeping the existing tree structure.
+    pub fn map_values<W, F: FnMut(V) -> W>(self, mut f: F) -> SgTree<K, W, N> {
+        self.map_entries(|_, val| f(val))
+    }
+
+    /// Consumes the tree, converting each value given its key (in key order) while keeping the existing tree structure.
+    pub fn map_entries<W, F: FnMut(&K, V) -> W>(self, f: F) -> SgTree<K, W, N> {
+        let sorted_idxs = self.in_order_idxs();
+        SgTree {
+            arena: self.arena.map_vals(&sorted_idxs, f),
+            opt_root_idx: self.opt_root_idx,
+            max_idx: self.max_idx,
+            min_idx: self.min_idx,
+            curr_size: self.curr_size,
+            alpha: self.alpha,
+            max_size: self.max_size,
+            rebal_cnt: self.rebal_cnt,
+
+            #[cfg(feature = "access_tracking")]
+            access_tick: self.access_tick,
+        }
+    }
+
+    /// Keeps only the `k` smallest entries, removing the rest from the back and rebuilding once.
+    /// No-op if `k >= len`.
+    pub fn truncate_first(&mut self, k: usize) {
+        self.priv_truncate(k, true);
+    }
+
+    /// Keeps only the `k` largest entries, removing the rest from the front and rebuilding once.
+    /// No-op if `k >= len`.
+    pub fn truncate_last(&mut self, k: usize) {
+        self.priv_truncate(k, false);
+    }
+
+    /// Merge a slice of strictly ascending entries into the tree in linear time, rebuilding once.
+    /// Entries in the slice replace existing ones with equal keys.
+    /// Returns `Err`, leaving the tree unmodified, if the slice is unsorted (or has duplicates) or the merge won't fit.
+    pub fn try_extend_sorted(&mut self, sorted: &[(K, V)]) -> Result<(), SgError>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        if sorted.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err(SgError::KeysNotSorted);
+        }
+
+        // Preemptive - count keys in common via a merge, before mutating anything
+        let mut sorted_iter = sorted.iter().peekable();
+        let mut common_cnt = 0;
+        for (k, _) in self.iter() {
+            while sorted_iter.next_if(|(sorted_k, _)| sorted_k < k).is_some() {}
+            if sorted_iter.next_if(|(sorted_k, _)| sorted_k == k).is_some() {
+                common_cnt += 1;
+            }
+        }
+
+        if (self.len() + sorted.len() - common_cnt) > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        let alpha = self.alpha;
+        let rebal_cnt = self.rebal_cnt;
+        let mut existing = mem::take(self).into_iter().peekable();
+        let mut sorted_iter = sorted.iter().cloned().peekable();
+        let mut builder = SortedBuilder::<K, V, N>::new();
+
+        loop {
+            let next = match (existing.peek(), sorted_iter.peek()) {
+                (Some((existing_k, _)), Some((sorted_k, _))) => match existing_k.cmp(sorted_k) {
+                    Ordering::Less => existing.next(),
+                    Ordering::Equal => {
+                        existing.next();
+                        sorted_iter.next()
+                    }
+                    Ordering::Greater => sorted_iter.next(),
+                },
+                (Some(_), None) => existing.next(),
+                (None, _) => sorted_iter.next(),
+            };
+
+            match next {
+                Some((k, v)) => builder.try_push(k, v)?,
+                None => break,
+            }
+        }
+
+        *self = builder.build();
+        self.alpha = alpha;
+        self.rebal_cnt = rebal_cnt;
+        Ok(())
+    }
+
+    /// Build a balanced tree from an iterator of strictly ascending keys, without per-element traversal.
+    /// Returns `Err` if the iterator yields more than `N` elements.
+    pub(crate) fn try_from_sorted_iter<I: IntoIterator<Item = (K, V)>>(
+        iter: I,
+    ) -> Result<Self, SgError> {
+        let mut builder = SortedBuilder::new();
+
+        for (k, v) in iter {
+            debug_assert!(
+                builder.last_key().is_none_or(|last| *last < k),
+                "Keys must be strictly ascending!"
+            );
+            builder.try_push(k, v)?;
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Build a balanced tree from parallel key/value slices, keys strictly ascending, cloning each entry.
+    /// Input is validated up front, so no clones are made if it's rejected.
+    pub(crate) fn try_from_sorted_slices(keys: &[K], vals: &[V]) -> Result<Self, SgError>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        if keys.len() != vals.len() {
+            return Err(SgError::SliceLengthMismatch);
+        }
+
+        if keys.len() > N {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        if keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(SgError::KeysNotSorted);
+        }
+
+        Self::try_from_sorted_iter(keys.iter().cloned().zip(vals.iter().cloned()))
+    }
+
     /// Gets an iterator over the entries of the tree, sorted by key.
     #[inline]
     pub fn iter(&self) -> Iter<'_, K, V, N> {
@@ -277,6 +740,18 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         self.remove_entry(key).map(|(_, v)| v)
     }
 
+    /// Removes a key from the tree, like [`remove`][SgTree::remove].
+    /// Also returns `true` if the removal triggered a full rebuild.
+    pub fn remove_tracked<Q>(&mut self, key: &Q) -> (Option<V>, bool)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let rebal_cnt = self.rebal_cnt;
+        let opt_val = self.remove(key);
+        (opt_val, self.rebal_cnt != rebal_cnt)
+    }
+
     /// Retains only the elements specified by the predicate.
     #[inline]
     pub fn retain<F>(&mut self, mut f: F)
@@ -287,6 +762,105 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         self.priv_drain_filter(|k, v| !f(k, v));
     }
 
+    /// Retains only the elements specified by the predicate, returning the number of elements removed.
+    pub fn retain_count<F>(&mut self, mut f: F) -> usize
+    where
+        F: FnMut(&K, &V) -> bool,
+        K: Ord,
+    {
+        self.priv_drain_filter(|k, v| !f(k, v)).len()
+    }
+
+    /// Removes every entry whose key is yielded by `keys`, returning the number removed.
+    /// Survivors are found via a single merge against the sorted tree, then relinked with one rebuild.
+    /// Keys arriving out of ascending order fall back to a binary search, so unsorted input is correct but slower.
+    pub fn remove_many<'a, Q, I>(&mut self, keys: I) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized + 'a,
+        I: IntoIterator<Item = &'a Q>,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let sorted_idxs = self.in_order_idxs();
+        let mut doomed: ArrayVec<bool, N> = sorted_idxs.iter().map(|_| false).collect();
+        let mut opt_max_key: Option<&Q> = None;
+        let mut cursor = 0;
+
+        for key in keys {
+            let sorted_pos = match opt_max_key {
+                // Slow path: out-of-order input, search the whole sorted sequence
+                Some(max_key) if key < max_key => sorted_idxs
+                    .binary_search_by(|idx| self.arena[*idx].key().borrow().cmp(key))
+                    .ok(),
+                // Fast path: ascending input, advance the merge cursor
+                _ => {
+                    opt_max_key = Some(key);
+                    while cursor < sorted_idxs.len()
+                        && self.arena[sorted_idxs[cursor]].key().borrow() < key
+                    {
+                        cursor += 1;
+                    }
+                    (cursor < sorted_idxs.len()
+                        && self.arena[sorted_idxs[cursor]].key().borrow() == key)
+                        .then_some(cursor)
+                }
+            };
+
+            if let Some(pos) = sorted_pos {
+                doomed[pos] = true;
+            }
+        }
+
+        let remove_cnt = doomed.iter().filter(|d| **d).count();
+        if remove_cnt == 0 {
+            return 0;
+        } else if remove_cnt == self.len() {
+            self.clear();
+            return remove_cnt;
+        }
+
+        let mut survivor_idxs = ArrayVec::<usize, N>::new_const();
+        for (idx, is_doomed) in sorted_idxs.into_iter().zip(doomed) {
+            match is_doomed {
+                true => drop(self.arena.hard_remove(idx)),
+                false => survivor_idxs.push(idx),
+            }
+        }
+
+        self.opt_root_idx = None;
+        self.curr_size = 0;
+        self.link_sorted_idxs(&survivor_idxs);
+        self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
+
+        remove_cnt
+    }
+
+    /// Calls `f` on every entry, in the order given by `cmp` over values (ties broken by ascending key).
+    pub fn for_each_value_by<C, F>(&mut self, mut cmp: C, mut f: F)
+    where
+        K: Ord,
+        C: FnMut(&V, &V) -> Ordering,
+        F: FnMut(&K, &mut V),
+    {
+        let mut idxs: ArrayVec<usize, N> = (0..self.arena.len())
+            .filter(|idx| self.arena.is_occupied(*idx))
+            .collect();
+
+        idxs.sort_unstable_by(|a_idx, b_idx| {
+            let (a, b) = (&self.arena[*a_idx], &self.arena[*b_idx]);
+            cmp(a.val(), b.val()).then_with(|| a.key().cmp(b.key()))
+        });
+
+        // Dispatch sequentially, so only one mutable value reference is live at a time
+        for idx in idxs {
+            let (k, v) = self.arena[idx].get_mut();
+            f(k, v);
+        }
+    }
+
     /// Splits the collection into two at the given key. Returns everything after the given key, including the key.
     #[inline]
     pub fn split_off<Q>(&mut self, key: &Q) -> Self
@@ -336,6 +910,33 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
     /// but the ordering on the borrowed form must match the ordering on the key type.
     #[inline]
     pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value_mut(key).map(|(_, v)| v)
+    }
+
+    /// Get the stored key and a mutable reference to its value, in a single lookup.
+    ///
+    /// The key may be any borrowed form of the map’s key type,
+    /// but the ordering on the borrowed form must match the ordering on the key type.
+    #[inline]
+    pub fn get_key_value_mut<Q>(&mut self, key: &Q) -> Option<(&K, &mut V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
+        match ngh.node_idx() {
+            Some(idx) => Some(self.arena[idx].get_mut()),
+            None => None,
+        }
+    }
+
+    /// Get reference to the value corresponding to the key, marking the entry as most recently used.
+    #[cfg(feature = "access_tracking")]
+    pub fn get_touch<Q>(&mut self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
@@ -343,13 +944,84 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
         match ngh.node_idx() {
             Some(idx) => {
-                let (_, val) = self.arena[idx].get_mut();
-                Some(val)
+                self.touch(idx);
+                Some(self.arena[idx].val())
             }
             None => None,
         }
     }
 
+    /// Removes and returns the least recently used entry.
+    /// Ties (e.g. entries never inserted or touched individually) go to the smallest key.
+    #[cfg(feature = "access_tracking")]
+    pub fn evict_lru(&mut self) -> Option<(K, V)> {
+        let lru_idx = self
+            .arena
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, opt_node)| opt_node.as_ref().map(|node| (idx, node)))
+            .min_by(|(_, a), (_, b)| {
+                a.last_access()
+                    .cmp(&b.last_access())
+                    .then_with(|| a.key().cmp(b.key()))
+            })
+            .map(|(idx, _)| idx)?;
+
+        self.priv_remove_by_idx(lru_idx)
+    }
+
+    /// Swap the values stored at keys `a` and `b`, leaving keys (and tree shape) untouched.
+    /// Returns `false`, changing nothing, unless both keys are present.
+    pub fn swap_values<Q>(&mut self, a: &Q, b: &Q) -> bool
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let a_ngh: NodeGetHelper<Idx> = self.internal_get(None, a);
+        let b_ngh: NodeGetHelper<Idx> = self.internal_get(None, b);
+        match (a_ngh.node_idx(), b_ngh.node_idx()) {
+            (Some(a_idx), Some(b_idx)) => {
+                self.arena.swap_vals(a_idx, b_idx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Apply `f` to a copy of the key matching `key` and, if the result still sorts strictly between the entry's
+    /// neighbors, store it in the same node (no removal, re-insertion, or rebalance).
+    /// Returns `Ok(false)` if `key` isn't present, or `Err(SgError::OrderViolation)`, leaving the tree unchanged,
+    /// if the result would break ordering.
+    pub fn update_key_in_place<Q, F>(&mut self, key: &Q, f: F) -> Result<bool, SgError>
+    where
+        K: Borrow<Q> + Ord + Clone,
+        Q: Ord + ?Sized,
+        F: FnOnce(&mut K),
+    {
+        let ngh: NodeGetHelper<Idx> = self.internal_get(None, key);
+        let idx = match ngh.node_idx() {
+            Some(idx) => idx,
+            None => return Ok(false),
+        };
+
+        let mut new_key = self.arena[idx].key().clone();
+        f(&mut new_key);
+
+        let after_prev = self.prev_entry(key).is_none_or(|(prev, _)| *prev < new_key);
+        let before_next = self.next_entry(key).is_none_or(|(next, _)| new_key < *next);
+        if !(after_prev && before_next) {
+            return Err(SgError::OrderViolation);
+        }
+
+        self.arena[idx].set_key(new_key);
+        Ok(true)
+    }
+
+    /// Initializes all of the arena's `N` slots up front, so no later insert is the first to touch a slot.
+    pub fn prewarm(&mut self) {
+        self.arena.prewarm();
+    }
+
     /// Clears the tree, removing all elements.
     #[inline]
     pub fn clear(&mut self) {
@@ -386,6 +1058,185 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         self.len() == self.capacity()
     }
 
+    /// Returns the largest entry with a key `<= key` and the smallest entry with a key `> key`, in a single descent.
+    #[allow(clippy::type_complexity)]
+    pub fn bracket<Q>(&self, key: &Q) -> (Option<(&K, &V)>, Option<(&K, &V)>)
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut opt_lower_idx = None;
+        let mut opt_upper_idx = None;
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match node.key().borrow() <= key {
+                true => {
+                    opt_lower_idx = Some(curr_idx);
+                    opt_curr_idx = node.right_idx();
+                }
+                false => {
+                    opt_upper_idx = Some(curr_idx);
+                    opt_curr_idx = node.left_idx();
+                }
+            }
+        }
+
+        let entry = |idx: usize| {
+            let node = &self.arena[idx];
+            (node.key(), node.val())
+        };
+
+        (opt_lower_idx.map(entry), opt_upper_idx.map(entry))
+    }
+
+    /// Returns the entry with the largest key `< key`, in a single descent. `key` needn't be present.
+    pub fn prev_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.strict_neighbor(key, Ordering::Less)
+    }
+
+    /// Returns the entry with the smallest key `> key`, in a single descent. `key` needn't be present.
+    pub fn next_entry<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.strict_neighbor(key, Ordering::Greater)
+    }
+
+    // Descend toward `key`, tracking the closest node whose key compares to `key` as `side`
+    fn strict_neighbor<Q>(&self, key: &Q, side: Ordering) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.strict_neighbor_idx(key, side).map(|idx| {
+            let node = &self.arena[idx];
+            (node.key(), node.val())
+        })
+    }
+
+    // Arena index of the closest node whose key compares to `key` as `side`, via a single descent
+    pub(crate) fn strict_neighbor_idx<Q>(&self, key: &Q, side: Ordering) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut opt_best_idx = None;
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            let order = node.key().borrow().cmp(key);
+
+            if order == side {
+                opt_best_idx = Some(curr_idx);
+            }
+
+            opt_curr_idx = match order {
+                Ordering::Less => node.right_idx(),
+                Ordering::Greater => node.left_idx(),
+                Ordering::Equal => match side {
+                    Ordering::Less => node.left_idx(),
+                    _ => node.right_idx(),
+                },
+            };
+        }
+
+        opt_best_idx
+    }
+
+    /// Returns an iterator over up to `k` entries nearest to `key` by `dist`, in ascending distance order.
+    /// On a distance tie, the smaller key is yielded first.
+    /// Two in-order cursors, seeded by a single descent toward `key`, walk outward from it, for `O(log n + k)` time overall.
+    pub fn k_nearest_by<Q, D, F>(
+        &self,
+        key: &Q,
+        k: usize,
+        dist: F,
+    ) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        D: Ord,
+        F: Fn(&K, &Q) -> D,
+    {
+        // Ancestors above `key` resume a forward in-order walk, those at or below it a reverse one.
+        // The tops of the stacks are the entries bracketing `key`.
+        let mut fwd_idx_stack = ArrayVec::<usize, N>::new_const();
+        let mut rev_idx_stack = ArrayVec::<usize, N>::new_const();
+        let mut opt_curr_idx = if k > 0 { self.opt_root_idx } else { None };
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match node.key().borrow() <= key {
+                true => {
+                    rev_idx_stack.push(curr_idx);
+                    opt_curr_idx = node.right_idx();
+                }
+                false => {
+                    fwd_idx_stack.push(curr_idx);
+                    opt_curr_idx = node.left_idx();
+                }
+            }
+        }
+
+        let mut remaining = k;
+        core::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+
+            let take_upper = match (rev_idx_stack.last(), fwd_idx_stack.last()) {
+                (Some(&lower_idx), Some(&upper_idx)) => {
+                    dist(self.arena[upper_idx].key(), key) < dist(self.arena[lower_idx].key(), key)
+                }
+                (opt_lower_idx, opt_upper_idx) => {
+                    opt_lower_idx.is_none() && opt_upper_idx.is_some()
+                }
+            };
+
+            let idx = match take_upper {
+                true => {
+                    let idx = fwd_idx_stack.pop()?;
+                    self.push_spine(&mut fwd_idx_stack, self.arena[idx].right_idx(), true);
+                    idx
+                }
+                false => {
+                    let idx = rev_idx_stack.pop()?;
+                    self.push_spine(&mut rev_idx_stack, self.arena[idx].left_idx(), false);
+                    idx
+                }
+            };
+
+            remaining -= 1;
+            let node = &self.arena[idx];
+            Some((node.key(), node.val()))
+        })
+    }
+
+    // Push the path from `opt_idx` to its subtree's minimum (or maximum, if `!to_min`) onto `idx_stack`.
+    fn push_spine(
+        &self,
+        idx_stack: &mut ArrayVec<usize, N>,
+        mut opt_idx: Option<usize>,
+        to_min: bool,
+    ) {
+        while let Some(idx) = opt_idx {
+            idx_stack.push(idx);
+            let node = &self.arena[idx];
+            opt_idx = match to_min {
+                true => node.left_idx(),
+                false => node.right_idx(),
+            };
+        }
+    }
+
     /// Returns a reference to the first key-value pair in the tree.
     /// The key in this pair is the minimum key in the tree.
     #[inline]
@@ -401,57 +1252,279 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         }
     }
 
-    /// Returns a reference to the first/minium key in the tree, if any.
-    #[inline]
-    pub fn first_key(&self) -> Option<&K>
-    where
-        K: Ord,
-    {
-        self.first_key_value().map(|(k, _)| k)
-    }
+    /// Returns a reference to the first/minium key in the tree, if any.
+    #[inline]
+    pub fn first_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.first_key_value().map(|(k, _)| k)
+    }
+
+    /// Removes and returns the first element in the tree.
+    /// The key of this element is the minimum key that was in the tree.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.priv_remove_by_idx(self.min_idx)
+    }
+
+    /// Removes and returns the first element in the tree, only if `pred` holds for it.
+    /// Otherwise the tree is left unchanged.
+    #[inline]
+    pub fn pop_first_if<F>(&mut self, pred: F) -> Option<(K, V)>
+    where
+        K: Ord,
+        F: FnOnce(&K, &V) -> bool,
+    {
+        match self.first_key_value() {
+            Some((k, v)) if pred(k, v) => self.priv_remove_by_idx(self.min_idx),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the last key-value pair in the tree.
+    /// The key in this pair is the maximum key in the tree.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            let node = &self.arena[self.max_idx];
+            Some((node.key(), node.val()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the last/maximum key in the tree, if any.
+    #[inline]
+    pub fn last_key(&self) -> Option<&K>
+    where
+        K: Ord,
+    {
+        self.last_key_value().map(|(k, _)| k)
+    }
+
+    /// Removes and returns the last element in the tree.
+    /// The key of this element is the maximum key that was in the tree.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        self.priv_remove_by_idx(self.max_idx)
+    }
+
+    /// Removes and returns the last element in the tree, only if `pred` holds for it.
+    /// Otherwise the tree is left unchanged.
+    #[inline]
+    pub fn pop_last_if<F>(&mut self, pred: F) -> Option<(K, V)>
+    where
+        K: Ord,
+        F: FnOnce(&K, &V) -> bool,
+    {
+        match self.last_key_value() {
+            Some((k, v)) if pred(k, v) => self.priv_remove_by_idx(self.max_idx),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over up to `k` of the smallest entries in the tree, in ascending order.
+    pub fn first_n(&self, k: usize) -> Take<Iter<'_, K, V, N>>
+    where
+        K: Ord,
+    {
+        self.iter().take(k)
+    }
+
+    /// Returns an iterator over up to `k` of the largest entries in the tree, in ascending order.
+    /// Uses reverse in-order traversal, so only the returned nodes and their right spines are visited.
+    pub fn last_n(&self, k: usize) -> impl Iterator<Item = (&K, &V)> {
+        let mut idxs = ArrayVec::<usize, N>::new();
+        let mut idx_stack = ArrayVec::<usize, N>::new();
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while idxs.len() < k {
+            while let Some(curr_idx) = opt_curr_idx {
+                idx_stack.push(curr_idx);
+                opt_curr_idx = self.arena[curr_idx].right_idx();
+            }
+
+            match idx_stack.pop() {
+                Some(pop_idx) => {
+                    idxs.push(pop_idx);
+                    opt_curr_idx = self.arena[pop_idx].left_idx();
+                }
+                None => break,
+            }
+        }
+
+        idxs.into_iter().rev().map(move |idx| {
+            let node = &self.arena[idx];
+            (node.key(), node.val())
+        })
+    }
+
+    /// Returns a reference to the entry at sorted position `index` (0-based), if any.
+    /// Runs in `O(log n)` time with feature `fast_rebalance` (descends by cached subtree sizes),
+    /// `O(log n + index)` otherwise (in-order walk).
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        let node = &self.arena[self.nth_idx(index)?];
+        Some((node.key(), node.val()))
+    }
+
+    /// Returns a reference to the key and a mutable reference to the value at sorted position `index` (0-based), if any.
+    /// Runs in `O(log n)` time with feature `fast_rebalance` (descends by cached subtree sizes),
+    /// `O(log n + index)` otherwise (in-order walk).
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        let idx = self.nth_idx(index)?;
+        Some(self.arena[idx].get_mut())
+    }
+
+    /// Returns the number of entries within `range` and, if any, the arena indices of its first and last entries.
+    /// Panics on an invalid range like [`range`][Self::range] does.
+    /// The first and last entries are found by bound descents. With feature `fast_rebalance`, the count is the
+    /// difference of their ranks, for `O(log n)` time overall. Otherwise it's counted like [`range_len`][Self::range_len],
+    /// for `O(log n + len)` time.
+    pub fn range_bounds_resolved<T, R>(&self, range: &R) -> (usize, Option<(usize, usize)>)
+    where
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+    {
+        Self::assert_valid_range(range);
+
+        let below_start = |key: &T| match range.start_bound() {
+            Included(start) => key < start,
+            Excluded(start) => key <= start,
+            Unbounded => false,
+        };
+
+        let above_end = |key: &T| match range.end_bound() {
+            Included(end) => key > end,
+            Excluded(end) => key >= end,
+            Unbounded => false,
+        };
+
+        // Smallest key not below the start, and largest key not above the end
+        let mut opt_first_idx = None;
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match below_start(node.key().borrow()) {
+                true => opt_curr_idx = node.right_idx(),
+                false => {
+                    opt_first_idx = Some(curr_idx);
+                    opt_curr_idx = node.left_idx();
+                }
+            }
+        }
+
+        let mut opt_last_idx = None;
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match above_end(node.key().borrow()) {
+                true => opt_curr_idx = node.left_idx(),
+                false => {
+                    opt_last_idx = Some(curr_idx);
+                    opt_curr_idx = node.right_idx();
+                }
+            }
+        }
+
+        let (first_idx, last_idx) = match (opt_first_idx, opt_last_idx) {
+            (Some(first_idx), Some(last_idx))
+                if self.arena[first_idx].key() <= self.arena[last_idx].key() =>
+            {
+                (first_idx, last_idx)
+            }
+            _ => return (0, None),
+        };
+
+        #[cfg(feature = "fast_rebalance")]
+        let cnt = {
+            let rank_of = |idx: usize| {
+                self.rank::<K>(self.arena[idx].key())
+                    .expect("Internal invariant failed: bound node must be present")
+            };
+            rank_of(last_idx) - rank_of(first_idx) + 1
+        };
 
-    /// Removes and returns the first element in the tree.
-    /// The key of this element is the minimum key that was in the tree.
-    #[inline]
-    pub fn pop_first(&mut self) -> Option<(K, V)>
-    where
-        K: Ord,
-    {
-        self.priv_remove_by_idx(self.min_idx)
+        #[cfg(not(feature = "fast_rebalance"))]
+        let cnt = self.range_len(range);
+
+        (cnt, Some((first_idx, last_idx)))
     }
 
-    /// Returns a reference to the last key-value pair in the tree.
-    /// The key in this pair is the maximum key in the tree.
-    #[inline]
-    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    /// Returns the number of entries within `range`, panicking on an invalid range like [`range`][Self::range] does.
+    /// Subtrees entirely outside the range are pruned, and the traversal stack lives on the stack (no allocation).
+    pub fn range_len<T, R>(&self, range: &R) -> usize
     where
-        K: Ord,
+        T: Ord + ?Sized,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
     {
-        if !self.is_empty() {
-            let node = &self.arena[self.max_idx];
-            Some((node.key(), node.val()))
-        } else {
-            None
+        Self::assert_valid_range(range);
+
+        let below_start = |key: &T| match range.start_bound() {
+            Included(start) => key < start,
+            Excluded(start) => key <= start,
+            Unbounded => false,
+        };
+
+        let above_end = |key: &T| match range.end_bound() {
+            Included(end) => key > end,
+            Excluded(end) => key >= end,
+            Unbounded => false,
+        };
+
+        let mut cnt = 0;
+        let mut idx_stack = ArrayVec::<usize, N>::new();
+        idx_stack.extend(self.opt_root_idx);
+
+        while let Some(idx) = idx_stack.pop() {
+            let node = &self.arena[idx];
+            let key = node.key().borrow();
+
+            // Children on the out-of-range side of this node are out of range too
+            let (too_low, too_high) = (below_start(key), above_end(key));
+            if !too_low {
+                idx_stack.extend(node.left_idx());
+            }
+            if !too_high {
+                idx_stack.extend(node.right_idx());
+            }
+            if !too_low && !too_high {
+                cnt += 1;
+            }
         }
+
+        cnt
     }
 
-    /// Returns a reference to the last/maximum key in the tree, if any.
-    #[inline]
-    pub fn last_key(&self) -> Option<&K>
+    /// Returns the sorted position (0-based) of `key`, if present.
+    /// Runs in `O(log n)` time with feature `fast_rebalance` (sums cached subtree sizes),
+    /// `O(log n + position)` otherwise (counts smaller subtrees once `key` is found).
+    pub fn position<Q>(&self, key: &Q) -> Option<usize>
     where
-        K: Ord,
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
     {
-        self.last_key_value().map(|(k, _)| k)
+        self.rank(key)
     }
 
-    /// Removes and returns the last element in the tree.
-    /// The key of this element is the maximum key that was in the tree.
-    #[inline]
-    pub fn pop_last(&mut self) -> Option<(K, V)>
+    /// Returns the sorted position (0-based) of the first entry for which `pred` returns `true`, if any.
+    /// Runs in `O(n)` time.
+    pub fn find_index<F>(&self, mut pred: F) -> Option<usize>
     where
-        K: Ord,
+        F: FnMut(&K, &V) -> bool,
     {
-        self.priv_remove_by_idx(self.max_idx)
+        self.iter().position(|(k, v)| pred(k, v))
     }
 
     /// Returns the number of elements in the tree.
@@ -467,6 +1540,71 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         self.rebal_cnt
     }
 
+    /// Returns the height of the tree, counted in nodes along the longest root-to-leaf path (`0` if empty).
+    pub fn height(&self) -> usize {
+        let mut max_height = 0;
+        let mut idx_stack = ArrayVec::<(usize, usize), N>::new();
+
+        if let Some(root_idx) = self.opt_root_idx {
+            idx_stack.push((root_idx, 1));
+        }
+
+        while let Some((idx, height)) = idx_stack.pop() {
+            max_height = max_height.max(height);
+            let node = &self.arena[idx];
+            for child_idx in [node.left_idx(), node.right_idx()].into_iter().flatten() {
+                idx_stack.push((child_idx, height + 1));
+            }
+        }
+
+        max_height
+    }
+
+    /// Rebuilds the entire tree into a perfectly balanced shape, in `O(n)` time.
+    /// Uses the same subtree rebuild as internal balancing, applied at the root.
+    pub fn optimize(&mut self) {
+        if let Some(root_idx) = self.opt_root_idx {
+            self.rebuild::<Idx>(root_idx);
+            self.max_size = self.curr_size;
+        }
+    }
+
+    /// Feeds length and entries, in sorted order, into the provided hasher.
+    /// Depends only on logical contents, not on tree shape or arena layout.
+    pub fn hash_with<H: Hasher>(&self, hasher: &mut H)
+    where
+        K: Ord + Hash,
+        V: Hash,
+    {
+        hasher.write_usize(self.len());
+        for entry in self {
+            entry.hash(hasher);
+        }
+    }
+
+    /// Hashes length and entries, in sorted order, with fixed-seed 64-bit FNV-1a.
+    /// Depends only on logical contents, not on tree shape or arena layout.
+    pub fn content_hash(&self) -> u64
+    where
+        K: Ord + Hash,
+        V: Hash,
+    {
+        let mut hasher = Fnv1a::new();
+        self.hash_with(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns a snapshot of arena occupancy, tree height, and rebuild count.
+    pub fn debug_stats(&self) -> ArenaStats {
+        ArenaStats {
+            live: self.len(),
+            free: self.arena.free_len(),
+            capacity: self.capacity(),
+            height: self.height(),
+            rebuilds: self.rebal_cnt(),
+        }
+    }
+
     // Crate-internal API ----------------------------------------------------------------------------------------------
 
     // Remove a node by index.
@@ -544,6 +1682,146 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         subtree_flattened
     }
 
+    // Arena indexes of all nodes in sorted order, via in-order traversal (no sort required).
+    pub(crate) fn in_order_idxs(&self) -> ArrayVec<usize, N> {
+        let mut sorted_idxs = ArrayVec::<usize, N>::new();
+        let mut idx_stack = ArrayVec::<usize, N>::new();
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        loop {
+            while let Some(curr_idx) = opt_curr_idx {
+                idx_stack.push(curr_idx);
+                opt_curr_idx = self.arena[curr_idx].left_idx();
+            }
+
+            match idx_stack.pop() {
+                Some(pop_idx) => {
+                    sorted_idxs.push(pop_idx);
+                    opt_curr_idx = self.arena[pop_idx].right_idx();
+                }
+                None => return sorted_idxs,
+            }
+        }
+    }
+
+    // Sorted position of `key`, counting the subtrees left of the search path once it's found.
+    #[cfg(not(feature = "fast_rebalance"))]
+    fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        // Roots of subtrees holding smaller keys, only counted once `key` is found
+        let mut lesser_idxs = ArrayVec::<usize, N>::new();
+        let mut lesser_cnt = 0;
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            match key.cmp(node.key().borrow()) {
+                Ordering::Less => opt_curr_idx = node.left_idx(),
+                Ordering::Greater => {
+                    lesser_idxs.extend(node.left_idx());
+                    lesser_cnt += 1;
+                    opt_curr_idx = node.right_idx();
+                }
+                Ordering::Equal => {
+                    lesser_idxs.extend(node.left_idx());
+                    while let Some(idx) = lesser_idxs.pop() {
+                        let node = &self.arena[idx];
+                        lesser_idxs.extend(node.left_idx());
+                        lesser_idxs.extend(node.right_idx());
+                        lesser_cnt += 1;
+                    }
+                    return Some(lesser_cnt);
+                }
+            }
+        }
+
+        None
+    }
+
+    // Sorted position of `key`, summing cached sizes of the subtrees left of the search path.
+    #[cfg(feature = "fast_rebalance")]
+    fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut lesser_cnt = 0;
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            let left_size = node
+                .left_idx()
+                .map_or(0, |idx| self.arena[idx].subtree_size());
+            match key.cmp(node.key().borrow()) {
+                Ordering::Less => opt_curr_idx = node.left_idx(),
+                Ordering::Greater => {
+                    lesser_cnt += left_size + 1;
+                    opt_curr_idx = node.right_idx();
+                }
+                Ordering::Equal => return Some(lesser_cnt + left_size),
+            }
+        }
+
+        None
+    }
+
+    // Arena index of the node at sorted position `index`, via in-order traversal that stops early.
+    #[cfg(not(feature = "fast_rebalance"))]
+    pub(crate) fn nth_idx(&self, index: usize) -> Option<usize> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut idx_stack = ArrayVec::<usize, N>::new();
+        let mut opt_curr_idx = self.opt_root_idx;
+        let mut visited = 0;
+
+        loop {
+            while let Some(curr_idx) = opt_curr_idx {
+                idx_stack.push(curr_idx);
+                opt_curr_idx = self.arena[curr_idx].left_idx();
+            }
+
+            let pop_idx = idx_stack.pop()?;
+            if visited == index {
+                return Some(pop_idx);
+            }
+
+            visited += 1;
+            opt_curr_idx = self.arena[pop_idx].right_idx();
+        }
+    }
+
+    // Arena index of the node at sorted position `index`, via descent guided by cached subtree sizes.
+    #[cfg(feature = "fast_rebalance")]
+    pub(crate) fn nth_idx(&self, mut index: usize) -> Option<usize> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut opt_curr_idx = self.opt_root_idx;
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            let left_size = node
+                .left_idx()
+                .map_or(0, |idx| self.arena[idx].subtree_size());
+            match index.cmp(&left_size) {
+                Ordering::Less => opt_curr_idx = node.left_idx(),
+                Ordering::Equal => return Some(curr_idx),
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    opt_curr_idx = node.right_idx();
+                }
+            }
+        }
+
+        None
+    }
+
     /// Sort the internal arena such that logically contiguous nodes are in-order (by key).
     #[inline]
     pub(crate) fn sort_arena(&mut self) {
@@ -614,15 +1892,13 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         match (range.start_bound(), range.end_bound()) {
             (Included(start), Included(end))
             | (Included(start), Excluded(end))
-            | (Excluded(start), Included(end)) => {
-                if start > end {
-                    panic!("range start is greater than range end");
-                }
+            | (Excluded(start), Included(end))
+                if start > end =>
+            {
+                panic!("range start is greater than range end");
             }
-            (Excluded(start), Excluded(end)) => {
-                if start == end {
-                    panic!("range start and end are equal and excluded");
-                }
+            (Excluded(start), Excluded(end)) if start == end => {
+                panic!("range start and end are equal and excluded");
             }
             _ => {}
         }
@@ -663,6 +1939,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                             None => {
                                 if unlikely(opt_path.is_some()) {
                                     // Find failed, clear path
+                                    // SAFETY: `opt_path` was just checked to be `Some`.
                                     unsafe {
                                         opt_path.unwrap_unchecked().clear();
                                     }
@@ -674,6 +1951,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                         Ordering::Equal => {
                             if likely(opt_path.is_some()) {
                                 // Only parents in path
+                                // SAFETY: `opt_path` was just checked to be `Some`.
                                 unsafe {
                                     opt_path.unwrap_unchecked().pop();
                                 }
@@ -695,6 +1973,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                             None => {
                                 if unlikely(opt_path.is_some()) {
                                     // Find failed, clear path
+                                    // SAFETY: `opt_path` was just checked to be `Some`.
                                     unsafe {
                                         opt_path.unwrap_unchecked().clear();
                                     }
@@ -719,44 +1998,121 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         key: K,
         val: V,
     ) -> (Option<V>, usize) {
+        self.internal_balancing_upsert::<U, _>(key, val, |node, key, val| {
+            // Replacing key necessary b/c custom Eq impl may not consider all K's fields
+            node.set_key(key);
+
+            // Replacing val necessary b/c it may be different
+            Some(mem::replace(node.get_mut().1, val))
+        })
+    }
+
+    // Replace the key and value of an existing entry, found in a single descent, without adding a node.
+    // Hands the pair back if the key is absent.
+    pub(crate) fn replace_existing(&mut self, key: K, val: V) -> Result<V, (K, V)> {
+        match self.internal_get::<K, Idx>(None, &key).node_idx() {
+            Some(idx) => {
+                #[cfg(feature = "access_tracking")]
+                self.touch(idx);
+
+                // Replacing key necessary b/c custom Eq impl may not consider all K's fields
+                let node = &mut self.arena[idx];
+                node.set_key(key);
+                Ok(mem::replace(node.get_mut().1, val))
+            }
+            None => Err((key, val)),
+        }
+    }
+
+    // Sorted insert of node into the tree (outer), with a caller-defined action if the key is already present.
+    // `on_equal` receives the existing node and the new key/val, returning whatever should be reported as the old
+    // value. Re-balances the tree if necessary.
+    //
+    // Returns the result of `on_equal`, if called, and the index of the new or existing node in the arena.
+    pub(crate) fn internal_balancing_upsert<U, F>(
+        &mut self,
+        key: K,
+        val: V,
+        on_equal: F,
+    ) -> (Option<V>, usize)
+    where
+        U: Copy + Ord + Sub + SmallUnsigned,
+        F: FnOnce(&mut Node<K, V, Idx>, K, V) -> Option<V>,
+    {
         let mut path: ArrayVec<U, N> = Arena::<K, V, U, N>::new_idx_vec();
-        let (opt_val, ngh) = self.priv_insert(&mut path, key, val);
+        let old_size = self.curr_size;
+        let (opt_val, ngh) = self.priv_insert(&mut path, key, val, on_equal);
+        let node_added = self.curr_size > old_size;
 
         #[cfg(feature = "fast_rebalance")]
         {
-            // Update subtree sizes
-            for parent_idx in &path {
-                let parent_node = &mut self.arena[(*parent_idx).usize()];
-                parent_node.set_subtree_size(parent_node.subtree_size() + 1);
+            // Update subtree sizes, only if a new node was added (an update leaves sizes unchanged)
+            if node_added {
+                for parent_idx in &path {
+                    let parent_node = &mut self.arena[(*parent_idx).usize()];
+                    parent_node.set_subtree_size(parent_node.subtree_size() + 1);
+                }
             }
         }
 
-        // Potential rebalance
-        if path.len() > self.alpha_balance_depth(self.max_size) {
+        // Potential rebalance, only if a new node was added (an update leaves the shape unchanged)
+        if node_added && path.len() > self.alpha_balance_depth(self.max_size) {
             if let Some(scapegoat_idx) = self.find_scapegoat(&path) {
                 self.rebuild::<U>(scapegoat_idx);
             }
         }
 
+        // SAFETY: `priv_insert` always returns the index of the inserted or updated node.
         unsafe { assume(ngh.node_idx().is_some()) };
         let new_node_idx = ngh.node_idx().expect("Inserted node index must be `Some`");
+
+        #[cfg(feature = "access_tracking")]
+        self.touch(new_node_idx);
+
         (opt_val, new_node_idx)
     }
 
     // Private API -----------------------------------------------------------------------------------------------------
 
+    // Stamp a node with the next recency tick.
+    // On clock overflow, existing ticks are first renumbered by rank, preserving recency order.
+    #[cfg(feature = "access_tracking")]
+    fn touch(&mut self, idx: usize) {
+        if unlikely(self.access_tick == u32::MAX) {
+            let mut ranked: ArrayVec<(u32, usize), N> = self
+                .arena
+                .iter()
+                .enumerate()
+                .filter_map(|(i, opt_node)| opt_node.as_ref().map(|node| (node.last_access(), i)))
+                .collect();
+            ranked.sort_unstable();
+
+            for (rank, (_, i)) in ranked.iter().enumerate() {
+                self.arena[*i].set_last_access(rank as u32);
+            }
+            self.access_tick = ranked.len() as u32;
+        }
+
+        self.access_tick += 1;
+        self.arena[idx].set_last_access(self.access_tick);
+    }
+
     // Sorted insert of node into the tree (inner).
     // Maintains a traversal path to avoid nodes needing to maintain a parent index.
-    // Returns a tuple of the old value, if any, and the `NodeGetHelper` of the new node.
+    // Returns a tuple of the `on_equal` result, if any, and the `NodeGetHelper` of the new node.
     //
-    // If a node with the same key existed, overwrites both that nodes key and value with the new one's and
-    // returns the old value.
-    fn priv_insert<U: SmallUnsigned + Copy>(
+    // If a node with the same key existed, hands it to `on_equal` along with the new key and value.
+    fn priv_insert<U, F>(
         &mut self,
         path: &mut ArrayVec<U, N>,
         key: K,
         val: V,
-    ) -> (Option<V>, NodeGetHelper<U>) {
+        on_equal: F,
+    ) -> (Option<V>, NodeGetHelper<U>)
+    where
+        U: SmallUnsigned + Copy,
+        F: FnOnce(&mut Node<K, V, Idx>, K, V) -> Option<V>,
+    {
         match self.opt_root_idx {
             // Sorted insert
             Some(idx) => {
@@ -801,12 +2157,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                             }
                         }
                         Ordering::Equal => {
-                            // Replacing key necessary b/c custom Eq impl may not consider all K's fields
-                            curr_node.set_key(key);
-
-                            // Replacing val necessary b/c it may be different
-                            opt_val = Some(curr_node.take_val());
-                            curr_node.set_val(val);
+                            opt_val = on_equal(curr_node, key, val);
 
                             // Key/val updated "in-place": no need to update `curr_node`'s parent or children
                             ngh = NodeGetHelper::new(Some(curr_idx), None, false);
@@ -943,6 +2294,14 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                             match min_node.left_idx() {
                                 // Continue search for min node
                                 Some(lt_idx) => {
+                                    // Every node passed on the way down loses the min node
+                                    #[cfg(feature = "fast_rebalance")]
+                                    {
+                                        let min_ancestor_node = &mut self.arena[min_idx];
+                                        min_ancestor_node
+                                            .set_subtree_size(min_ancestor_node.subtree_size() - 1);
+                                    }
+
                                     min_parent_idx = min_idx;
                                     min_idx = lt_idx;
                                 }
@@ -955,13 +2314,6 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                                         } else {
                                             let min_parent_node = &mut self.arena[min_parent_idx];
                                             min_parent_node.set_left_idx(unlink_new_child);
-
-                                            #[cfg(feature = "fast_rebalance")]
-                                            {
-                                                min_parent_node.set_subtree_size(
-                                                    min_parent_node.subtree_size() - 1,
-                                                );
-                                            }
                                         }
                                         break;
                                     }
@@ -971,13 +2323,6 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                                         } else {
                                             let min_parent_node = &mut self.arena[min_parent_idx];
                                             min_parent_node.set_left_idx(None);
-
-                                            #[cfg(feature = "fast_rebalance")]
-                                            {
-                                                min_parent_node.set_subtree_size(
-                                                    min_parent_node.subtree_size() - 1,
-                                                );
-                                            }
                                         }
                                         break;
                                     }
@@ -1016,7 +2361,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                 }
 
                 // Perform removal
-                let mut removed_node = self.arena.hard_remove(node_idx);
+                let removed_node = self.arena.hard_remove(node_idx);
                 self.curr_size -= 1;
 
                 // Update min/max
@@ -1039,7 +2384,7 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
                     }
                 }
 
-                Some((removed_node.take_key(), removed_node.take_val()))
+                Some(removed_node.into_key_val())
             }
             None => None,
         }
@@ -1097,6 +2442,171 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         drained_sgt
     }
 
+    // Sort staged scratch arena nodes by key and drop duplicates, keeping the most recently staged.
+    // Leaves `staged` sorted and unique.
+    fn compact_staged(scratch: &mut Arena<K, V, Idx, N>, staged: &mut ArrayVec<Idx, N>) {
+        // Ties broken by staging order, so an unstable sort is OK
+        let mut order = staged
+            .iter()
+            .enumerate()
+            .map(|(pos, idx)| (*idx, Idx::checked_from(pos)))
+            .collect::<ArrayVec<(Idx, Idx), N>>();
+
+        order.sort_unstable_by(|(a_idx, a_pos), (b_idx, b_pos)| {
+            scratch[a_idx.usize()]
+                .key()
+                .cmp(scratch[b_idx.usize()].key())
+                .then(a_pos.cmp(b_pos))
+        });
+
+        staged.clear();
+        for (i, (idx, _)) in order.iter().enumerate() {
+            let idx = idx.usize();
+
+            // Superseded by a later duplicate
+            if let Some((next_idx, _)) = order.get(i + 1) {
+                if scratch[idx].key() == scratch[next_idx.usize()].key() {
+                    scratch.remove(idx);
+                    continue;
+                }
+            }
+
+            staged.push(Idx::checked_from(idx));
+        }
+    }
+
+    // Merge sorted, unique scratch arena nodes into the tree, then rebuild it balanced.
+    // Returns the number of new keys, or `Err` (tree unmodified) if they don't fit.
+    //
+    // All key comparisons happen while planning, before the tree is modified: committing only moves nodes.
+    // Replaced pairs are swapped into the scratch arena, so they're dropped after the tree is consistent again.
+    fn merge_staged(
+        &mut self,
+        mut scratch: Arena<K, V, Idx, N>,
+        staged: &[Idx],
+    ) -> Result<usize, SgError> {
+        if staged.is_empty() {
+            return Ok(0);
+        }
+
+        let existing = match self.opt_root_idx {
+            Some(root_idx) => self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx),
+            None => Arena::<K, V, Idx, N>::new_idx_vec(),
+        };
+
+        // Plan: per staged node, the tree node it replaces or, if new, its rank among existing keys
+        let mut opt_replaced = ArrayVec::<Option<usize>, N>::new_const();
+        let mut new_ranks = ArrayVec::<usize, N>::new_const();
+        let mut rank = 0;
+
+        for staged_idx in staged {
+            let key = scratch[staged_idx.usize()].key();
+            while rank < existing.len() && self.arena[existing[rank].usize()].key() < key {
+                rank += 1;
+            }
+
+            match existing.get(rank) {
+                Some(e) if self.arena[e.usize()].key() == key => opt_replaced.push(Some(e.usize())),
+                _ => {
+                    opt_replaced.push(None);
+                    new_ranks.push(rank);
+                }
+            }
+        }
+
+        let new_cnt = new_ranks.len();
+        if self.len() + new_cnt > self.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        // Commit
+        let mut merged = ArrayVec::<usize, N>::new_const();
+        let mut new_ranks = new_ranks.into_iter();
+        let mut next_existing = 0;
+
+        for (staged_idx, opt_tree_idx) in staged.iter().zip(opt_replaced) {
+            let staged_idx = staged_idx.usize();
+            match opt_tree_idx {
+                Some(tree_idx) => self.arena[tree_idx].swap_key_val(&mut scratch[staged_idx]),
+                None => {
+                    let rank = new_ranks.next().expect("Planned rank for each new key");
+                    merged.extend(existing[next_existing..rank].iter().map(|e| e.usize()));
+                    next_existing = rank;
+
+                    let (k, v) = scratch.hard_remove(staged_idx).into_key_val();
+                    merged.push(self.arena.add(k, v));
+                }
+            }
+        }
+
+        merged.extend(existing[next_existing..].iter().map(|e| e.usize()));
+
+        // Rebuild whole tree
+        self.opt_root_idx = Some(merged[0]);
+        self.rebalance_subtree_from_sorted_idxs::<Idx>(merged[0], &merged);
+        self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
+
+        self.min_idx = merged[0];
+        self.max_idx = merged[merged.len() - 1];
+        self.curr_size = merged.len();
+        self.max_size = merged.len();
+
+        Ok(new_cnt)
+    }
+
+    // Keep `k` entries from one end of the sorted order, dropping the rest, then relink the survivors balanced.
+    fn priv_truncate(&mut self, k: usize, keep_first: bool) {
+        if k >= self.len() {
+            return;
+        }
+
+        if k == 0 {
+            self.clear();
+            return;
+        }
+
+        let sorted_idxs = self.in_order_idxs();
+        let (kept, dropped) = match keep_first {
+            true => sorted_idxs.split_at(k),
+            false => {
+                let (dropped, kept) = sorted_idxs.split_at(sorted_idxs.len() - k);
+                (kept, dropped)
+            }
+        };
+
+        for &idx in dropped {
+            self.arena.hard_remove(idx);
+        }
+
+        self.opt_root_idx = None;
+        self.link_sorted_idxs(kept);
+    }
+
+    // Link an empty tree's freshly added, key-sorted arena nodes into a balanced tree.
+    fn link_sorted_idxs(&mut self, sorted_idxs: &[usize]) {
+        debug_assert!(self.opt_root_idx.is_none());
+        let (&first_idx, &last_idx) = match (sorted_idxs.first(), sorted_idxs.last()) {
+            (Some(first_idx), Some(last_idx)) => (first_idx, last_idx),
+            _ => return,
+        };
+
+        // A lone node isn't visited by the rebalance, so clear any stale links here
+        if sorted_idxs.len() == 1 {
+            let node = &mut self.arena[first_idx];
+            node.set_left_idx(None);
+            node.set_right_idx(None);
+            #[cfg(feature = "fast_rebalance")]
+            node.set_subtree_size(1);
+        }
+
+        self.opt_root_idx = Some(first_idx);
+        self.rebalance_subtree_from_sorted_idxs::<Idx>(first_idx, sorted_idxs);
+        self.min_idx = first_idx;
+        self.max_idx = last_idx;
+        self.curr_size = sorted_idxs.len();
+        self.max_size = sorted_idxs.len();
+    }
+
     /// Minimum update without recursion
     #[inline]
     fn update_min_idx(&mut self) {
@@ -1153,8 +2663,8 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         let mut parent_subtree_size = self.get_subtree_size::<U>(path[parent_path_idx].usize());
 
         while parent_path_idx > 0 {
-            let node_size_fp = Alpha::from_num(node_subtree_size);
-            let parent_size_fp = Alpha::from_num(parent_subtree_size);
+            let node_size_fp = Alpha::saturating_from_num(node_subtree_size);
+            let parent_size_fp = Alpha::saturating_from_num(parent_subtree_size);
 
             if (node_size_fp / parent_size_fp) > self.alpha {
                 break;
@@ -1231,7 +2741,9 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
     }
 
     // Retrieve cached subtree size
+    // `U` kept so callers share a signature with the non-cached variant
     #[cfg(feature = "fast_rebalance")]
+    #[allow(clippy::extra_unused_type_parameters)]
     #[inline]
     fn get_subtree_size<U: SmallUnsigned>(&self, idx: usize) -> usize {
         self.arena[idx].subtree_size()
@@ -1402,11 +2914,11 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
         if val <= 1 {
             return 0;
         }
-        let val_fp = Alpha::from_num(val);
+        let val_fp = Alpha::saturating_from_num(val);
         let mut low = 0;
-        let mut high = 64;
+        let mut high: usize = 64;
         while low < high {
-            let mid = (low + high + 1) / 2;
+            let mid = (low + high).div_ceil(2);
             let mut r = Alpha::ONE;
             let mut b = self.alpha.recip();
             let mut e = mid;
@@ -1427,6 +2939,66 @@ impl<K: Ord, V, const N: usize> SgTree<K, V, N> {
     }
 }
 
+// Sorted Builder ------------------------------------------------------------------------------------------------------
+
+/// Incrementally builds a balanced tree from entries supplied in strictly ascending key order.
+/// Entries are placed directly in the arena and linked once, by [`build`][SortedBuilder::build], in `O(n)` total.
+/// Ordering is the caller's responsibility: check [`last_key`][SortedBuilder::last_key] before each push.
+pub(crate) struct SortedBuilder<K, V, const N: usize> {
+    sgt: SgTree<K, V, N>,
+    sorted_idxs: ArrayVec<usize, N>,
+}
+
+impl<K: Ord, V, const N: usize> SortedBuilder<K, V, N> {
+    /// Construct empty builder.
+    pub(crate) fn new() -> Self {
+        SortedBuilder {
+            sgt: SgTree::new(),
+            sorted_idxs: ArrayVec::new_const(),
+        }
+    }
+
+    /// Largest key pushed so far, if any.
+    pub(crate) fn last_key(&self) -> Option<&K> {
+        self.sorted_idxs
+            .last()
+            .map(|last_idx| self.sgt.arena[*last_idx].key())
+    }
+
+    /// Append the next-largest entry, returning `Err` if `N` entries were already pushed.
+    pub(crate) fn try_push(&mut self, key: K, val: V) -> Result<(), SgError> {
+        if self.sorted_idxs.is_full() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        self.sorted_idxs.push(self.sgt.arena.add(key, val));
+        Ok(())
+    }
+
+    /// Link all pushed entries into a balanced tree.
+    pub(crate) fn build(mut self) -> SgTree<K, V, N> {
+        self.sgt.link_sorted_idxs(&self.sorted_idxs);
+        self.sgt
+    }
+}
+
+// Vacated Slot Guard --------------------------------------------------------------------------------------------------
+
+// Armed while a node's value is moved out (e.g. during a user-supplied merge), disarmed by `mem::forget`.
+// If dropped during unwinding, unlinks the node, forgetting the stale value bits instead of dropping them.
+struct VacatedSlotGuard<'a, K: Ord, V, const N: usize> {
+    tree: &'a mut SgTree<K, V, N>,
+    idx: usize,
+}
+
+impl<K: Ord, V, const N: usize> Drop for VacatedSlotGuard<'_, K, V, N> {
+    fn drop(&mut self) {
+        if let Some((_, stale_val)) = self.tree.priv_remove_by_idx(self.idx) {
+            mem::forget(stale_val);
+        }
+    }
+}
+
 // Convenience Traits --------------------------------------------------------------------------------------------------
 
 // Debug
@@ -1589,9 +3161,7 @@ where
 {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        for i in self {
-            i.hash(state);
-        }
+        self.hash_with(state);
     }
 }
 
@@ -0,0 +1,111 @@
+#![cfg(feature = "borsh")]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use escapegoat::{SgMap, SgSet};
+
+use borsh::io::ErrorKind;
+use rand::Rng;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+#[test]
+fn test_map_borsh_round_trip() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u64, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    while sgm.len() < DEFAULT_CAPACITY {
+        let (k, v) = (rng.random(), rng.random());
+        sgm.insert(k, v);
+        btm.insert(k, v);
+    }
+
+    let sgm_bytes = borsh::to_vec(&sgm).unwrap();
+    let btm_bytes = borsh::to_vec(&btm).unwrap();
+    assert_eq!(sgm_bytes, btm_bytes);
+
+    let sgm_2: SgMap<u32, u64, DEFAULT_CAPACITY> = borsh::from_slice(&sgm_bytes).unwrap();
+    assert_eq!(sgm_2, sgm);
+    assert!(sgm_2.into_iter().eq(btm.into_iter()));
+
+    // Empty
+    let empty = SgMap::<u32, u64, DEFAULT_CAPACITY>::new();
+    let empty_bytes = borsh::to_vec(&empty).unwrap();
+    assert_eq!(empty_bytes, [0, 0, 0, 0]);
+    assert!(
+        borsh::from_slice::<SgMap<u32, u64, DEFAULT_CAPACITY>>(&empty_bytes)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_set_borsh_round_trip() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<i16, DEFAULT_CAPACITY>::new();
+    let mut bts = BTreeSet::new();
+
+    while sgs.len() < DEFAULT_CAPACITY {
+        let e = rng.random();
+        sgs.insert(e);
+        bts.insert(e);
+    }
+
+    let sgs_bytes = borsh::to_vec(&sgs).unwrap();
+    assert_eq!(sgs_bytes, borsh::to_vec(&bts).unwrap());
+
+    let sgs_2: SgSet<i16, DEFAULT_CAPACITY> = borsh::from_slice(&sgs_bytes).unwrap();
+    assert_eq!(sgs_2, sgs);
+    assert!(sgs_2.into_iter().eq(bts.into_iter()));
+}
+
+#[test]
+fn test_borsh_capacity_exceeded() {
+    let sgm = SgMap::<u8, u8, 4>::from([(1, 1), (2, 2), (3, 3), (4, 4)]);
+    let bytes = borsh::to_vec(&sgm).unwrap();
+
+    let err = borsh::from_slice::<SgMap<u8, u8, 3>>(&bytes).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let sgs = SgSet::<u8, 4>::from([1, 2, 3, 4]);
+    let bytes = borsh::to_vec(&sgs).unwrap();
+
+    let err = borsh::from_slice::<SgSet<u8, 3>>(&bytes).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // Length prefix alone is validated before reading any entries
+    let err = borsh::from_slice::<SgSet<u8, 3>>(&u32::MAX.to_le_bytes()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_borsh_wrong_order() {
+    // Unsorted
+    let unsorted = borsh::to_vec(&vec![(2u8, 0u8), (1u8, 0u8)]).unwrap();
+    let err = borsh::from_slice::<SgMap<u8, u8, 4>>(&unsorted).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    // Duplicate
+    let dup = borsh::to_vec(&vec![1u8, 1u8]).unwrap();
+    let err = borsh::from_slice::<SgSet<u8, 4>>(&dup).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_borsh_builds_balanced() {
+    // Ascending input would trigger repeated rebuilds if inserted entry by entry
+    let sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::from_iter((0..100).map(|i| (i, i)));
+    let sgm_2: SgMap<u32, u32, DEFAULT_CAPACITY> =
+        borsh::from_slice(&borsh::to_vec(&sgm).unwrap()).unwrap();
+    assert_eq!(sgm_2, sgm);
+    assert_eq!(sgm_2.debug_stats().rebuilds, 0);
+    assert_eq!(sgm_2.debug_stats().height, 7); // Minimum for 100 elements
+
+    let sgs = SgSet::<u32, DEFAULT_CAPACITY>::from_iter(0..100);
+    let sgs_2: SgSet<u32, DEFAULT_CAPACITY> =
+        borsh::from_slice(&borsh::to_vec(&sgs).unwrap()).unwrap();
+    assert_eq!(sgs_2, sgs);
+    assert_eq!(sgs_2.debug_stats().rebuilds, 0);
+    assert_eq!(sgs_2.debug_stats().height, 7);
+}
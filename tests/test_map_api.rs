@@ -1,8 +1,9 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::iter::FromIterator;
 use std::ops::Bound::{Excluded, Included};
 
-use escapegoat::{SgError, SgMap, sgmap};
+use escapegoat::map_types::{DiffEvent, MergeValue};
+use escapegoat::{Alpha, CollectError, SgError, SgMap, sgmap, sgmap_from};
 
 use rand::Rng;
 
@@ -111,7 +112,7 @@ fn test_basic_map_functionality() {
 #[test]
 fn test_map_from_iter() {
     let key_val_tuples = vec![(1, "1"), (2, "2"), (3, "3")];
-    let sgm = SgMap::<_, _, 3>::from_iter(key_val_tuples.into_iter());
+    let sgm = SgMap::<_, _, 3>::from_iter(key_val_tuples);
 
     assert!(sgm.len() == 3);
     assert_eq!(
@@ -127,89 +128,1735 @@ fn test_map_from_iter_panic() {
         SgMap::from_iter((0..(DEFAULT_CAPACITY + 1)).map(|val| (val, val)));
 }
 
+#[test]
+fn test_map_from_macro() {
+    // Overflow is an error, not a panic
+    assert_eq!(
+        sgmap_from!(
+            (0..(DEFAULT_CAPACITY + 1)).map(|val| (val, val)),
+            DEFAULT_CAPACITY
+        ),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    let map = sgmap_from!(
+        (0..DEFAULT_CAPACITY).map(|val| (val, val)),
+        DEFAULT_CAPACITY
+    )
+    .unwrap();
+    assert!(map.is_full());
+    assert!(
+        map.into_iter()
+            .eq((0..DEFAULT_CAPACITY).map(|val| (val, val)))
+    );
+
+    // Duplicate keys don't count toward capacity, last value wins
+    let map = sgmap_from!([(1, 'a'), (2, 'b'), (1, 'c')], 2).unwrap();
+    assert!(map.into_iter().eq([(1, 'c'), (2, 'b')]));
+}
+
 #[test]
 fn test_map_iter() {
     let key_val_tuples = vec![(1, "1"), (2, "2"), (3, "3")];
-    let sgm = SgMap::<_, _, 3>::from_iter(key_val_tuples.into_iter());
+    let sgm = SgMap::<_, _, 3>::from_iter(key_val_tuples);
     let mut sgm_iter = sgm.iter();
 
-    assert_eq!(sgm_iter.next(), Some((&1, &"1")));
-    assert_eq!(sgm_iter.next(), Some((&2, &"2")));
-    assert_eq!(sgm_iter.next(), Some((&3, &"3")));
-    assert_eq!(sgm_iter.next(), None);
+    assert_eq!(sgm_iter.next(), Some((&1, &"1")));
+    assert_eq!(sgm_iter.next(), Some((&2, &"2")));
+    assert_eq!(sgm_iter.next(), Some((&3, &"3")));
+    assert_eq!(sgm_iter.next(), None);
+}
+
+#[test]
+fn test_map_iter_rev() {
+    let sgm: SgMap<_, _, 10> = (0..8).map(|i| (i, i * 10)).collect();
+    let btm: BTreeMap<_, _> = (0..8).map(|i| (i, i * 10)).collect();
+
+    assert!(sgm.iter().rev().eq(btm.iter().rev()));
+    assert!(
+        sgm.clone()
+            .into_iter()
+            .rev()
+            .eq(btm.clone().into_iter().rev())
+    );
+
+    let mut sgm_2 = sgm.clone();
+    for (_, v) in sgm_2.iter_mut().rev().take(2) {
+        *v = 0;
+    }
+    assert_eq!(sgm_2.last_key_value(), Some((&7, &0)));
+    assert_eq!(sgm_2.get(&5), Some(&50));
+
+    // Interleaved, no element visited twice
+    let mut iter = sgm.iter();
+    assert_eq!(iter.next_back(), Some((&7, &70)));
+    assert_eq!(iter.next(), Some((&0, &0)));
+    assert_eq!(iter.len(), 6);
+    assert!(iter.eq(btm.range(1..7)));
+
+    let mut into_iter = sgm.into_iter();
+    assert_eq!(into_iter.next(), Some((0, 0)));
+    assert_eq!(into_iter.next_back(), Some((7, 70)));
+    assert_eq!(into_iter.next_back(), Some((6, 60)));
+    assert_eq!(into_iter.len(), 5);
+    assert!(into_iter.eq(btm.into_iter().skip(1).take(5)));
+}
+
+#[test]
+fn test_map_for_each_sorted() {
+    let sgm: SgMap<_, _, DEFAULT_CAPACITY> = (0..10).rev().map(|i| (i, i * 10)).collect();
+
+    // Visits all, in order
+    let mut visited = Vec::new();
+    assert_eq!(
+        sgm.for_each_sorted(|k, v| {
+            visited.push((*k, *v));
+            Ok::<(), ()>(())
+        }),
+        Ok(())
+    );
+    assert!(visited.into_iter().eq(sgm.clone()));
+
+    // Short-circuits on first error
+    let mut visited = Vec::new();
+    assert_eq!(
+        sgm.for_each_sorted(|k, _| {
+            visited.push(*k);
+            match *k < 3 {
+                true => Ok(()),
+                false => Err(*k),
+            }
+        }),
+        Err(3)
+    );
+    assert_eq!(visited, [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_map_try_fold_entries() {
+    let mut rng = rand::rng();
+    let sgm: SgMap<u16, u32, 100> = (0..100)
+        .map(|_| (rng.random(), rng.random_range(0..1_000)))
+        .collect();
+
+    // Full folds match the iterator form, in order
+    let sum = sgm.try_fold_entries(0u64, |acc, _, v| Ok::<_, ()>(acc + u64::from(*v)));
+    assert_eq!(sum, Ok(sgm.values().map(|v| u64::from(*v)).sum()));
+
+    let keys = sgm.try_fold_entries(Vec::new(), |mut acc, k, _| {
+        acc.push(*k);
+        Ok::<_, ()>(acc)
+    });
+    assert!(keys.unwrap().iter().eq(sgm.keys()));
+
+    let rkeys = sgm.try_rfold_entries(Vec::new(), |mut acc, k, _| {
+        acc.push(*k);
+        Ok::<_, ()>(acc)
+    });
+    assert!(rkeys.unwrap().iter().eq(sgm.iter().rev().map(|(k, _)| k)));
+
+    // Short-circuits on the first error, in each direction
+    if let Some((&target, _)) = sgm.get_index(sgm.len() / 2) {
+        let mut visited = 0;
+        let res = sgm.try_fold_entries((), |_, k, _| {
+            visited += 1;
+            if *k == target { Err(*k) } else { Ok(()) }
+        });
+        assert_eq!(res, Err(target));
+        assert_eq!(visited, sgm.position(&target).unwrap() + 1);
+
+        let mut visited = 0;
+        let res = sgm.try_rfold_entries((), |_, k, _| {
+            visited += 1;
+            if *k == target { Err(*k) } else { Ok(()) }
+        });
+        assert_eq!(res, Err(target));
+        assert_eq!(visited, sgm.len() - sgm.position(&target).unwrap());
+    }
+
+    let empty = SgMap::<u16, u32, DEFAULT_CAPACITY>::new();
+    assert_eq!(
+        empty.try_fold_entries(7, |_, _, _| Err::<i32, ()>(())),
+        Ok(7)
+    );
+    assert_eq!(
+        empty.try_rfold_entries(7, |_, _, _| Err::<i32, ()>(())),
+        Ok(7)
+    );
+}
+
+#[test]
+fn test_map_min_max_by_value() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, u8, 32> = (0..32)
+            .map(|_| (rng.random(), rng.random_range(0..8)))
+            .collect();
+        let btm: BTreeMap<u8, u8> = sgm.clone().into_iter().collect();
+
+        // Reference: first in key order among extreme values
+        let min_val = btm.values().min().unwrap();
+        let max_val = btm.values().max().unwrap();
+        let expected_min = btm.iter().find(|(_, v)| *v == min_val);
+        let expected_max = btm.iter().find(|(_, v)| *v == max_val);
+
+        assert_eq!(sgm.min_by_value(), expected_min);
+        assert_eq!(sgm.max_by_value(), expected_max);
+
+        // Reversed comparator swaps roles
+        assert_eq!(sgm.min_by_value_with(|a, b| b.cmp(a)), expected_max);
+        assert_eq!(sgm.max_by_value_with(|a, b| b.cmp(a)), expected_min);
+    }
+
+    let empty = SgMap::<u8, u8, 1>::new();
+    assert_eq!(empty.min_by_value(), None);
+    assert_eq!(empty.max_by_value_with(u8::cmp), None);
+}
+
+#[test]
+fn test_map_group_runs() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, (), 64> = (0..64).map(|_| (rng.random(), ())).collect();
+
+        // Monotone bucket: one run per bucket, counts match a reference histogram
+        let mut expected = BTreeMap::new();
+        for k in sgm.keys() {
+            *expected.entry(k / 16).or_insert(0) += 1;
+        }
+        assert!(sgm.group_runs(|k| k / 16).eq(expected));
+
+        // Non-monotone bucket: runs still partition the map
+        let runs: Vec<(u8, usize)> = sgm.group_runs(|k| k % 3).collect();
+        assert_eq!(runs.iter().map(|(_, cnt)| cnt).sum::<usize>(), sgm.len());
+        assert!(runs.windows(2).all(|w| w[0].0 != w[1].0));
+    }
+
+    let empty = SgMap::<u8, u8, 1>::new();
+    assert_eq!(empty.group_runs(|k| *k).count(), 0);
+}
+
+#[test]
+fn test_map_duplicate_values() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, u8, 32> = (0..32)
+            .map(|_| (rng.random(), rng.random_range(0..16)))
+            .collect();
+
+        // Reference: count values in a BTreeMap
+        let mut counts = BTreeMap::new();
+        for v in sgm.values() {
+            *counts.entry(v).or_insert(0) += 1;
+        }
+        let expected: Vec<_> = counts.into_iter().filter(|(_, cnt)| *cnt > 1).collect();
+
+        assert!(sgm.duplicate_values().eq(expected.iter().copied()));
+        assert_eq!(sgm.has_duplicate_values(), !expected.is_empty());
+    }
+
+    let unique = SgMap::<u8, u8, 4>::from_iter([(1, 10), (2, 20), (3, 30)]);
+    assert_eq!(unique.duplicate_values().count(), 0);
+    assert!(!unique.has_duplicate_values());
+}
+
+#[test]
+fn test_map_keys_for_value() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, u8, 32> = (0..32)
+            .map(|_| (rng.random(), rng.random_range(0..8)))
+            .collect();
+
+        for target in 0..10 {
+            let expected: Vec<&u8> = sgm
+                .iter()
+                .filter(|(_, v)| **v == target)
+                .map(|(k, _)| k)
+                .collect();
+
+            assert!(sgm.keys_for_value(&target).eq(expected.iter().copied()));
+            assert_eq!(sgm.any_key_for_value(&target), expected.first().copied());
+        }
+    }
+
+    let empty = SgMap::<u8, u8, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.keys_for_value(&0).count(), 0);
+    assert_eq!(empty.any_key_for_value(&0), None);
+}
+
+#[test]
+fn test_map_quantile() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u16, u8, 64> = (0..rng.random_range(0..64))
+            .map(|_| (rng.random(), rng.random()))
+            .collect();
+        let sorted: Vec<(&u16, &u8)> = sgm.iter().collect();
+
+        for q in [0.0, 0.01, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0] {
+            let expected = match sorted.len() {
+                0 => None,
+                len => Some(sorted[(q * (len - 1) as f64).round() as usize]),
+            };
+            assert_eq!(sgm.quantile(q), expected);
+        }
+
+        assert_eq!(sgm.median(), sgm.quantile(0.5));
+    }
+
+    // Out of range
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 'a'), (2, 'b')]);
+    for q in [-0.01, 1.01, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(sgm.quantile(q), None);
+    }
+
+    // Rounding: position 0.5 rounds up
+    assert_eq!(sgm.median(), Some((&2, &'b')));
+    assert_eq!(sgm.quantile(0.49), Some((&1, &'a')));
+
+    let empty = SgMap::<u8, u8, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.median(), None);
+}
+
+#[test]
+fn test_map_map_values() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut sgm = SgMap::<u16, u16, 64>::new();
+        for _ in 0..64 {
+            sgm.insert(rng.random(), rng.random());
+        }
+        for _ in 0..16 {
+            sgm.remove(&rng.random_range(0..u16::MAX));
+        }
+        let mut btm: BTreeMap<u16, u16> = sgm.clone().into_iter().collect();
+
+        // In place, key-aware
+        sgm.map_values_in_place(|k, v| *v = v.wrapping_add(*k));
+        btm.iter_mut().for_each(|(k, v)| *v = v.wrapping_add(*k));
+        assert!(sgm.iter().eq(btm.iter()));
+        let stats = sgm.debug_stats();
+
+        // Consuming, to a new value type, visited in key order
+        let mut visited = Vec::new();
+        let mut sgm_str: SgMap<u16, (u16, bool), 64> = sgm.map_values(|v| {
+            visited.push(v);
+            (v, v % 2 == 0)
+        });
+        let btm_str: BTreeMap<u16, (u16, bool)> =
+            btm.into_iter().map(|(k, v)| (k, (v, v % 2 == 0))).collect();
+        assert!(sgm_str.iter().eq(btm_str.iter()));
+        assert!(visited.iter().eq(btm_str.values().map(|(v, _)| v)));
+
+        // Same structure, still usable
+        assert_eq!(sgm_str.debug_stats().height, stats.height);
+        let (k, v) = sgm_str.pop_last().unwrap();
+        assert_eq!(sgm_str.insert(k, v), None);
+        assert!(sgm_str.iter().eq(btm_str.iter()));
+    }
+}
+
+#[test]
+fn test_map_nearest_by() {
+    let mut rng = rand::rng();
+    let dist = |k: &i16, q: &i16| k.abs_diff(*q);
+
+    for _ in 0..100 {
+        let sgm: SgMap<i16, u8, 32> = (0..rng.random_range(0..32))
+            .map(|_| (rng.random_range(-500..500), rng.random()))
+            .collect();
+
+        for _ in 0..32 {
+            let q = rng.random_range(-600..600);
+
+            // Reference: brute force, first (smallest key) minimum wins
+            let expected = sgm
+                .iter()
+                .fold(None, |best: Option<(&i16, &u8)>, curr| match best {
+                    Some(b) if dist(b.0, &q) <= dist(curr.0, &q) => Some(b),
+                    _ => Some(curr),
+                });
+
+            assert_eq!(sgm.nearest_by(&q, dist), expected);
+        }
+    }
+}
+
+#[test]
+fn test_map_prev_next_entry() {
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(10, 'a'), (20, 'b'), (30, 'c')]);
+
+    // Equal to min/max
+    assert_eq!(sgm.prev_entry(&10), None);
+    assert_eq!(sgm.next_entry(&10), Some((&20, &'b')));
+    assert_eq!(sgm.prev_entry(&30), Some((&20, &'b')));
+    assert_eq!(sgm.next_entry(&30), None);
+
+    // Absent keys, inside and outside the key range
+    assert_eq!(sgm.prev_entry(&25), Some((&20, &'b')));
+    assert_eq!(sgm.next_entry(&25), Some((&30, &'c')));
+    assert_eq!(sgm.prev_entry(&5), None);
+    assert_eq!(sgm.next_entry(&5), Some((&10, &'a')));
+    assert_eq!(sgm.prev_entry(&35), Some((&30, &'c')));
+    assert_eq!(sgm.next_entry(&35), None);
+
+    let empty = SgMap::<i32, char, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.prev_entry(&0), None);
+    assert_eq!(empty.next_entry(&0), None);
+
+    // Reference: linear scan over random maps
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let sgm: SgMap<i16, u8, 32> = (0..rng.random_range(0..32))
+            .map(|_| (rng.random_range(-50..50), rng.random()))
+            .collect();
+
+        for q in -60..60 {
+            assert_eq!(sgm.prev_entry(&q), sgm.iter().rev().find(|(k, _)| **k < q));
+            assert_eq!(sgm.next_entry(&q), sgm.iter().find(|(k, _)| **k > q));
+        }
+    }
+}
+
+#[test]
+fn test_map_intersection_entries() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, u16, 32> = (0..rng.random_range(0..32))
+            .map(|_| (rng.random_range(0..64), rng.random()))
+            .collect();
+        let other: SgMap<u8, char, 32> = (0..rng.random_range(0..32))
+            .map(|_| (rng.random_range(0..64), 'x'))
+            .collect();
+
+        let expected: Vec<_> = sgm.iter().filter(|(k, _)| other.contains_key(k)).collect();
+        assert!(sgm.intersection_entries(&other).eq(expected));
+    }
+}
+
+#[test]
+fn test_map_collect_array() {
+    use std::rc::Rc;
+
+    let tracker = Rc::new(());
+    let sgm: SgMap<_, _, DEFAULT_CAPACITY> = (0..4).map(|i| (i, Rc::clone(&tracker))).collect();
+    assert_eq!(Rc::strong_count(&tracker), 5);
+
+    // Exact fit moves out every entry
+    let arr: [(i32, Rc<()>); 4] = sgm.clone().into_iter().collect_array().unwrap();
+    assert!(arr.iter().map(|(k, _)| *k).eq(0..4));
+    drop(arr);
+    assert_eq!(Rc::strong_count(&tracker), 5);
+
+    // Partially moved entries are dropped on error, nothing leaks
+    assert_eq!(
+        sgm.clone().into_iter().collect_array::<5>(),
+        Err(CollectError::TooFew)
+    );
+    assert_eq!(Rc::strong_count(&tracker), 5);
+
+    assert_eq!(
+        sgm.clone().into_iter().collect_array::<2>(),
+        Err(CollectError::TooMany)
+    );
+    assert_eq!(Rc::strong_count(&tracker), 5);
+
+    // Partially consumed iterator
+    let mut iter = sgm.clone().into_iter();
+    iter.next();
+    iter.next_back();
+    let [(k1, _), (k2, _)] = iter.collect_array().unwrap();
+    assert_eq!((k1, k2), (1, 2));
+
+    // Ranges
+    assert_eq!(
+        sgm.range(1..3)
+            .collect_array()
+            .map(|[(a, _), (b, _)]| (*a, *b)),
+        Ok((1, 2))
+    );
+    assert_eq!(
+        sgm.range(1..).collect_array::<0>().map(|_| ()),
+        Err(CollectError::TooMany)
+    );
+    assert!(sgm.range(4..).collect_array::<0>().is_ok());
+
+    drop(sgm);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn test_map_get_key_value_mut() {
+    use std::cmp::Ordering;
+
+    // Ordering and equality only consider `id`
+    #[derive(Debug)]
+    struct Tagged {
+        id: u32,
+        tag: &'static str,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.id.cmp(&other.id)
+        }
+    }
+
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    sgm.insert(
+        Tagged {
+            id: 1,
+            tag: "stored",
+        },
+        10,
+    );
+    sgm.insert(
+        Tagged {
+            id: 2,
+            tag: "other",
+        },
+        20,
+    );
+
+    let lookup = Tagged {
+        id: 1,
+        tag: "lookup",
+    };
+    let (k, v) = sgm.get_key_value_mut(&lookup).unwrap();
+    assert_eq!(k.tag, "stored");
+    *v += 1;
+
+    assert_eq!(sgm.get(&lookup), Some(&11));
+    assert_eq!(sgm.get(&Tagged { id: 2, tag: "" }), Some(&20));
+    assert!(sgm.get_key_value_mut(&Tagged { id: 3, tag: "" }).is_none());
+}
+
+#[test]
+fn test_map_update_key_in_place() {
+    let mut rng = rand::rng();
+    let mut sgm: SgMap<i32, u32, 32> = (0..32)
+        .map(|_| (rng.random_range(0..1_000), rng.random()))
+        .collect();
+
+    for _ in 0..500 {
+        let before = sgm.clone();
+        let pos = rng.random_range(0..sgm.len());
+        let (&key, &val) = sgm.get_index(pos).unwrap();
+        let new_key = key + rng.random_range(-20..20);
+
+        // Reference: strictly between sorted neighbors
+        let prev_ok = pos == 0 || *sgm.get_index(pos - 1).unwrap().0 < new_key;
+        let next_ok = pos + 1 == sgm.len() || new_key < *sgm.get_index(pos + 1).unwrap().0;
+
+        match sgm.update_key_in_place(&key, |k| *k = new_key) {
+            Ok(true) => {
+                assert!(prev_ok && next_ok);
+                assert_eq!(sgm.get(&new_key), Some(&val));
+                assert_eq!(sgm.position(&new_key), Some(pos));
+                assert!(new_key == key || !sgm.contains_key(&key));
+            }
+            Err(err) => {
+                assert_eq!(err, SgError::OrderViolation);
+                assert!(!(prev_ok && next_ok));
+                assert_eq!(sgm, before);
+            }
+            Ok(false) => unreachable!(),
+        }
+
+        // Lookups still agree with iteration order
+        assert!(sgm.keys().is_sorted());
+        assert!(sgm.iter().all(|(k, v)| sgm.get(k) == Some(v)));
+    }
+
+    assert_eq!(sgm.update_key_in_place(&-1, |k| *k = 0), Ok(false));
+}
+
+#[test]
+fn test_map_insert_tracked() {
+    // Ascending keys always extend the rightmost path. With the default alpha of 2/3, inserting into a map of
+    // size `n` rebuilds once that path is deeper than `floor(log_1.5(n))`: the first time at key 4 (depth 4 > 3).
+    let mut sgm = SgMap::<u8, u8, 16>::new();
+    let rebuilt_at: Vec<u8> = (0..16).filter(|&k| sgm.insert_tracked(k, k).1).collect();
+
+    // Both scapegoat searches trigger at key 4, but pick different scapegoats, so later rebuild points differ
+    #[cfg(not(feature = "alt_impl"))]
+    assert_eq!(rebuilt_at, [4, 6, 8, 9, 12, 13, 15]);
+    #[cfg(feature = "alt_impl")]
+    assert_eq!(rebuilt_at, [4, 8, 12, 15]);
+
+    // Updates never rebuild
+    for k in 0..16 {
+        assert_eq!(sgm.insert_tracked(k, 0), (Some(k), false));
+    }
+
+    // Values match `insert` for arbitrary keys
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u16, u16, 256>::new();
+    let mut btm = BTreeMap::new();
+    while sgm.len() < sgm.capacity() {
+        let (k, v) = (rng.random(), rng.random());
+        assert_eq!(sgm.insert_tracked(k, v).0, btm.insert(k, v));
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+}
+
+#[test]
+fn test_map_remove_tracked() {
+    // Removal rebuilds once fewer than half the entries since the last full rebuild remain: from 16 entries,
+    // at 7 left (removing key 8), then from 7 at 3 left (key 12), then from 3 at 1 left (key 14).
+    let mut sgm = SgMap::<u8, u8, 16>::from_iter((0..16).map(|k| (k, k)));
+    let rebuilt_at: Vec<u8> = (0..16)
+        .filter(|k| {
+            let (opt_val, rebuilt) = sgm.remove_tracked(k);
+            assert_eq!(opt_val, Some(*k));
+            rebuilt
+        })
+        .collect();
+    assert_eq!(rebuilt_at, [8, 12, 14]);
+    assert!(sgm.is_empty());
+
+    // Absent keys never rebuild
+    let mut sgm = SgMap::<u8, u8, 16>::from_iter((0..16).map(|k| (k * 2, k)));
+    for k in 0..16 {
+        assert_eq!(sgm.remove_tracked(&(k * 2 + 1)), (None, false));
+    }
+
+    // Values match `remove` for arbitrary keys
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u16, u16, 256>::new();
+    while sgm.len() < sgm.capacity() {
+        sgm.insert(rng.random(), rng.random());
+    }
+    let mut btm: BTreeMap<_, _> = sgm.iter().map(|(k, v)| (*k, *v)).collect();
+    while !sgm.is_empty() {
+        let k = match rng.random_bool(0.5) {
+            true => *sgm.get_index(rng.random_range(0..sgm.len())).unwrap().0,
+            false => rng.random(),
+        };
+        assert_eq!(sgm.remove_tracked(&k).0, btm.remove(&k));
+    }
+    assert!(btm.is_empty());
+}
+
+#[test]
+fn test_map_upsert() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u8, usize, 64>::new();
+    let mut btm = BTreeMap::new();
+
+    for _ in 0..1_000 {
+        let k = rng.random_range(0..64);
+        let sg_val = *sgm.upsert(k, 1, |n| *n += 1);
+        let bt_val = *btm.entry(k).and_modify(|n| *n += 1).or_insert(1);
+        assert_eq!(sg_val, bt_val);
+    }
+
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.values().sum::<usize>(), 1_000);
+}
+
+#[should_panic(expected = "Stack-storage capacity exceeded!")]
+#[test]
+fn test_map_upsert_panic() {
+    let mut sgm = SgMap::<_, _, 2>::from_iter([(1, 0), (2, 0)]);
+    sgm.upsert(1, 0, |n| *n += 1);
+    sgm.upsert(3, 0, |n| *n += 1); // panic
+}
+
+#[test]
+fn test_map_iter_clone() {
+    let sgm: SgMap<_, _, 10> = (0..8).map(|i| (i, i * 10)).collect();
+
+    let mut iter = sgm.iter();
+    iter.nth(2);
+    let forked = iter.clone();
+    assert_eq!(forked.len(), iter.len());
+    assert!(forked.eq(iter));
+
+    let mut keys = sgm.keys();
+    keys.next();
+    assert!(keys.clone().eq(keys));
+
+    let mut values = sgm.values();
+    values.next();
+    assert!(values.clone().eq(values));
+
+    let mut range = sgm.range(2..7);
+    range.next();
+    range.next_back();
+    assert!(range.clone().eq(range));
+}
+
+#[test]
+fn test_map_as_view() {
+    let sgm: SgMap<_, _, DEFAULT_CAPACITY> = (0..8).map(|i| (i * 10, i)).collect();
+    let view = sgm.as_view();
+
+    assert_eq!(view.len(), sgm.len());
+    assert!(!view.is_empty());
+    assert_eq!(view.capacity(), DEFAULT_CAPACITY);
+    assert_eq!(view.get(&30), Some(&3));
+    assert_eq!(view.get_key_value(&35), None);
+    assert!(view.contains_key(&70));
+    assert!(view.iter().eq(sgm.iter()));
+    assert!(view.keys().eq(sgm.keys()));
+    assert!(view.values().eq(sgm.values()));
+    assert!(view.range(15..45).eq(sgm.range(15..45)));
+    assert_eq!(view.first_key_value(), Some((&0, &0)));
+    assert_eq!(view.last_key_value(), Some((&70, &7)));
+    assert_eq!(format!("{:?}", view), format!("{:?}", sgm));
+
+    // Copyable, and borrows outlive the view itself
+    let copy = view;
+    let first = {
+        let tmp = copy;
+        tmp.first_key_value()
+    };
+    assert!(view.into_iter().next() == first);
+}
+
+#[test]
+fn test_map_nodes_in_order() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u16, u32, 64>::new();
+
+    for _ in 0..200 {
+        match rng.random_bool(0.6) {
+            true => {
+                let _ = sgm.try_insert(rng.random_range(0..128), rng.random());
+            }
+            false => {
+                sgm.remove(&rng.random_range(0..128));
+            }
+        }
+
+        let nodes = sgm.nodes_in_order();
+        assert_eq!(nodes.len(), sgm.len());
+
+        let nodes: Vec<_> = nodes.collect();
+        assert!(nodes.iter().map(|n| (n.key(), n.val())).eq(sgm.iter()));
+
+        // Slots are unique and in bounds
+        let slots: BTreeSet<usize> = nodes.iter().map(|n| n.arena_idx()).collect();
+        assert_eq!(slots.len(), nodes.len());
+        assert!(slots.iter().all(|idx| *idx < sgm.capacity()));
+
+        assert!(
+            sgm.nodes_in_order()
+                .rev()
+                .map(|n| n.key())
+                .eq(sgm.iter().rev().map(|(k, _)| k))
+        );
+    }
+}
+
+#[test]
+fn test_map_iter_mut() {
+    let key_val_tuples = vec![
+        ("h", 8),
+        ("d", 4),
+        ("b", 2),
+        ("e", 5),
+        ("f", 6),
+        ("a", 1),
+        ("g", 7),
+        ("c", 3),
+    ];
+
+    let mut sgm = SgMap::<_, _, 8>::from_iter(key_val_tuples);
+    assert_eq!(sgm.len(), 8);
+    assert_eq!(sgm.first_key_value(), Some((&"a", &1)));
+    assert_eq!(sgm.last_key_value(), Some((&"h", &8)));
+
+    for (key, val) in sgm.iter_mut() {
+        if (key != &"a") && (key != &"f") {
+            *val += 10;
+        }
+    }
+
+    assert_eq!(sgm.len(), 8);
+    assert_eq!(sgm.first_key_value(), Some((&"a", &1)));
+    assert_eq!(sgm.last_key_value(), Some((&"h", &18)));
+
+    assert_eq!(
+        sgm.into_iter().collect::<Vec<(&str, usize)>>(),
+        vec![
+            ("a", 1),
+            ("b", 12),
+            ("c", 13),
+            ("d", 14),
+            ("e", 15),
+            ("f", 6),
+            ("g", 17),
+            ("h", 18),
+        ],
+    );
+}
+
+#[test]
+fn test_map_iter_mut_removable() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut sgm: SgMap<u16, u16, 64> = (0..64)
+            .map(|_| (rng.random(), rng.random_range(0..8)))
+            .collect();
+        let mut btm: BTreeMap<u16, u16> = sgm.clone().into_iter().collect();
+        let keys: Vec<u16> = btm.keys().copied().collect();
+
+        // Stateful: drop values equal to the last kept one, bump the rest
+        let mut last_kept = None;
+        let mut visited = Vec::new();
+        let mut iter = sgm.iter_mut_removable();
+        assert_eq!(iter.len(), btm.len());
+        while let Some(mut entry) = iter.next() {
+            visited.push(*entry.key());
+            if last_kept == Some(*entry.value()) {
+                let (k, v) = entry.remove();
+                assert_eq!(btm.remove(&k), Some(v));
+            } else {
+                last_kept = Some(*entry.value());
+                *entry.value_mut() += 100;
+            }
+        }
+        assert!(iter.is_empty());
+
+        // Reference second pass
+        btm.values_mut().for_each(|v| *v += 100);
+
+        // No successor skipped, tree still valid for further use
+        assert_eq!(visited, keys);
+        assert!(sgm.iter().eq(btm.iter()));
+        while let Some((k, v)) = sgm.pop_first() {
+            assert_eq!(btm.pop_first(), Some((k, v)));
+        }
+    }
+}
+
+#[test]
+fn test_map_iter_mut_rand() {
+    const CAPACITY: usize = 500;
+    let mut sgm = SgMap::<isize, isize, CAPACITY>::new();
+    let mut rng = rand::rng();
+
+    for _ in 0..CAPACITY {
+        sgm.insert(rng.random::<i64>() as isize, 0);
+    }
+
+    let min_key = *sgm.first_key().unwrap();
+    let max_key = *sgm.last_key().unwrap();
+
+    let mut last_key_opt = None;
+    for (key, val) in sgm.iter_mut() {
+        *val += 25;
+        if let Some(last_key) = last_key_opt {
+            assert!(key >= last_key);
+        }
+        last_key_opt = Some(key);
+    }
+
+    assert_eq!(min_key, *sgm.first_key().unwrap());
+    assert_eq!(max_key, *sgm.last_key().unwrap());
+
+    let result_vec = sgm.into_iter().collect::<Vec<(isize, isize)>>();
+    assert!(result_vec.as_slice().windows(2).all(|w| w[0].0 <= w[1].0));
+    assert!(result_vec.iter().all(|(_, v)| *v == 25));
+}
+
+#[test]
+fn test_map_count_where() {
+    // Same shapes as the benchmark datasets: random and sequential keys
+    fn check<const N: usize>(keys: impl Iterator<Item = usize>) {
+        let sgm = SgMap::<usize, usize, N>::from_iter(keys.map(|k| (k, k % 7)));
+
+        for m in [1, 2, 3, 5] {
+            let pred = |k: &usize, v: &usize| (k / 3 + v) % m == 0;
+            assert_eq!(
+                sgm.count_where(pred),
+                sgm.iter().filter(|(k, v)| pred(k, v)).count()
+            );
+        }
+
+        assert_eq!(sgm.count_where(|_, _| true), sgm.len());
+        assert_eq!(sgm.count_where(|_, _| false), 0);
+    }
+
+    let mut rng = rand::rng();
+    check::<100>((0..100).map(|_| rng.random_range(0..usize::MAX)));
+    check::<1_000>((0..1_000).map(|_| rng.random_range(0..usize::MAX)));
+    check::<100>(0..100);
+    check::<1_000>(0..1_000);
+
+    let empty = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.count_where(|_, _| true), 0);
+}
+
+#[test]
+fn test_map_filter_values() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    while !sgm.is_full() {
+        let (k, v) = (rng.random_range(0..100), rng.random_range(0..100));
+        sgm.insert(k, v);
+        btm.insert(k, v);
+    }
+
+    assert!(
+        sgm.filter_values(|v| v % 2 == 0)
+            .eq(btm.iter().filter(|(_, v)| *v % 2 == 0))
+    );
+    assert_eq!(sgm.filter_values(|_| false).next(), None);
+
+    for (_, v) in sgm.filter_values_mut(|v| *v < 50) {
+        *v += 100;
+    }
+    for (_, v) in btm.iter_mut().filter(|(_, v)| **v < 50) {
+        *v += 100;
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.filter_values(|v| *v < 50).count(), 0);
+}
+
+#[test]
+fn test_map_for_each_value_by() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u8, 64>::new();
+    while !sgm.is_full() {
+        sgm.insert(rng.random(), rng.random_range(0..8));
+    }
+
+    // Expected order: by value descending, then by key ascending
+    let mut expected: Vec<(u32, u8)> = sgm.iter().map(|(k, v)| (*k, *v)).collect();
+    expected.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let mut visited = Vec::new();
+    sgm.for_each_value_by(
+        |a, b| b.cmp(a),
+        |k, v| {
+            visited.push((*k, *v));
+            *v += 100;
+        },
+    );
+
+    assert_eq!(visited, expected);
+    assert!(sgm.values().all(|v| *v >= 100));
+    assert_eq!(sgm.len(), 64);
+}
+
+#[test]
+fn test_map_merge_iter() {
+    let mut rng = rand::rng();
+    let left: SgMap<u8, u8, 32> = (0..20)
+        .map(|_| (rng.random_range(0..40), rng.random()))
+        .collect();
+    let right: SgMap<u8, u8, 32> = (0..20)
+        .map(|_| (rng.random_range(0..40), rng.random()))
+        .collect();
+
+    let mut expected = BTreeMap::new();
+    for (k, v) in left.iter() {
+        expected.insert(*k, MergeValue::Left(v));
+    }
+    for (k, v) in right.iter() {
+        let merged = match expected.remove(k) {
+            Some(MergeValue::Left(left_v)) => MergeValue::Both(left_v, v),
+            _ => MergeValue::Right(v),
+        };
+        expected.insert(*k, merged);
+    }
+
+    let merge_iter = left.merge_iter(&right);
+    let (lower, upper) = merge_iter.size_hint();
+    assert!(lower <= expected.len() && expected.len() <= upper.unwrap());
+    assert!(merge_iter.eq(expected.iter().map(|(k, v)| (k, *v))));
+
+    let empty = SgMap::new();
+    assert!(empty.merge_iter(&empty).next().is_none());
+    assert!(
+        left.merge_iter(&empty)
+            .all(|(_, v)| matches!(v, MergeValue::Left(_)))
+    );
+    assert!(
+        empty
+            .merge_iter(&right)
+            .all(|(_, v)| matches!(v, MergeValue::Right(_)))
+    );
+}
+
+#[test]
+fn test_map_diff() {
+    let older = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 10), (2, 20), (3, 30), (5, 50)]);
+    let mut newer = older.clone();
+
+    newer.remove(&1);
+    newer.insert(3, 33);
+    newer.insert(4, 40);
+    newer.insert(6, 60);
+
+    assert!(older.diff(&newer).eq([
+        DiffEvent::Removed(&1, &10),
+        DiffEvent::Changed(&3, &30, &33),
+        DiffEvent::Added(&4, &40),
+        DiffEvent::Added(&6, &60),
+    ]));
+
+    // Reversed direction swaps adds and removes
+    assert!(newer.diff(&older).eq([
+        DiffEvent::Added(&1, &10),
+        DiffEvent::Changed(&3, &33, &30),
+        DiffEvent::Removed(&4, &40),
+        DiffEvent::Removed(&6, &60),
+    ]));
+
+    // Replaying the changeset on the old map reproduces the new one
+    let mut replica = older.clone();
+    for event in older.diff(&newer) {
+        match event {
+            DiffEvent::Added(k, v) | DiffEvent::Changed(k, _, v) => {
+                replica.insert(*k, *v);
+            }
+            DiffEvent::Removed(k, _) => {
+                replica.remove(k);
+            }
+        }
+    }
+    assert_eq!(replica, newer);
+
+    assert!(older.diff(&older).next().is_none());
+    let empty = SgMap::new();
+    assert!(
+        empty
+            .diff(&older)
+            .all(|e| matches!(e, DiffEvent::Added(..)))
+    );
+    assert_eq!(older.diff(&empty).count(), older.len());
+}
+
+#[test]
+fn test_map_pop_if() {
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    assert_eq!(sgm.pop_first_if(|_, _| true), None);
+    assert_eq!(sgm.pop_last_if(|_, _| true), None);
+
+    sgm.extend((0..10).map(|i| (i, i * 10)));
+
+    // Rejected predicate leaves the map untouched
+    assert_eq!(sgm.pop_first_if(|k, _| *k > 0), None);
+    assert_eq!(sgm.pop_last_if(|_, v| *v < 90), None);
+    assert_eq!(sgm.len(), 10);
+
+    let mut popped = Vec::new();
+    while let Some((k, _)) = sgm.pop_first_if(|k, _| *k < 4) {
+        popped.push(k);
+    }
+    while let Some((k, _)) = sgm.pop_last_if(|_, v| *v >= 70) {
+        popped.push(k);
+    }
+    assert_eq!(popped, vec![0, 1, 2, 3, 9, 8, 7]);
+    assert!(sgm.keys().eq([4, 5, 6].iter()));
+    assert_eq!(sgm.first_key_value(), Some((&4, &40)));
+    assert_eq!(sgm.last_key_value(), Some((&6, &60)));
+}
+
+#[test]
+fn test_map_insert_with() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    let mut std = BTreeMap::new();
+
+    // Word-count style accumulation matches the `entry` pattern
+    for _ in 0..1000 {
+        let (k, v) = (
+            rng.random_range(0..(DEFAULT_CAPACITY as u32)),
+            rng.random_range(0..10),
+        );
+        assert!(sgm.insert_with(k, v, |old, new| old + new).is_ok());
+        std.entry(k).and_modify(|n| *n += v).or_insert(v);
+    }
+    assert!(sgm.iter().eq(std.iter()));
+    assert_eq!(sgm.len(), DEFAULT_CAPACITY);
+
+    // Full map: merge into existing key succeeds, new key fails and leaves the map untouched
+    assert_eq!(sgm.insert_with(0, 100_000, |old, new| old.max(new)), Ok(()));
+    assert_eq!(sgm[&0], 100_000);
+    assert_eq!(
+        sgm.insert_with(DEFAULT_CAPACITY as u32, 1, |_, _| unreachable!()),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(sgm.len(), DEFAULT_CAPACITY);
+
+    // Merge receives the old value first, and the stored key is kept
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    sgm.insert_with(1, String::from("a"), |old, new| old + &new)
+        .unwrap();
+    sgm.insert_with(1, String::from("b"), |old, new| old + &new)
+        .unwrap();
+    sgm.insert_with(1, String::from("c"), |old, new| old + &new)
+        .unwrap();
+    assert_eq!(sgm.get(&1).map(String::as_str), Some("abc"));
+
+    // Merging into existing keys is in place: no rebuilds, even for ascending keys that would rebuild on insert
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    for k in 0..(DEFAULT_CAPACITY as u32) {
+        sgm.insert(k, 0);
+    }
+    let rebuilds = sgm.debug_stats().rebuilds;
+    for k in 0..(DEFAULT_CAPACITY as u32) {
+        sgm.insert_with(k, 1, |old, new| old + new).unwrap();
+    }
+    assert_eq!(sgm.debug_stats().rebuilds, rebuilds);
+    assert!(sgm.values().all(|v| *v == 1));
+}
+
+#[test]
+fn test_map_insert_with_panic_safety() {
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    let mut sgm = SgMap::<u32, String, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+    for k in 0..DEFAULT_CAPACITY as u32 {
+        sgm.insert(k, k.to_string());
+        btm.insert(k, k.to_string());
+    }
+
+    // Panicking merge into a full map
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        sgm.insert_with(3, String::from("x"), |_, _| panic!("merge failed"))
+    }));
+    assert!(res.is_err());
+    btm.remove(&3);
+
+    // Still valid: lookups, iteration, and further inserts all work
+    assert_eq!(sgm.len(), btm.len());
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.get(&3), None);
+    assert_eq!(sgm.get(&4).map(String::as_str), Some("4"));
+    sgm.insert_with(3, String::from("3"), |old, new| old + &new)
+        .unwrap();
+    sgm.insert_with(4, String::from("!"), |old, new| old + &new)
+        .unwrap();
+    assert_eq!(sgm.get(&3).map(String::as_str), Some("3"));
+    assert_eq!(sgm.get(&4).map(String::as_str), Some("4!"));
+    assert!(sgm.is_full());
+    while let Some((k, _)) = sgm.pop_first() {
+        assert!(sgm.get(&k).is_none());
+    }
+}
+
+#[test]
+fn test_map_windows() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1000), rng.random());
+    }
+    let entries: Vec<_> = sgm.iter().collect();
+
+    // Pairwise form matches slice windows
+    let pairs: Vec<_> = sgm.windows2().collect();
+    let expected: Vec<_> = entries.windows(2).map(|w| (w[0], w[1])).collect();
+    assert_eq!(pairs, expected);
+
+    // General form matches slice windows, for every size including oversized
+    for size in 1..=(DEFAULT_CAPACITY + 2) {
+        let windows = sgm.windows(size);
+        assert_eq!(windows.len(), entries.windows(size).len());
+        assert!(
+            windows
+                .map(|w| w.to_vec())
+                .eq(entries.windows(size).map(|w| w.to_vec()))
+        );
+    }
+
+    // Fewer than 2 entries yields nothing
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    assert_eq!(sgm.windows2().count(), 0);
+    assert_eq!(sgm.windows(1).count(), 0);
+    sgm.insert(1, 1);
+    assert_eq!(sgm.windows2().count(), 0);
+    assert_eq!(sgm.windows(1).count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn test_map_windows_panic() {
+    let sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    let _ = sgm.windows(0);
+}
+
+#[test]
+fn test_map_range_between() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1000), rng.random_range(0..4));
+    }
+    let entries: Vec<_> = sgm.iter().collect();
+
+    // Reference: slice between first start match and first end match at or after it
+    for (start_val, end_val) in [(0, 1), (1, 0), (2, 2), (3, 0)] {
+        let expected = entries
+            .iter()
+            .position(|(_, v)| **v == start_val)
+            .and_then(|s| {
+                entries[s..]
+                    .iter()
+                    .position(|(_, v)| **v == end_val)
+                    .map(|l| &entries[s..=(s + l)])
+            })
+            .unwrap_or(&[]);
+
+        let between = sgm.range_between(|_, v| *v == start_val, |_, v| *v == end_val);
+        assert_eq!(between.len(), expected.len());
+        assert!(between.eq(expected.iter().copied()));
+
+        // Reversible
+        let between = sgm.range_between(|_, v| *v == start_val, |_, v| *v == end_val);
+        assert!(between.rev().eq(expected.iter().rev().copied()));
+    }
+
+    // Start or end not found
+    assert_eq!(sgm.range_between(|_, _| false, |_, _| true).count(), 0);
+    assert_eq!(sgm.range_between(|_, _| true, |_, _| false).count(), 0);
+
+    // Single entry: start is end
+    let (k, v) = entries[3];
+    assert!(
+        sgm.range_between(|key, _| key == k, |_, _| true)
+            .eq([(k, v)])
+    );
+}
+
+#[test]
+fn test_map_is_disjoint_intervals() {
+    let as_interval = |start: &u64, len: &u64| (*start, start + len);
+
+    // Trivially disjoint
+    let mut sgm = SgMap::<u64, u64, DEFAULT_CAPACITY>::new();
+    assert!(sgm.is_disjoint_intervals(as_interval));
+    sgm.insert(5, 0);
+    assert!(sgm.is_disjoint_intervals(as_interval));
+
+    // Non-overlapping, including touching and gapped neighbors
+    let sgm =
+        SgMap::<u64, u64, DEFAULT_CAPACITY>::from_iter([(0, 4), (4, 4), (10, 1), (11, 0), (11, 9)]);
+    assert!(sgm.is_disjoint_intervals(as_interval));
+
+    // Each single overlap is detected, wherever it is
+    for (start, len) in [(1, 1), (3, 5), (9, 2), (12, 1), (19, 5)] {
+        let mut overlapping = sgm.clone();
+        overlapping.insert(start, len);
+        assert!(!overlapping.is_disjoint_intervals(as_interval));
+    }
+
+    // Appending past the end stays disjoint
+    let mut appended = sgm.clone();
+    appended.insert(20, 100);
+    assert!(appended.is_disjoint_intervals(as_interval));
+
+    // Malformed interval (end before start)
+    assert!(!sgm.is_disjoint_intervals(|start, len| (*start + len, *start)));
+
+    // Random partition of a domain
+    let mut rng = rand::rng();
+    let mut parts = SgMap::<u64, u64, DEFAULT_CAPACITY>::new();
+    let mut start = 0;
+    while parts.len() < parts.capacity() {
+        let len = rng.random_range(0..100);
+        parts.insert(start, len);
+        start += len + rng.random_range(0..2);
+    }
+    assert!(parts.is_disjoint_intervals(as_interval));
+    let (&last_start, &last_len) = parts.last_key_value().unwrap();
+    parts.insert(last_start, last_len + 1);
+    assert!(parts.is_disjoint_intervals(as_interval));
+    let (&first_start, &first_len) = parts.first_key_value().unwrap();
+    let (&second_start, _) = parts.iter().nth(1).unwrap();
+    parts.insert(first_start, first_len.max(second_start - first_start) + 1);
+    assert!(!parts.is_disjoint_intervals(as_interval));
+}
+
+#[test]
+fn test_map_insert_all_new() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u8, u32, 64>::new();
+    let mut btm = BTreeMap::new();
+
+    // Batches with duplicates and pre-existing keys: first pair wins, existing values untouched
+    while !sgm.is_full() {
+        let batch: Vec<(u8, u32)> = (0..8)
+            .map(|_| (rng.random_range(0..96), rng.random()))
+            .collect();
+
+        let mut expected = Vec::new();
+        let mut overflowed = false;
+        for (k, v) in &batch {
+            if btm.contains_key(k) {
+                continue;
+            }
+            if btm.len() == sgm.capacity() {
+                overflowed = true;
+                break;
+            }
+            btm.insert(*k, *v);
+            expected.push(*k);
+        }
+
+        match sgm.insert_all_new(batch) {
+            Ok(added) => {
+                assert!(!overflowed);
+                assert_eq!(added.as_slice(), expected);
+            }
+            Err((added, err)) => {
+                assert!(overflowed);
+                assert_eq!(added.as_slice(), expected);
+                assert_eq!(err, SgError::StackCapacityExceeded);
+            }
+        }
+        assert!(sgm.iter().eq(btm.iter()));
+    }
+
+    // Full map: re-inserting only existing keys succeeds as a no-op
+    let keys: Vec<_> = sgm.keys().copied().collect();
+    let added = sgm
+        .insert_all_new(keys.into_iter().map(|k| (k, 0)))
+        .unwrap();
+    assert!(added.is_empty());
+    assert!(sgm.iter().eq(btm.iter()));
+}
+
+#[test]
+fn test_map_iter_mut_indexed() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, usize, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1000), 0);
+    }
+
+    // Rank matches sorted position, from either end
+    let mut iter = sgm.iter_mut_indexed();
+    assert_eq!(iter.len(), DEFAULT_CAPACITY);
+    let (last_rank, last_key, _) = iter.next_back().unwrap();
+    assert_eq!(last_rank, DEFAULT_CAPACITY - 1);
+    let last_key = *last_key;
+    drop(iter);
+    assert_eq!(sgm.last_key(), Some(&last_key));
+
+    for (rank, _, v) in sgm.iter_mut_indexed() {
+        *v = rank * 10;
+    }
+    for idx in 0..sgm.len() {
+        assert_eq!(sgm.get_index(idx).map(|(_, v)| *v), Some(idx * 10));
+    }
+
+    // Empty
+    let mut empty = SgMap::<u32, usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.iter_mut_indexed().count(), 0);
+}
+
+#[test]
+fn test_map_swap_values() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, String, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        let k = rng.random_range(0..1000);
+        sgm.insert(k, k.to_string());
+    }
+    let mut expected: Vec<(u32, String)> = sgm.clone().into_iter().collect();
+
+    // Keys stay put while values swap, mirrored on a `Vec`
+    for _ in 0..100 {
+        let (i, j) = (
+            rng.random_range(0..DEFAULT_CAPACITY),
+            rng.random_range(0..DEFAULT_CAPACITY),
+        );
+        assert!(sgm.swap_values(&expected[i].0, &expected[j].0));
+
+        let tmp = expected[i].1.clone();
+        expected[i].1 = expected[j].1.clone();
+        expected[j].1 = tmp;
+        assert!(
+            sgm.iter()
+                .map(|(k, v)| (*k, v.clone()))
+                .eq(expected.iter().cloned())
+        );
+    }
+
+    // Either key missing leaves both unchanged
+    let present = expected[0].0;
+    let missing = (0..).find(|k| !sgm.contains_key(k)).unwrap();
+    let before = sgm.clone();
+    assert!(!sgm.swap_values(&present, &missing));
+    assert!(!sgm.swap_values(&missing, &present));
+    assert!(!sgm.swap_values(&missing, &missing));
+    assert_eq!(sgm, before);
+}
+
+#[test]
+fn test_map_k_nearest_by() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        let k = rng.random_range(0..100);
+        sgm.insert(k, k * 2);
+    }
+    let dist = |k: &u32, q: &u32| k.abs_diff(*q);
+
+    // Reference: stable sort of all entries by (distance, key)
+    for _ in 0..100 {
+        let query = rng.random_range(0..110);
+        let k = rng.random_range(0..(DEFAULT_CAPACITY + 3));
+
+        let mut expected: Vec<_> = sgm.iter().collect();
+        expected.sort_by_key(|(key, _)| (dist(key, &query), **key));
+        expected.truncate(k);
+
+        assert!(sgm.k_nearest_by(&query, k, dist).eq(expected.into_iter()));
+    }
+
+    // First result agrees with `nearest_by`
+    let query = rng.random_range(0..110);
+    assert_eq!(
+        sgm.k_nearest_by(&query, 1, dist).next(),
+        sgm.nearest_by(&query, dist)
+    );
+
+    // Degenerate
+    assert_eq!(sgm.k_nearest_by(&50, 0, dist).count(), 0);
+    let empty = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.k_nearest_by(&50, 3, dist).count(), 0);
+}
+
+#[test]
+fn test_map_cloned_copied_iter() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1000), rng.random());
+    }
+    let btm: BTreeMap<u32, u32> = sgm.iter().map(|(k, v)| (*k, *v)).collect();
+
+    assert_eq!(sgm.copied_iter().len(), sgm.len());
+    assert!(sgm.copied_iter().eq(btm.clone()));
+    assert!(sgm.copied_iter().rev().eq(btm.clone().into_iter().rev()));
+
+    // Owned clones of non-`Copy` data, map left intact
+    let strs = SgMap::<String, Vec<u32>, DEFAULT_CAPACITY>::from_iter(
+        btm.iter().map(|(k, v)| (k.to_string(), vec![*v])),
+    );
+    let owned: Vec<(String, Vec<u32>)> = strs.cloned_iter().collect();
+    assert!(owned.iter().map(|(k, v)| (k, v)).eq(strs.iter()));
+    assert_eq!(strs.len(), DEFAULT_CAPACITY);
+}
+
+#[test]
+fn test_map_from_refs() {
+    let mut rng = rand::rng();
+    let mut btm = BTreeMap::new();
+    while btm.len() < 100 {
+        btm.insert(
+            rng.random_range(0..1000u32),
+            rng.random::<u32>().to_string(),
+        );
+    }
+
+    // Borrowed source left intact
+    let sgm = SgMap::<u32, String, 100>::from_refs(&btm).unwrap();
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(btm.len(), 100);
+
+    // Later duplicate wins, like `from_iter`
+    let pairs = [(1, 10), (2, 20), (1, 11)];
+    let sgm = SgMap::<i32, i32, 2>::from_refs(pairs.iter().map(|(k, v)| (k, v))).unwrap();
+    assert!(sgm.into_iter().eq([(1, 11), (2, 20)]));
+
+    assert_eq!(
+        SgMap::<u32, String, 99>::from_refs(&btm),
+        Err(SgError::StackCapacityExceeded)
+    );
+}
+
+#[cfg(feature = "access_tracking")]
+#[test]
+fn test_map_lru() {
+    const CACHE_CAPACITY: usize = 16;
+
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, CACHE_CAPACITY>::new();
+
+    // Reference model: keys, least recently used first
+    let mut recency: Vec<u32> = Vec::new();
+
+    for _ in 0..10_000 {
+        let key = rng.random_range(0..64);
+        match rng.random_bool(0.5) {
+            true => {
+                if !sgm.contains_key(&key) && sgm.is_full() {
+                    let lru = recency.remove(0);
+                    assert_eq!(sgm.evict_lru().map(|(k, _)| k), Some(lru));
+                }
+                sgm.insert(key, key * 2);
+                recency.retain(|k| *k != key);
+                recency.push(key);
+            }
+            false => {
+                assert_eq!(sgm.get(&key).copied(), sgm.get_touch(&key).copied());
+                if sgm.contains_key(&key) {
+                    recency.retain(|k| *k != key);
+                    recency.push(key);
+                }
+            }
+        }
+        assert_eq!(sgm.len(), recency.len());
+    }
+
+    // Drains in recency order
+    for key in recency {
+        assert_eq!(sgm.evict_lru(), Some((key, key * 2)));
+    }
+    assert_eq!(sgm.evict_lru(), None);
+
+    // Bulk-built entries are untouched, so evicted smallest key first
+    let mut sgm =
+        SgMap::<u32, u32, CACHE_CAPACITY>::from_sorted_slices(&[0, 1, 2, 3], &[0, 1, 2, 3])
+            .unwrap();
+    sgm.get_touch(&0);
+    assert_eq!(sgm.evict_lru(), Some((1, 1)));
+    assert_eq!(sgm.evict_lru(), Some((2, 2)));
+}
+
+#[test]
+fn test_map_ensure_capacity() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    while sgm.len() < DEFAULT_CAPACITY - 1 {
+        let k: u16 = rng.random();
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+
+    // Same capacity
+    let sgm = sgm.ensure_capacity::<DEFAULT_CAPACITY>().unwrap();
+    assert!(sgm.iter().eq(btm.iter()));
+
+    // Promote, then keep inserting
+    let mut sgm = sgm.ensure_capacity::<{ DEFAULT_CAPACITY * 2 }>().unwrap();
+    assert!(sgm.iter().eq(btm.iter()));
+    while sgm.len() < sgm.capacity() {
+        let k: u16 = rng.random();
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+
+    // Too small
+    assert_eq!(
+        sgm.ensure_capacity::<{ DEFAULT_CAPACITY * 2 - 1 }>()
+            .unwrap_err(),
+        SgError::StackCapacityExceeded
+    );
+}
+
+#[test]
+fn test_map_hash_with() {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    // Records raw writes, so whole input streams can be compared
+    #[derive(Default)]
+    struct RecordingHasher(Vec<u8>);
+
+    impl Hasher for RecordingHasher {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+
+        fn finish(&self) -> u64 {
+            self.0.len() as u64
+        }
+    }
+
+    let mut rng = rand::rng();
+    let mut entries: Vec<(u32, u64)> = (0..DEFAULT_CAPACITY)
+        .map(|_| (rng.random(), rng.random()))
+        .collect();
+
+    let sgm_1 = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter(entries.clone());
+    entries.reverse();
+    let sgm_2 = SgMap::<_, _, { DEFAULT_CAPACITY * 2 }>::from_iter(entries);
+
+    // Identical input stream, regardless of insertion order and capacity
+    let mut rec_1 = RecordingHasher::default();
+    let mut rec_2 = RecordingHasher::default();
+    sgm_1.hash_with(&mut rec_1);
+    sgm_2.hash_with(&mut rec_2);
+    assert_eq!(rec_1.0, rec_2.0);
+
+    // `Hash` is built on the same stream
+    let mut rec_3 = RecordingHasher::default();
+    sgm_1.hash(&mut rec_3);
+    assert_eq!(rec_1.0, rec_3.0);
+
+    // Any hasher works
+    let mut hasher_1 = DefaultHasher::new();
+    let mut hasher_2 = DefaultHasher::new();
+    sgm_1.hash_with(&mut hasher_1);
+    sgm_2.hash_with(&mut hasher_2);
+    assert_eq!(hasher_1.finish(), hasher_2.finish());
+
+    // Length prefix separates empty from non-empty
+    let mut rec_empty = RecordingHasher::default();
+    SgMap::<u32, u64, 1>::new().hash_with(&mut rec_empty);
+    assert_eq!(rec_empty.0, 0usize.to_ne_bytes());
 }
 
 #[test]
-fn test_map_iter_mut() {
-    let key_val_tuples = vec![
-        ("h", 8),
-        ("d", 4),
-        ("b", 2),
-        ("e", 5),
-        ("f", 6),
-        ("a", 1),
-        ("g", 7),
-        ("c", 3),
-    ];
+fn test_map_split_first_last() {
+    assert!(
+        SgMap::<u32, u32, DEFAULT_CAPACITY>::new()
+            .split_first()
+            .is_none()
+    );
+    assert!(
+        SgMap::<u32, u32, DEFAULT_CAPACITY>::new()
+            .split_last()
+            .is_none()
+    );
 
-    let mut sgm = SgMap::<_, _, 8>::from_iter(key_val_tuples.into_iter());
-    assert_eq!(sgm.len(), 8);
-    assert_eq!(sgm.first_key_value(), Some((&"a", &1)));
-    assert_eq!(sgm.last_key_value(), Some((&"h", &8)));
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1000), rng.random());
+    }
+    let expected: Vec<_> = sgm.iter().map(|(k, v)| (*k, *v)).collect();
+
+    // Repeated split_first yields entries in ascending key order
+    let mut heads = Vec::new();
+    let mut rest = sgm.clone();
+    while let Some((head, tail)) = rest.split_first() {
+        assert_eq!(tail.len(), expected.len() - heads.len() - 1);
+        heads.push(head);
+        rest = tail;
+    }
+    assert_eq!(heads, expected);
+
+    // Repeated split_last yields entries in descending key order
+    let mut lasts = Vec::new();
+    let mut rest = sgm;
+    while let Some((last, init)) = rest.split_last() {
+        lasts.push(last);
+        rest = init;
+    }
+    lasts.reverse();
+    assert_eq!(lasts, expected);
+}
 
-    for (key, val) in sgm.iter_mut() {
-        if (key != &"a") && (key != &"f") {
-            *val += 10;
+#[test]
+fn test_map_drain_chunks() {
+    let mut sgm =
+        SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter((0..DEFAULT_CAPACITY).map(|i| (i, i)));
+
+    let chunks = sgm.drain_chunks(3);
+    assert_eq!(chunks.len(), DEFAULT_CAPACITY.div_ceil(3));
+
+    let mut expected = 0;
+    for chunk in chunks {
+        assert!(!chunk.is_empty() && chunk.len() <= 3);
+        for (k, v) in chunk {
+            assert_eq!((k, v), (expected, expected));
+            expected += 1;
         }
     }
+    assert_eq!(expected, DEFAULT_CAPACITY);
+    assert!(sgm.is_empty());
 
-    assert_eq!(sgm.len(), 8);
-    assert_eq!(sgm.first_key_value(), Some((&"a", &1)));
-    assert_eq!(sgm.last_key_value(), Some((&"h", &18)));
+    // Chunk larger than the map, and refill after partial drain
+    sgm.extend([(7, 7), (3, 3), (5, 5)]);
+    let mut chunks = sgm.drain_chunks(DEFAULT_CAPACITY + 1);
+    assert_eq!(chunks.next().unwrap().as_slice(), [(3, 3), (5, 5), (7, 7)]);
+    assert!(chunks.next().is_none());
+    assert_eq!(sgm.drain_chunks(1).next(), None);
+}
 
-    assert_eq!(
-        sgm.into_iter().collect::<Vec<(&str, usize)>>(),
-        vec![
-            ("a", 1),
-            ("b", 12),
-            ("c", 13),
-            ("d", 14),
-            ("e", 15),
-            ("f", 6),
-            ("g", 17),
-            ("h", 18),
-        ],
-    );
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_map_drain_chunks_zero() {
+    let mut sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    sgm.drain_chunks(0);
 }
 
 #[test]
-fn test_map_iter_mut_rand() {
-    const CAPACITY: usize = 500;
-    let mut sgm = SgMap::<isize, isize, CAPACITY>::new();
+fn test_map_same_keys() {
     let mut rng = rand::rng();
 
-    for _ in 0..CAPACITY {
-        sgm.insert(rng.random::<i64>() as isize, 0);
+    for _ in 0..100 {
+        let a = SgMap::<u8, u8, DEFAULT_CAPACITY>::from_iter(
+            (0..rng.random_range(0..DEFAULT_CAPACITY)).map(|_| (rng.random_range(0..8), 0)),
+        );
+        let b = SgMap::<u8, &str, DEFAULT_CAPACITY>::from_iter(
+            (0..rng.random_range(0..DEFAULT_CAPACITY)).map(|_| (rng.random_range(0..8), "")),
+        );
+
+        let expected = a.keys().collect::<BTreeSet<_>>() == b.keys().collect::<BTreeSet<_>>();
+        assert_eq!(a.same_keys(&b), expected);
+        assert_eq!(b.same_keys(&a), expected);
+        assert!(a.same_keys(&a));
     }
 
-    let min_key = *sgm.first_key().unwrap();
-    let max_key = *sgm.last_key().unwrap();
+    // Same length, different keys
+    let a = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, ()), (2, ())]);
+    let b = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 'x'), (3, 'y')]);
+    assert!(!a.same_keys(&b));
+    assert!(
+        SgMap::<u8, (), DEFAULT_CAPACITY>::new()
+            .same_keys(&SgMap::<u8, u64, DEFAULT_CAPACITY>::new())
+    );
+}
 
-    let mut last_key_opt = None;
-    for (key, val) in sgm.iter_mut() {
-        *val += 25;
-        if let Some(last_key) = last_key_opt {
-            assert!(key >= last_key);
-        }
-        last_key_opt = Some(key);
+#[test]
+fn test_map_get_or() {
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([
+        (1, String::from("a")),
+        (3, String::from("c")),
+    ]);
+    let default = String::from("default");
+
+    for k in 0..5 {
+        let expected = sgm.get(&k).unwrap_or(&default);
+        assert_eq!(sgm.get_or(&k, &default), expected);
+        assert_eq!(&sgm.get_cloned_or(&k, default.clone()), expected);
     }
 
-    assert_eq!(min_key, *sgm.first_key().unwrap());
-    assert_eq!(max_key, *sgm.last_key().unwrap());
-
-    let result_vec = sgm.into_iter().collect::<Vec<(isize, isize)>>();
-    assert!(result_vec.as_slice().windows(2).all(|w| w[0].0 <= w[1].0));
-    assert!(result_vec.iter().all(|(_, v)| *v == 25));
+    // Borrowed key forms
+    let sgm = SgMap::<String, u8, DEFAULT_CAPACITY>::from_iter([(String::from("x"), 1)]);
+    assert_eq!(sgm.get_or("x", &0), &1);
+    assert_eq!(sgm.get_cloned_or("y", 0), 0);
+    assert_eq!(sgm.len(), 1);
 }
 
 #[test]
@@ -236,6 +1883,135 @@ fn test_map_append() {
     );
 }
 
+#[test]
+fn test_map_entry_ref() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static KEY_CONSTRUCTIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+    struct OwnedKey(String);
+
+    impl std::borrow::Borrow<str> for OwnedKey {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl From<&str> for OwnedKey {
+        fn from(s: &str) -> Self {
+            KEY_CONSTRUCTIONS.with(|c| c.set(c.get() + 1));
+            OwnedKey(s.to_string())
+        }
+    }
+
+    let mut map = SgMap::<OwnedKey, usize, DEFAULT_CAPACITY>::new();
+    let words = ["a", "b", "a", "c", "a", "b", "d", "a"];
+
+    for w in words {
+        *map.entry_ref(w).or_insert(0) += 1;
+    }
+
+    // Owned keys only built for vacant inserts
+    assert_eq!(KEY_CONSTRUCTIONS.with(|c| c.get()), 4);
+    assert_eq!(map.len(), 4);
+    assert_eq!(map["a"], 4);
+    assert_eq!(map["b"], 2);
+    assert_eq!(map["c"], 1);
+    assert_eq!(map["d"], 1);
+
+    assert_eq!(map.entry_ref("a").key(), "a");
+    assert_eq!(map.entry_ref("z").key(), "z");
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn test_map_entry_inspection() {
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    for (k, v) in [(3, "c"), (1, "a"), (4, "d"), (1, "b"), (5, "e"), (9, "f")] {
+        let entry = sgm.entry(k);
+        let expected = btm.get(&k);
+        assert_eq!(entry.is_occupied(), expected.is_some());
+        assert_eq!(entry.is_vacant(), expected.is_none());
+        assert_eq!(entry.get(), expected);
+
+        // Inspection doesn't consume or modify the entry
+        match entry.is_vacant() {
+            true => assert_eq!(entry.or_insert(v), &v),
+            false => assert_ne!(entry.or_insert(v), &v),
+        }
+        btm.entry(k).or_insert(v);
+    }
+
+    assert!(sgm.into_iter().eq(btm));
+}
+
+#[test]
+fn test_map_clone_panic_safety() {
+    use std::cell::Cell;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    thread_local! {
+        static LIVE: Cell<isize> = const { Cell::new(0) };
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Tracked(usize);
+
+    impl Tracked {
+        fn new(val: usize) -> Self {
+            LIVE.with(|l| l.set(l.get() + 1));
+            Tracked(val)
+        }
+    }
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            let cnt = CLONES.with(|c| {
+                c.set(c.get() + 1);
+                c.get()
+            });
+            if cnt == 3 {
+                panic!("Tracked clone #3");
+            }
+            Tracked::new(self.0)
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            LIVE.with(|l| l.set(l.get() - 1));
+        }
+    }
+
+    let sgm: SgMap<_, _, DEFAULT_CAPACITY> = (0..5).map(|i| (i, Tracked::new(i))).collect();
+    assert_eq!(LIVE.with(|l| l.get()), 5);
+
+    // Clone panics on 3rd value, the 2 already cloned are dropped
+    assert!(catch_unwind(AssertUnwindSafe(|| sgm.clone())).is_err());
+    assert_eq!(CLONES.with(|c| c.get()), 3);
+    assert_eq!(LIVE.with(|l| l.get()), 5);
+
+    // Original intact, subsequent clones succeed
+    assert!(
+        sgm.iter()
+            .map(|(k, v)| (*k, v.0))
+            .eq((0..5).map(|i| (i, i)))
+    );
+    let sgm_2 = sgm.try_clone().unwrap();
+    assert_eq!(sgm_2, sgm);
+    assert_eq!(LIVE.with(|l| l.get()), 10);
+
+    drop(sgm);
+    drop(sgm_2);
+    assert_eq!(LIVE.with(|l| l.get()), 0);
+}
+
 // Fallible APIs -------------------------------------------------------------------------------------------------------
 
 #[test]
@@ -295,6 +2071,145 @@ fn test_map_append_fallible() {
     );
 }
 
+#[test]
+fn test_map_append_from() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut small = SgMap::<u32, u32, 8>::new();
+        let mut big = SgMap::<u32, u32, 32>::new();
+        let mut btm = BTreeMap::new();
+
+        for _ in 0..rng.random_range(0..=8) {
+            let k = rng.random_range(0..32);
+            small.insert(k, 0);
+            btm.insert(k, 0);
+        }
+        for _ in 0..rng.random_range(0..=16) {
+            let k = rng.random_range(0..32);
+            big.insert(k, 1);
+        }
+
+        let (small_before, big_before) = (small.clone(), big.clone());
+        let union_len = small.len() + big.keys().filter(|k| !small.contains_key(k)).count();
+
+        if union_len <= small.capacity() {
+            assert_eq!(small.append_from(&mut big), Ok(()));
+            btm.extend(big_before);
+            assert!(big.is_empty());
+            assert!(small.into_iter().eq(btm));
+        } else {
+            // All-or-nothing: both sides left unchanged
+            assert_eq!(
+                small.append_from(&mut big),
+                Err(SgError::StackCapacityExceeded)
+            );
+            assert_eq!(small, small_before);
+            assert_eq!(big, big_before);
+        }
+    }
+
+    // Filling exactly to capacity, with overlap, succeeds
+    let mut a = SgMap::<_, _, 3>::from_iter([(1, "a"), (2, "b")]);
+    let mut b = SgMap::<_, _, 6>::from_iter([(1, "c"), (2, "d"), (3, "e")]);
+    assert!(a.append_from(&mut b).is_ok());
+    assert!(a.is_full());
+    assert!(a.into_iter().eq([(1, "c"), (2, "d"), (3, "e")]));
+}
+
+#[test]
+fn test_map_append_reporting() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut a = SgMap::<u8, u8, DEFAULT_CAPACITY>::new();
+        let mut b = SgMap::<u8, u8, DEFAULT_CAPACITY>::new();
+        for _ in 0..rng.random_range(0..DEFAULT_CAPACITY) {
+            a.insert(rng.random_range(0..16), 0);
+        }
+        for _ in 0..rng.random_range(0..DEFAULT_CAPACITY) {
+            b.insert(rng.random_range(0..16), 1);
+        }
+
+        let (a_before, b_before) = (a.clone(), b.clone());
+        let expected: Vec<u8> = b.keys().filter(|k| a.contains_key(k)).copied().collect();
+
+        match a.append_reporting(&mut b) {
+            Ok(conflicts) => {
+                assert_eq!(conflicts.as_slice(), expected.as_slice());
+                assert!(b.is_empty());
+                assert_eq!(a.len(), a_before.len() + b_before.len() - expected.len());
+                assert!(b_before.iter().all(|(k, v)| a.get(k) == Some(v)));
+            }
+            Err(err) => {
+                assert_eq!(err, SgError::StackCapacityExceeded);
+                assert!(a_before.len() + b_before.len() - expected.len() > DEFAULT_CAPACITY);
+                assert_eq!(a, a_before);
+                assert_eq!(b, b_before);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_map_entry_fallible() {
+    let mut sgm = SgMap::<_, _, 3>::new();
+    for k in 0..3 {
+        assert_eq!(sgm.entry(k).or_try_insert(k * 10), Ok(&mut (k * 10)));
+    }
+    assert!(sgm.is_full());
+
+    // Occupied entries still succeed when full
+    *sgm.entry(1).or_try_insert(0).unwrap() += 1;
+    assert_eq!(sgm[&1], 11);
+
+    // Vacant entries fail without modifying the map
+    assert_eq!(
+        sgm.entry(3).or_try_insert(30),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert!(sgm.keys().eq([0, 1, 2].iter()));
+
+    assert_eq!(sgm.pop_first(), Some((0, 0)));
+    assert_eq!(sgm.entry(3).or_try_insert(30), Ok(&mut 30));
+    assert!(sgm.keys().eq([1, 2, 3].iter()));
+}
+
+#[test]
+fn test_map_resize() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<_, _, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    while sgm.len() < DEFAULT_CAPACITY {
+        let k: u16 = rng.random();
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+
+    // Too small
+    let (sgm, err) = sgm.resize::<{ DEFAULT_CAPACITY - 1 }>().unwrap_err();
+    assert_eq!(err, SgError::StackCapacityExceeded);
+    assert!(sgm.iter().eq(btm.iter()));
+
+    // Grow, then keep inserting
+    let mut sgm = sgm.resize::<{ DEFAULT_CAPACITY * 2 }>().unwrap();
+    assert!(sgm.iter().eq(btm.iter()));
+    while sgm.len() < sgm.capacity() {
+        let k: u16 = rng.random();
+        sgm.insert(k, k);
+        btm.insert(k, k);
+    }
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.first_key_value(), btm.first_key_value());
+    assert_eq!(sgm.last_key_value(), btm.last_key_value());
+
+    // Same capacity
+    let sgm = sgm.resize::<{ DEFAULT_CAPACITY * 2 }>().unwrap();
+    assert!(sgm.is_full());
+    assert!(sgm.into_iter().eq(btm.into_iter()));
+}
+
 #[should_panic]
 #[test]
 fn test_map_insert_panic() {
@@ -308,6 +2223,212 @@ fn test_map_insert_panic() {
     a.insert(4, "4"); // panic
 }
 
+#[test]
+fn test_map_zero_capacity() {
+    let mut sgm = SgMap::<u32, u32, 0>::new();
+    assert_eq!(sgm.capacity(), 0);
+    assert_eq!(sgm.len(), 0);
+    assert!(sgm.is_empty());
+    assert!(sgm.is_full());
+    assert_eq!(sgm.iter().next(), None);
+    assert_eq!(sgm.iter_mut().next(), None);
+    assert_eq!(sgm.range(..).next(), None);
+    assert_eq!(sgm.first_key_value(), None);
+    assert_eq!(sgm.pop_first(), None);
+    assert_eq!(sgm.pop_last(), None);
+    assert_eq!(sgm.get(&0), None);
+    assert_eq!(sgm.remove(&0), None);
+    assert_eq!(format!("{:?}", sgm), "{}");
+
+    // Fallible paths reject every new key
+    assert_eq!(sgm.try_insert(1, 1), Err(SgError::StackCapacityExceeded));
+    assert_eq!(
+        sgm.try_extend([(1, 1)].into_iter()),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert!(sgm.entry(1).or_try_insert(1).is_err());
+    assert_eq!(
+        sgm.append_from(&mut SgMap::<_, _, 1>::from_iter([(1, 1)])),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    // Empty merges are fine
+    assert!(sgm.append_from(&mut SgMap::<u32, u32, 1>::new()).is_ok());
+    sgm.append(&mut SgMap::new());
+    sgm.retain(|_, _| false);
+    sgm.clear();
+    assert!(sgm.is_empty());
+    assert_eq!(sgm.into_iter().next(), None);
+}
+
+#[should_panic(expected = "Stack-storage capacity exceeded!")]
+#[test]
+fn test_map_zero_capacity_insert_panic() {
+    let mut sgm = SgMap::<u32, u32, 0>::new();
+    sgm.insert(1, 1);
+}
+
+#[test]
+fn test_map_content_hash() {
+    let mut rng = rand::rng();
+    let mut entries: Vec<(u32, u64)> = (0..DEFAULT_CAPACITY)
+        .map(|_| (rng.random(), rng.random()))
+        .collect();
+
+    // Independently built: different insertion order, removals, and capacity
+    let sgm_1 = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter(entries.clone());
+    entries.reverse();
+    let mut sgm_2 = SgMap::<_, _, { DEFAULT_CAPACITY * 2 }>::new();
+    sgm_2.extend(entries.iter().map(|(k, _)| (*k, 0)));
+    sgm_2.extend(entries.iter().rev().cloned());
+    sgm_2.insert(u32::MAX, 0);
+    sgm_2.remove(&u32::MAX);
+
+    assert!(sgm_1.iter().eq(sgm_2.iter()));
+    assert_eq!(sgm_1.content_hash(), sgm_2.content_hash());
+    assert_eq!(sgm_1.content_hash(), sgm_1.clone().content_hash());
+
+    // Any change in keys or values is detected
+    let mut sgm_3 = sgm_1.clone();
+    let (k, v) = sgm_3.pop_first().unwrap();
+    assert_ne!(sgm_3.content_hash(), sgm_1.content_hash());
+    sgm_3.insert(k, v.wrapping_add(1));
+    assert_ne!(sgm_3.content_hash(), sgm_1.content_hash());
+    sgm_3.insert(k, v);
+    assert_eq!(sgm_3.content_hash(), sgm_1.content_hash());
+
+    // Empty maps agree regardless of capacity
+    assert_eq!(
+        SgMap::<u32, u64, 1>::new().content_hash(),
+        SgMap::<u32, u64, DEFAULT_CAPACITY>::new().content_hash()
+    );
+}
+
+#[test]
+fn test_map_replace_all() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, 32>::new();
+    let alpha = Alpha::from_num(3) / Alpha::from_num(4);
+    sgm.set_rebal_param(alpha).unwrap();
+
+    for _ in 0..50 {
+        let before: Vec<(u32, u32)> = sgm.clone().into_iter().collect();
+        let refresh: Vec<(u32, u32)> = (0..rng.random_range(0..48))
+            .map(|_| (rng.random_range(0..64), rng.random()))
+            .collect();
+
+        let expected: BTreeMap<u32, u32> = refresh.iter().cloned().collect();
+        match sgm.replace_all(refresh) {
+            Ok(()) => {
+                assert!(expected.len() <= 32);
+                assert!(sgm.clone().into_iter().eq(expected));
+            }
+            Err(err) => {
+                assert_eq!(err, SgError::StackCapacityExceeded);
+                assert!(expected.len() > 32);
+                assert!(sgm.clone().into_iter().eq(before));
+            }
+        }
+
+        assert_eq!(sgm.rebal_param(), alpha);
+    }
+
+    // Empty refresh clears
+    sgm.replace_all([]).unwrap();
+    assert!(sgm.is_empty());
+}
+
+#[test]
+fn test_map_from_sorted_slices() {
+    let keys: Vec<u32> = (0..64).map(|i| i * 3).collect();
+    let vals: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+
+    let sgm = SgMap::<_, _, 64>::from_sorted_slices(&keys, &vals).unwrap();
+    let btm: BTreeMap<_, _> = keys.iter().cloned().zip(vals.iter().cloned()).collect();
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.debug_stats().height, 7);
+
+    // Empty
+    assert!(
+        SgMap::<u32, String, 64>::from_sorted_slices(&[], &[])
+            .unwrap()
+            .is_empty()
+    );
+
+    // Length mismatch
+    assert_eq!(
+        SgMap::<_, _, 64>::from_sorted_slices(&keys, &vals[1..]),
+        Err(SgError::SliceLengthMismatch)
+    );
+
+    // Capacity
+    assert_eq!(
+        SgMap::<_, _, 63>::from_sorted_slices(&keys, &vals),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    // Duplicate and out-of-order keys
+    assert_eq!(
+        SgMap::<_, _, 64>::from_sorted_slices(&[1, 2, 2], &["a", "b", "c"]),
+        Err(SgError::KeysNotSorted)
+    );
+    assert_eq!(
+        SgMap::<_, _, 64>::from_sorted_slices(&[1, 3, 2], &["a", "b", "c"]),
+        Err(SgError::KeysNotSorted)
+    );
+}
+
+#[test]
+fn test_map_extend_sorted() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut sgm: SgMap<u8, u16, 48> = (0..rng.random_range(0..24))
+            .map(|_| (rng.random_range(0..64), rng.random()))
+            .collect();
+        let mut btm: BTreeMap<u8, u16> = sgm.clone().into_iter().collect();
+
+        let batch: Vec<(u8, u16)> = (0..rng.random_range(0..24))
+            .map(|_| (rng.random_range(0..64), rng.random()))
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .collect();
+
+        assert_eq!(sgm.extend_sorted(&batch), Ok(()));
+        btm.extend(batch.iter().copied());
+        assert!(sgm.iter().eq(btm.iter()));
+
+        // Still a valid tree
+        let (k, v) = (rng.random_range(64..128), rng.random());
+        if sgm.len() < sgm.capacity() {
+            sgm.insert(k, v);
+            btm.insert(k, v);
+        }
+        assert!(sgm.iter().eq(btm.iter()));
+    }
+
+    // Errors leave the map unmodified
+    let mut sgm = SgMap::<_, _, 4>::from_iter([(1, 'a'), (3, 'c')]);
+    let before = sgm.clone();
+    assert_eq!(
+        sgm.extend_sorted(&[(2, 'b'), (2, 'B')]),
+        Err(SgError::KeysNotSorted)
+    );
+    assert_eq!(
+        sgm.extend_sorted(&[(4, 'd'), (2, 'b')]),
+        Err(SgError::KeysNotSorted)
+    );
+    assert_eq!(
+        sgm.extend_sorted(&[(0, 'z'), (2, 'b'), (4, 'd')]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(sgm, before);
+
+    // Overlap counts once toward capacity
+    assert_eq!(sgm.extend_sorted(&[(1, 'A'), (2, 'b'), (4, 'd')]), Ok(()));
+    assert!(sgm.into_iter().eq([(1, 'A'), (2, 'b'), (3, 'c'), (4, 'd')]));
+}
+
 // Range APIs ----------------------------------------------------------------------------------------------------------
 
 #[test]
@@ -343,6 +2464,97 @@ fn test_map_range_mut() {
     assert_eq!(map["e"], 10);
 }
 
+#[test]
+fn test_map_range_bounds_resolved() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgm: SgMap<u8, (), 32> = (0..32).map(|_| (rng.random(), ())).collect();
+        let btm: BTreeMap<u8, ()> = sgm.clone().into_iter().collect();
+
+        let a = rng.random::<u8>();
+        let b = rng.random_range(a..=u8::MAX);
+        let key_at = |slot| {
+            sgm.nodes_in_order()
+                .find(|n| n.arena_idx() == slot)
+                .map(|n| (n.key(), n.val()))
+        };
+        for (resolved, expected) in [
+            (sgm.range_bounds_resolved(a..b), btm.range(a..b)),
+            (sgm.range_bounds_resolved(a..=b), btm.range(a..=b)),
+            (sgm.range_bounds_resolved(..b), btm.range(..b)),
+            (sgm.range_bounds_resolved(a..), btm.range(a..)),
+        ] {
+            let expected: Vec<_> = expected.collect();
+            let (len, slots) = resolved;
+            assert_eq!(len, expected.len());
+            match slots {
+                Some((first, last)) => {
+                    assert_eq!(key_at(first), expected.first().copied());
+                    assert_eq!(key_at(last), expected.last().copied());
+                }
+                None => assert!(expected.is_empty()),
+            }
+        }
+    }
+}
+
+#[should_panic(expected = "range start is greater than range end")]
+#[test]
+fn test_map_range_bounds_resolved_panic() {
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    sgm.range_bounds_resolved((Included(&2), Included(&1)));
+}
+
+#[should_panic(expected = "range start and end are equal and excluded")]
+#[test]
+fn test_map_range_bounds_resolved_panic_excluded() {
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    sgm.range_bounds_resolved((Excluded(&1), Excluded(&1)));
+}
+
+#[test]
+fn test_map_subrange() {
+    let mut rng = rand::rng();
+    let alpha = Alpha::from_num(3) / Alpha::from_num(4);
+
+    for _ in 0..100 {
+        let mut sgm: SgMap<u8, u32, 32> = (0..32).map(|_| (rng.random(), rng.random())).collect();
+        sgm.set_rebal_param(alpha).unwrap();
+        let before = sgm.clone();
+
+        let a = rng.random::<u8>();
+        let b = rng.random_range(a..=u8::MAX);
+
+        for (sub, expected) in [
+            (sgm.subrange(a..b), sgm.range(a..b)),
+            (sgm.subrange(a..=b), sgm.range(a..=b)),
+            (
+                sgm.subrange((Excluded(a), Included(b))),
+                sgm.range((Excluded(a), Included(b))),
+            ),
+            (sgm.subrange(..), sgm.range(..)),
+        ] {
+            let sub = sub.unwrap();
+            assert!(sub.iter().eq(expected));
+            assert_eq!(sub.rebal_param(), alpha);
+
+            // Built balanced
+            let min_height = usize::BITS - sub.len().leading_zeros();
+            assert_eq!(sub.debug_stats().height as u32, min_height);
+        }
+
+        assert_eq!(sgm, before);
+    }
+}
+
+#[should_panic(expected = "range start and end are equal and excluded")]
+#[test]
+fn test_map_subrange_panic() {
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::from_iter([(1, 1), (2, 2)]);
+    let _ = sgm.subrange((Excluded(&1), Excluded(&1)));
+}
+
 #[should_panic(expected = "range start is greater than range end in BTreeMap")]
 #[test]
 fn test_btree_map_range_panic_1() {
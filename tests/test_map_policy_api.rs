@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use escapegoat::policy::{Ignore, Panic};
+use escapegoat::{SgMap, SgMapPolicy};
+
+use rand::Rng;
+
+const DEFAULT_CAPACITY: usize = 64;
+
+// Normal APIs ---------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_policy_ignore_matches_btree() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMapPolicy::<u32, u32, DEFAULT_CAPACITY, Ignore>::new();
+    let mut btm = BTreeMap::new();
+
+    for _ in 0..2_000 {
+        let (k, v) = (rng.random_range(0..128), rng.random());
+        match rng.random_range(0..4) {
+            0 => assert_eq!(sgm.remove(&k), btm.remove(&k)),
+            _ => {
+                let fits = btm.len() < DEFAULT_CAPACITY || btm.contains_key(&k);
+                let expected = match fits {
+                    true => (btm.insert(k, v), true),
+                    false => (None, false),
+                };
+                assert_eq!(sgm.insert(k, v), expected);
+            }
+        }
+
+        assert_eq!(sgm.len(), btm.len());
+    }
+
+    assert!(sgm.iter().eq(btm.iter()));
+    assert!(sgm.into_iter().eq(btm.into_iter()));
+}
+
+#[test]
+fn test_map_policy_ignore_extend() {
+    let sgm = SgMapPolicy::<usize, usize, DEFAULT_CAPACITY, Ignore>::from_iter(
+        (0..(DEFAULT_CAPACITY * 2)).rev().map(|i| (i, i)),
+    );
+
+    // Overflowing pairs dropped, the first `N` kept
+    assert!(sgm.is_full());
+    assert_eq!(
+        sgm.first_key_value(),
+        Some((&DEFAULT_CAPACITY, &DEFAULT_CAPACITY))
+    );
+
+    let inner: SgMap<_, _, DEFAULT_CAPACITY> = sgm.into_inner();
+    assert!(
+        inner
+            .keys()
+            .copied()
+            .eq(DEFAULT_CAPACITY..(DEFAULT_CAPACITY * 2))
+    );
+}
+
+#[test]
+fn test_map_policy_panic_default() {
+    let mut sgm: SgMapPolicy<usize, usize, DEFAULT_CAPACITY> = SgMapPolicy::new();
+    for i in 0..DEFAULT_CAPACITY {
+        assert_eq!(sgm.insert(i, i), (None, true));
+    }
+    assert_eq!(sgm.insert(0, 1), (Some(0), true));
+
+    let explicit: SgMapPolicy<_, _, DEFAULT_CAPACITY, Panic> = sgm.clone().into_inner().into();
+    assert!(explicit.iter().eq(sgm.iter()));
+}
+
+#[test]
+#[should_panic(expected = "Stack-storage capacity exceeded!")]
+fn test_map_policy_panic_on_overflow() {
+    let mut sgm = SgMapPolicy::<usize, usize, DEFAULT_CAPACITY, Panic>::new();
+    for i in 0..=DEFAULT_CAPACITY {
+        sgm.insert(i, i);
+    }
+}
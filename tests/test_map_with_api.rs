@@ -0,0 +1,109 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+use std::ops::Bound::{Excluded, Included};
+
+use escapegoat::{Comparator, SgError, SgMapWith};
+
+use rand::Rng;
+
+const DEFAULT_CAPACITY: usize = 64;
+
+struct Descending;
+
+impl Comparator<u32> for Descending {
+    fn compare(a: &u32, b: &u32) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+struct TotalF64;
+
+impl Comparator<f64> for TotalF64 {
+    fn compare(a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+// Normal APIs ---------------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_map_with_reverse_matches_btree() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMapWith::<u32, u32, DEFAULT_CAPACITY, Descending>::new();
+    let mut btm = BTreeMap::new();
+
+    for _ in 0..1_000 {
+        let k = rng.random_range(0..128);
+        let v = rng.random();
+        match rng.random_range(0..3) {
+            0 if !sgm.is_full() || sgm.contains_key(&k) => {
+                assert_eq!(sgm.insert(k, v), btm.insert(Reverse(k), v));
+            }
+            1 => assert_eq!(sgm.remove(&k), btm.remove(&Reverse(k))),
+            _ => assert_eq!(sgm.get(&k), btm.get(&Reverse(k))),
+        }
+
+        assert_eq!(sgm.len(), btm.len());
+        assert!(sgm.iter().eq(btm.iter().map(|(Reverse(k), v)| (k, v))));
+    }
+
+    let lo = 100;
+    let hi = 20;
+    assert!(
+        sgm.range((Included(&lo), Excluded(&hi))).eq(btm
+            .range(Reverse(lo)..Reverse(hi))
+            .map(|(Reverse(k), v)| (k, v)))
+    );
+    assert!(
+        sgm.range(..=hi).rev().eq(btm
+            .range(..=Reverse(hi))
+            .rev()
+            .map(|(Reverse(k), v)| (k, v)))
+    );
+
+    assert_eq!(
+        sgm.pop_first(),
+        btm.pop_first().map(|(Reverse(k), v)| (k, v))
+    );
+    assert_eq!(sgm.pop_last(), btm.pop_last().map(|(Reverse(k), v)| (k, v)));
+    assert!(
+        sgm.into_iter()
+            .eq(btm.into_iter().map(|(Reverse(k), v)| (k, v)))
+    );
+}
+
+#[test]
+fn test_map_with_non_ord_keys() {
+    let mut sgm: SgMapWith<f64, &str, 8, TotalF64> = [
+        (0.5, "half"),
+        (-0.0, "neg zero"),
+        (0.0, "zero"),
+        (f64::NAN, "nan"),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(sgm.len(), 4);
+    assert_eq!(sgm.get(&0.0), Some(&"zero"));
+    assert_eq!(sgm.get(&-0.0), Some(&"neg zero"));
+    assert_eq!(sgm.last_key_value().map(|(_, v)| *v), Some("nan"));
+    assert_eq!(
+        format!("{:?}", sgm.range(0.0..1.0).collect::<Vec<_>>()),
+        r#"[(0.0, "zero"), (0.5, "half")]"#
+    );
+
+    sgm.retain(|k, _| !k.is_nan());
+    assert_eq!(sgm.remove_entry(&-0.0), Some((-0.0, "neg zero")));
+    assert!(sgm.keys().eq([0.0, 0.5].iter()));
+    assert_eq!(format!("{:?}", sgm), r#"{0.0: "zero", 0.5: "half"}"#);
+}
+
+#[test]
+fn test_map_with_try_insert_full() {
+    let mut sgm = SgMapWith::<u32, (), 2, Descending>::new();
+    assert_eq!(sgm.try_insert(1, ()), Ok(None));
+    assert_eq!(sgm.try_insert(2, ()), Ok(None));
+    assert_eq!(sgm.try_insert(2, ()), Ok(Some(())));
+    assert_eq!(sgm.try_insert(3, ()), Err(SgError::StackCapacityExceeded));
+    assert!(sgm.keys().eq([2, 1].iter()));
+}
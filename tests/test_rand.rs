@@ -0,0 +1,54 @@
+#![cfg(feature = "rand")]
+
+use escapegoat::SgMap;
+
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+#[test]
+fn test_map_sample() {
+    let mut rng = SmallRng::seed_from_u64(0x5347);
+    let sgm = SgMap::<usize, usize, DEFAULT_CAPACITY>::from_iter((0..10).map(|i| (i, i * 10)));
+
+    // Distinct, sorted, and valid entries
+    for k in 0..=sgm.len() {
+        let picks = sgm.sample(&mut rng, k);
+        assert_eq!(picks.len(), k);
+        assert!(picks.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(picks.iter().all(|(k, v)| sgm.get(k) == Some(v)));
+    }
+
+    // Oversized request returns everything, in order
+    assert!(
+        sgm.sample(&mut rng, sgm.len() + 1)
+            .into_iter()
+            .eq(sgm.iter())
+    );
+    assert!(sgm.sample(&mut rng, usize::MAX).into_iter().eq(sgm.iter()));
+
+    // Empty map
+    let empty = SgMap::<usize, usize, DEFAULT_CAPACITY>::new();
+    assert!(empty.sample(&mut rng, 3).is_empty());
+}
+
+#[test]
+fn test_map_sample_uniform() {
+    let mut rng = SmallRng::seed_from_u64(0x4756);
+    let sgm = SgMap::<usize, (), DEFAULT_CAPACITY>::from_iter((0..10).map(|i| (i, ())));
+
+    // Each key should be picked ~30% of the time for k = 3
+    let rounds = 20_000;
+    let mut hits = [0_usize; 10];
+    for _ in 0..rounds {
+        for (k, _) in sgm.sample(&mut rng, 3) {
+            hits[*k] += 1;
+        }
+    }
+
+    let expected = rounds * 3 / 10;
+    for cnt in hits {
+        assert!(cnt.abs_diff(expected) < expected / 10, "{hits:?}");
+    }
+}
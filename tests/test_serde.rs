@@ -0,0 +1,112 @@
+#![cfg(feature = "serde")]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use escapegoat::{SgMap, SgSet, SortedSeq};
+
+use rand::Rng;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+#[test]
+fn test_map_serde_round_trip() {
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u64, DEFAULT_CAPACITY>::new();
+    let mut btm = BTreeMap::new();
+
+    while sgm.len() < DEFAULT_CAPACITY {
+        let (k, v) = (rng.random(), rng.random());
+        sgm.insert(k, v);
+        btm.insert(k, v);
+    }
+
+    let sgm_json = serde_json::to_string(&sgm).unwrap();
+    let btm_json = serde_json::to_string(&btm).unwrap();
+    assert_eq!(sgm_json, btm_json);
+
+    let sgm_2: SgMap<u32, u64, DEFAULT_CAPACITY> = serde_json::from_str(&sgm_json).unwrap();
+    assert_eq!(sgm_2, sgm);
+
+    let SortedSeq(sgm_3) =
+        serde_json::from_str::<SortedSeq<SgMap<u32, u64, DEFAULT_CAPACITY>>>(&sgm_json).unwrap();
+    assert_eq!(sgm_3, sgm);
+    assert!(sgm_3.into_iter().eq(btm.into_iter()));
+
+    // Empty
+    let empty = SgMap::<u32, u64, DEFAULT_CAPACITY>::new();
+    let empty_json = serde_json::to_string(&empty).unwrap();
+    assert_eq!(empty_json, "{}");
+    assert!(
+        serde_json::from_str::<SortedSeq<SgMap<u32, u64, DEFAULT_CAPACITY>>>(&empty_json)
+            .unwrap()
+            .into_inner()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_set_serde_round_trip() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<u32, DEFAULT_CAPACITY>::new();
+    let mut bts = BTreeSet::new();
+
+    while sgs.len() < DEFAULT_CAPACITY {
+        let e = rng.random();
+        sgs.insert(e);
+        bts.insert(e);
+    }
+
+    let sgs_json = serde_json::to_string(&sgs).unwrap();
+    let bts_json = serde_json::to_string(&bts).unwrap();
+    assert_eq!(sgs_json, bts_json);
+
+    let sgs_2: SgSet<u32, DEFAULT_CAPACITY> = serde_json::from_str(&sgs_json).unwrap();
+    assert_eq!(sgs_2, sgs);
+
+    let SortedSeq(sgs_3) =
+        serde_json::from_str::<SortedSeq<SgSet<u32, DEFAULT_CAPACITY>>>(&sgs_json).unwrap();
+    assert_eq!(sgs_3, sgs);
+    assert!(sgs_3.into_iter().eq(bts.into_iter()));
+
+    // Serializing the wrapper is transparent
+    assert_eq!(serde_json::to_string(&SortedSeq(sgs)).unwrap(), bts_json);
+}
+
+#[test]
+fn test_serde_capacity_exceeded() {
+    let json = "[1,2,3,4,5]";
+    assert!(serde_json::from_str::<SgSet<u32, 5>>(json).is_ok());
+    assert!(serde_json::from_str::<SortedSeq<SgSet<u32, 5>>>(json).is_ok());
+
+    let err = serde_json::from_str::<SgSet<u32, 4>>(json).unwrap_err();
+    assert!(err.to_string().contains("capacity"));
+
+    let err = serde_json::from_str::<SortedSeq<SgSet<u32, 4>>>(json).unwrap_err();
+    assert!(err.to_string().contains("capacity"));
+
+    let json = r#"{"1":1,"2":2,"3":3}"#;
+    let err = serde_json::from_str::<SortedSeq<SgMap<u32, u32, 2>>>(json).unwrap_err();
+    assert!(err.to_string().contains("capacity"));
+}
+
+#[test]
+fn test_serde_wrong_order() {
+    // Plain deserialization accepts any order, last duplicate wins
+    let sgs: SgSet<u32, 10> = serde_json::from_str("[3,1,2,1]").unwrap();
+    assert!(sgs.into_iter().eq([1, 2, 3]));
+
+    let sgm: SgMap<u32, char, 10> = serde_json::from_str(r#"{"2":"b","1":"a","2":"c"}"#).unwrap();
+    assert!(sgm.into_iter().eq([(1, 'a'), (2, 'c')]));
+
+    // Sorted deserialization rejects out-of-order keys...
+    let err = serde_json::from_str::<SortedSeq<SgSet<u32, 10>>>("[1,3,2]").unwrap_err();
+    assert!(err.to_string().contains("ascending"));
+
+    let err = serde_json::from_str::<SortedSeq<SgMap<u32, char, 10>>>(r#"{"2":"b","1":"a"}"#)
+        .unwrap_err();
+    assert!(err.to_string().contains("ascending"));
+
+    // ...and duplicates
+    let err = serde_json::from_str::<SortedSeq<SgSet<u32, 10>>>("[1,2,2]").unwrap_err();
+    assert!(err.to_string().contains("ascending"));
+}
@@ -1,8 +1,10 @@
 use std::collections::BTreeSet;
 use std::iter::FromIterator;
-use std::ops::Bound::{Excluded, Included};
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
 
-use escapegoat::{SgError, SgSet, sgset};
+use escapegoat::{SgError, SgSet, sgset, sgset_from};
+
+use rand::Rng;
 
 const DEFAULT_CAPACITY: usize = 10;
 
@@ -92,7 +94,7 @@ fn test_basic_set_functionality() {
 #[test]
 fn test_set_from_iter() {
     let keys = vec![1, 10, 100];
-    let sgs = SgSet::<_, 3>::from_iter(keys.into_iter());
+    let sgs = SgSet::<_, 3>::from_iter(keys);
 
     assert!(sgs.len() == 3);
     assert_eq!(sgs.into_iter().collect::<Vec<usize>>(), vec![1, 10, 100]);
@@ -104,10 +106,30 @@ fn test_set_from_iter_panic() {
     let _: SgSet<usize, DEFAULT_CAPACITY> = SgSet::from_iter(0..(DEFAULT_CAPACITY + 1));
 }
 
+#[test]
+fn test_set_from_macro() {
+    // Overflow is an error, not a panic
+    assert_eq!(
+        sgset_from!(0..(DEFAULT_CAPACITY + 1), DEFAULT_CAPACITY),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    let set = sgset_from!(0..DEFAULT_CAPACITY, DEFAULT_CAPACITY).unwrap();
+    assert!(set.is_full());
+    assert!(set.into_iter().eq(0..DEFAULT_CAPACITY));
+
+    // Duplicates don't count toward capacity
+    let set = sgset_from!([1, 1, 2, 2, 3], 3).unwrap();
+    assert!(set.iter().eq(&[1, 2, 3]));
+
+    let set: SgSet<u8, 4> = sgset_from!(core::iter::empty(), 4).unwrap();
+    assert!(set.is_empty());
+}
+
 #[test]
 fn test_set_iter() {
     let keys = vec![1, 2, 3];
-    let sgs = SgSet::<_, 3>::from_iter(keys.into_iter());
+    let sgs = SgSet::<_, 3>::from_iter(keys);
     let mut sgs_iter = sgs.iter();
 
     assert_eq!(sgs_iter.next(), Some(&1));
@@ -227,6 +249,272 @@ fn test_set_is_disjoint() {
     assert!(!a.is_disjoint(&c));
 }
 
+#[test]
+fn test_set_relations_across_capacities() {
+    let mut rng = rand::rng();
+
+    for _ in 0..200 {
+        let small: SgSet<u8, 8> = (0..rng.random_range(0..=8))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let large: SgSet<u8, 32> = (0..rng.random_range(0..=32))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+
+        let small_ref: BTreeSet<u8> = small.iter().copied().collect();
+        let large_ref: BTreeSet<u8> = large.iter().copied().collect();
+
+        assert_eq!(small.is_subset_of(&large), small_ref.is_subset(&large_ref));
+        assert_eq!(large.is_subset_of(&small), large_ref.is_subset(&small_ref));
+        assert_eq!(
+            small.is_superset_of(&large),
+            small_ref.is_superset(&large_ref)
+        );
+        assert_eq!(
+            large.is_superset_of(&small),
+            large_ref.is_superset(&small_ref)
+        );
+        assert_eq!(
+            small.is_disjoint_from(&large),
+            small_ref.is_disjoint(&large_ref)
+        );
+        assert_eq!(
+            large.is_disjoint_from(&small),
+            large_ref.is_disjoint(&small_ref)
+        );
+    }
+}
+
+#[test]
+fn test_set_ord() {
+    let mut rng = rand::rng();
+    let mut sg_sets = Vec::new();
+    let mut bt_sets = Vec::new();
+
+    for _ in 0..64 {
+        let elems: Vec<u8> = (0..rng.random_range(0..5))
+            .map(|_| rng.random_range(0..4))
+            .collect();
+        sg_sets.push(SgSet::<_, DEFAULT_CAPACITY>::from_iter(elems.clone()));
+        bt_sets.push(BTreeSet::from_iter(elems));
+    }
+
+    // Lexicographic over sorted elements, a strict prefix orders first
+    assert!(SgSet::<u8, 4>::new() < SgSet::from_iter([0]));
+    assert!(SgSet::<u8, 4>::from_iter([1, 2]) < SgSet::from_iter([1, 2, 3]));
+    assert!(SgSet::<u8, 4>::from_iter([1, 3]) > SgSet::from_iter([1, 2, 3]));
+
+    for (sg_a, bt_a) in sg_sets.iter().zip(&bt_sets) {
+        for (sg_b, bt_b) in sg_sets.iter().zip(&bt_sets) {
+            assert_eq!(sg_a.cmp(sg_b), bt_a.cmp(bt_b));
+            assert_eq!(sg_a.partial_cmp(sg_b), bt_a.partial_cmp(bt_b));
+        }
+    }
+
+    sg_sets.sort();
+    bt_sets.sort();
+    assert!(
+        sg_sets
+            .iter()
+            .map(|s| s.iter().collect::<Vec<_>>())
+            .eq(bt_sets.iter().map(|s| s.iter().collect::<Vec<_>>()))
+    );
+}
+
+#[test]
+fn test_set_iter_rev() {
+    let sgs = SgSet::from([3, 4, 1, 2, 5, 6]);
+
+    assert!(sgs.iter().rev().eq([6, 5, 4, 3, 2, 1].iter()));
+    assert!(sgs.clone().into_iter().rev().eq([6, 5, 4, 3, 2, 1]));
+
+    let mut iter = sgs.iter();
+    assert_eq!(iter.next_back(), Some(&6));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&5));
+    assert!(iter.eq([2, 3, 4].iter()));
+}
+
+#[test]
+fn test_set_insert_if_absent() {
+    // Ordered by `id` only, so equal elements can still differ in `tag`
+    #[derive(Debug, Default, Clone, Copy)]
+    struct Tagged {
+        id: u8,
+        tag: char,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.id.cmp(&other.id)
+        }
+    }
+
+    let mut sgs = SgSet::<_, 2>::new();
+    assert!(sgs.insert_if_absent(Tagged { id: 1, tag: 'a' }));
+    assert!(!sgs.insert_if_absent(Tagged { id: 1, tag: 'b' }));
+    assert_eq!(sgs.first().unwrap().tag, 'a');
+
+    // Unlike `insert`, which overwrites
+    assert!(!sgs.insert(Tagged { id: 1, tag: 'b' }));
+    assert_eq!(sgs.first().unwrap().tag, 'b');
+
+    // Full, but present: no panic
+    assert!(sgs.insert_if_absent(Tagged { id: 2, tag: 'c' }));
+    assert!(!sgs.insert_if_absent(Tagged { id: 2, tag: 'd' }));
+    assert_eq!(sgs.len(), 2);
+}
+
+#[should_panic(expected = "Stack-storage capacity exceeded!")]
+#[test]
+fn test_set_insert_if_absent_panic() {
+    let mut sgs = SgSet::<_, 1>::from_iter([1]);
+    sgs.insert_if_absent(2);
+}
+
+#[test]
+fn test_set_from_slice() {
+    let mut rng = rand::rng();
+    let mut vals = Vec::new();
+
+    // Unsorted, with duplicates
+    for _ in 0..(DEFAULT_CAPACITY * 2) {
+        vals.push(rng.random_range(0..(DEFAULT_CAPACITY as u32)));
+    }
+
+    let mut expected = vals.clone();
+    expected.sort_unstable();
+    expected.dedup();
+
+    // Longer than capacity, but fits after dedup
+    let sgs = SgSet::<_, DEFAULT_CAPACITY>::from_slice(&vals).unwrap();
+    assert!(sgs.iter().eq(expected.iter()));
+
+    // Fits the scratch buffer
+    let sgs = SgSet::<_, DEFAULT_CAPACITY>::from_slice(&vals[..DEFAULT_CAPACITY]).unwrap();
+    assert!(sgs.iter().is_sorted());
+    assert!(vals[..DEFAULT_CAPACITY].iter().all(|v| sgs.contains(v)));
+
+    let empty: [u32; 0] = [];
+    assert!(
+        SgSet::<_, DEFAULT_CAPACITY>::from_slice(&empty)
+            .unwrap()
+            .is_empty()
+    );
+
+    assert_eq!(
+        SgSet::<_, 2>::from_slice(&[3, 1, 2]),
+        Err(SgError::StackCapacityExceeded)
+    );
+    assert_eq!(
+        SgSet::<_, 2>::from_slice(&[3, 1, 2, 1]),
+        Err(SgError::StackCapacityExceeded)
+    );
+}
+
+#[test]
+fn test_set_contains_all_any() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgs: SgSet<u8, 32> = (0..rng.random_range(0..32))
+            .map(|_| rng.random_range(0..48))
+            .collect();
+        let items: Vec<u8> = (0..rng.random_range(0..8))
+            .map(|_| rng.random_range(0..48))
+            .collect();
+
+        let all = items.iter().all(|i| sgs.contains(i));
+        let any = items.iter().any(|i| sgs.contains(i));
+        assert_eq!(sgs.contains_all(&items), all);
+        assert_eq!(sgs.contains_any(&items), any);
+
+        // Merge path, duplicates kept
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+        assert_eq!(sgs.contains_all_sorted(&sorted), all);
+        assert_eq!(sgs.contains_any_sorted(&sorted), any);
+
+        // Subset built from the set itself
+        let subset: Vec<u8> = sgs.iter().copied().step_by(2).collect();
+        assert!(sgs.contains_all(&subset));
+        assert!(sgs.contains_all_sorted(&subset));
+        assert_eq!(sgs.contains_any_sorted(&subset), !sgs.is_empty());
+    }
+
+    // Empty input
+    let sgs = SgSet::<_, DEFAULT_CAPACITY>::from_iter([1, 2, 3]);
+    let none: [i32; 0] = [];
+    assert!(sgs.contains_all(&none));
+    assert!(sgs.contains_all_sorted(&none));
+    assert!(!sgs.contains_any(&none));
+    assert!(!sgs.contains_any_sorted(&none));
+
+    // Borrowed forms
+    let names = SgSet::<String, DEFAULT_CAPACITY>::from_iter(["a".to_string(), "b".to_string()]);
+    assert!(names.contains_all(["b", "a"]));
+    assert!(names.contains_any_sorted(["a", "c"]));
+}
+
+#[test]
+fn test_set_count_where() {
+    // Same shapes as the benchmark datasets: random and sequential keys
+    fn check<const N: usize>(keys: impl Iterator<Item = usize>) {
+        let sgs = SgSet::<usize, N>::from_iter(keys);
+
+        for m in [1, 2, 3, 5] {
+            let pred = |k: &usize| (k / 3) % m == 0;
+            assert_eq!(
+                sgs.count_where(pred),
+                sgs.iter().filter(|k| pred(k)).count()
+            );
+        }
+
+        assert_eq!(sgs.count_where(|_| true), sgs.len());
+        assert_eq!(sgs.count_where(|_| false), 0);
+    }
+
+    let mut rng = rand::rng();
+    check::<100>((0..100).map(|_| rng.random_range(0..usize::MAX)));
+    check::<1_000>((0..1_000).map(|_| rng.random_range(0..usize::MAX)));
+    check::<100>(0..100);
+    check::<1_000>(0..1_000);
+
+    let empty = SgSet::<usize, DEFAULT_CAPACITY>::new();
+    assert_eq!(empty.count_where(|_| true), 0);
+}
+
+#[test]
+fn test_set_from_exact() {
+    let set = SgSet::from_exact([3, 4, 1, 2, 5, 6]);
+    assert_eq!(set.capacity(), 6);
+    assert_eq!(set.len(), 6);
+    assert_eq!(set, SgSet::from([1, 2, 3, 4, 5, 6]));
+
+    // Duplicates collapse, leaving spare capacity
+    let set = SgSet::from_exact([1, 1, 2]);
+    assert_eq!(set.capacity(), 3);
+    assert_eq!(set.len(), 2);
+    assert!(!set.is_full());
+
+    let empty = SgSet::<u8, 0>::from_exact([]);
+    assert!(empty.is_empty());
+}
+
 // Fallible APIs -------------------------------------------------------------------------------------------------------
 
 #[test]
@@ -286,6 +574,111 @@ fn test_set_append_fallible() {
     );
 }
 
+#[test]
+fn test_set_algebra_in_place() {
+    const N: usize = 16;
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let a_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let b_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let a = SgSet::<_, N>::from_iter(a_btree.iter().copied());
+        let b = SgSet::<_, N>::from_iter(b_btree.iter().copied());
+
+        let mut sgs = a.clone();
+        sgs.intersection_with(&b);
+        assert!(sgs.iter().eq(a_btree.intersection(&b_btree)));
+
+        let mut sgs = a.clone();
+        sgs.difference_with(&b);
+        assert!(sgs.iter().eq(a_btree.difference(&b_btree)));
+
+        let expected: BTreeSet<u8> = a_btree.union(&b_btree).copied().collect();
+        let mut sgs = a.clone();
+        match expected.len() <= N {
+            true => {
+                assert_eq!(sgs.union_with(&b), Ok(()));
+                assert!(sgs.iter().eq(expected.iter()));
+            }
+            false => {
+                assert_eq!(sgs.union_with(&b), Err(SgError::StackCapacityExceeded));
+                assert_eq!(sgs, a);
+            }
+        }
+
+        let expected: BTreeSet<u8> = a_btree.symmetric_difference(&b_btree).copied().collect();
+        let mut sgs = a.clone();
+        match expected.len() <= N {
+            true => {
+                assert_eq!(sgs.symmetric_difference_with(&b), Ok(()));
+                assert!(sgs.iter().eq(expected.iter()));
+            }
+            false => {
+                assert_eq!(
+                    sgs.symmetric_difference_with(&b),
+                    Err(SgError::StackCapacityExceeded)
+                );
+                assert_eq!(sgs, a);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_set_algebra_owned() {
+    const N: usize = 16;
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let a_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let b_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let a = SgSet::<_, N>::from_iter(a_btree.iter().copied());
+        let b = SgSet::<_, N>::from_iter(b_btree.iter().copied());
+
+        let sgs = a.intersection_set(&b).unwrap();
+        assert!(sgs.iter().eq(a_btree.intersection(&b_btree)));
+
+        let sgs = a.difference_set(&b).unwrap();
+        assert!(sgs.iter().eq(a_btree.difference(&b_btree)));
+
+        let expected: BTreeSet<u8> = a_btree.union(&b_btree).copied().collect();
+        match expected.len() <= N {
+            true => {
+                let mut sgs = a.union_set(&b).unwrap();
+                assert!(sgs.iter().eq(expected.iter()));
+                assert_eq!(sgs.first(), expected.first());
+                assert_eq!(sgs.last(), expected.last());
+                if !sgs.is_full() {
+                    assert!(sgs.try_insert(u8::MAX).is_ok());
+                }
+            }
+            false => assert_eq!(a.union_set(&b), Err(SgError::StackCapacityExceeded)),
+        }
+
+        let expected: BTreeSet<u8> = a_btree.symmetric_difference(&b_btree).copied().collect();
+        match expected.len() <= N {
+            true => assert!(
+                a.symmetric_difference_set(&b)
+                    .unwrap()
+                    .iter()
+                    .eq(expected.iter())
+            ),
+            false => assert_eq!(
+                a.symmetric_difference_set(&b),
+                Err(SgError::StackCapacityExceeded)
+            ),
+        }
+    }
+}
+
 #[should_panic]
 #[test]
 fn test_set_insert_panic() {
@@ -354,6 +747,81 @@ fn test_sg_set_range_panic_2() {
     let _bad_range = set.range((Excluded(&5), Excluded(&5)));
 }
 
+#[test]
+fn test_set_range_len() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let sgs: SgSet<u8, 32> = (0..rng.random_range(0..32))
+            .map(|_| rng.random_range(0..64))
+            .collect();
+        let bts: BTreeSet<u8> = sgs.iter().copied().collect();
+
+        let a = rng.random_range(0..70);
+        let b = rng.random_range(a..70);
+        let bounds = |v| [Included(v), Excluded(v), Unbounded];
+
+        // Every (Bound, Bound) combination the range iterator accepts
+        for start in bounds(a) {
+            for end in bounds(b) {
+                let range: (Bound<u8>, Bound<u8>) = (start, end);
+                if let (Excluded(s), Excluded(e)) = range
+                    && s == e
+                {
+                    continue;
+                }
+
+                assert_eq!(sgs.range_len(range), bts.range(range).count());
+                assert_eq!(sgs.range_len(range), sgs.range(range).count());
+            }
+        }
+    }
+
+    assert_eq!(SgSet::<u8, DEFAULT_CAPACITY>::new().range_len(..), 0);
+}
+
+#[should_panic(expected = "range start is greater than range end")]
+#[test]
+fn test_set_range_len_panic_1() {
+    let set = SgSet::<_, DEFAULT_CAPACITY>::from_iter([3, 5, 8]);
+    set.range_len((Included(&8), Included(&3)));
+}
+
+#[should_panic(expected = "range start and end are equal and excluded")]
+#[test]
+fn test_set_range_len_panic_2() {
+    let set = SgSet::<_, DEFAULT_CAPACITY>::from_iter([3, 5, 8]);
+    set.range_len((Excluded(&5), Excluded(&5)));
+}
+
+#[test]
+fn test_set_range_rev() {
+    let set = SgSet::from([1, 5, 3, 7, 9]);
+    let bts = BTreeSet::from([1, 5, 3, 7, 9]);
+
+    assert!(set.range_rev(3..8).eq(bts.range(3..8).rev()));
+    assert!(set.range_rev(..).eq(bts.iter().rev()));
+    assert!(
+        set.range_rev((Excluded(&1), Included(&9)))
+            .eq(bts.range((Excluded(&1), Included(&9))).rev())
+    );
+    assert_eq!(set.range_rev(10..).next(), None);
+}
+
+#[should_panic(expected = "range start is greater than range end")]
+#[test]
+fn test_sg_set_range_rev_panic_1() {
+    let set = SgSet::<usize, DEFAULT_CAPACITY>::from_iter([3, 5, 8]);
+    let _bad_range = set.range_rev((Included(&8), Included(&3)));
+}
+
+#[should_panic(expected = "range start and end are equal and excluded")]
+#[test]
+fn test_sg_set_range_rev_panic_2() {
+    let set = SgSet::<usize, DEFAULT_CAPACITY>::from_iter([3, 5, 8]);
+    let _bad_range = set.range_rev((Excluded(&5), Excluded(&5)));
+}
+
 #[test]
 fn test_set_macro() {
     // Mutable
@@ -393,3 +861,297 @@ fn test_set_macro_panic() {
         "d", // Capacity exceeded!
     };
 }
+
+#[test]
+fn test_set_gaps() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<u8, 64>::new();
+
+    while sgs.len() < sgs.capacity() {
+        sgs.insert(rng.random());
+    }
+
+    // Reconstruct the domain from gaps and elements, verifying gaps are maximal and disjoint
+    for (lo, hi) in [(0, u8::MAX), (10, 200), (100, 100), (37, 38)] {
+        let gaps: Vec<_> = sgs.gaps(lo..=hi).collect();
+        let mut covered = vec![false; 256];
+
+        for g in &gaps {
+            assert!(g.start() <= g.end());
+            if *g.start() > lo {
+                assert!(sgs.contains(&(g.start() - 1)));
+            }
+            if *g.end() < hi {
+                assert!(sgs.contains(&(g.end() + 1)));
+            }
+            for v in g.clone() {
+                assert!(!sgs.contains(&v));
+                assert!(!covered[v as usize]);
+                covered[v as usize] = true;
+            }
+        }
+
+        for v in lo..=hi {
+            assert_eq!(covered[v as usize], !sgs.contains(&v));
+        }
+    }
+
+    // Domain boundaries
+    let sgs = SgSet::<u8, 4>::from([0, 1, 254, 255]);
+    assert_eq!(sgs.gaps(0..=255).collect::<Vec<_>>(), vec![2..=253]);
+    assert_eq!(sgs.gaps(254..=255).count(), 0);
+
+    // Empty domain
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty_domain = 10..=5;
+    assert_eq!(sgs.gaps(empty_domain).count(), 0);
+
+    // Signed
+    let sgs = SgSet::<i32, 4>::from([-3, 0, 3, i32::MAX]);
+    assert_eq!(
+        sgs.gaps(-5..=5).collect::<Vec<_>>(),
+        vec![-5..=-4, -2..=-1, 1..=2, 4..=5]
+    );
+}
+
+#[test]
+fn test_set_longest_run() {
+    let mut rng = rand::rng();
+
+    for _ in 0..100 {
+        let mut sgs = SgSet::<u8, 64>::new();
+        let target_len = rng.random_range(1..=64);
+        while sgs.len() < target_len {
+            sgs.insert(rng.random_range(0..96));
+        }
+
+        // Reference: first maximal run by brute force
+        let vals: Vec<u8> = sgs.iter().copied().collect();
+        let (mut best, mut start) = (vals[0]..=vals[0], 0);
+        for i in 1..=vals.len() {
+            if i == vals.len() || vals[i] != vals[i - 1] + 1 {
+                if (vals[i - 1] - vals[start]) > (best.end() - best.start()) {
+                    best = vals[start]..=vals[i - 1];
+                }
+                start = i;
+            }
+        }
+
+        assert_eq!(sgs.longest_run(), Some(best));
+    }
+
+    // Boundaries, ties, and degenerate sets
+    let sgs = SgSet::<u8, 4>::from([0, 1, 254, 255]);
+    assert_eq!(sgs.longest_run(), Some(0..=1));
+
+    let sgs = SgSet::<i32, 6>::from([-3, -2, -1, 5, 6, 7]);
+    assert_eq!(sgs.longest_run(), Some(-3..=-1));
+
+    let sgs = SgSet::<i32, 5>::from([1, 3, 4, 5, 9]);
+    assert_eq!(sgs.longest_run(), Some(3..=5));
+
+    assert_eq!(SgSet::<u64, 1>::from([7]).longest_run(), Some(7..=7));
+    assert_eq!(SgSet::<u64, 1>::new().longest_run(), None);
+}
+
+#[test]
+fn test_set_split_first_last() {
+    assert!(SgSet::<u32, 1>::new().split_first().is_none());
+    assert!(SgSet::<u32, 1>::new().split_last().is_none());
+
+    let sgs = SgSet::<usize, 100>::from_iter((0..100).rev());
+
+    // Repeated split_first yields ascending elements
+    let mut heads = Vec::new();
+    let mut rest = sgs.clone();
+    while let Some((head, tail)) = rest.split_first() {
+        heads.push(head);
+        rest = tail;
+    }
+    assert_eq!(heads, (0..100).collect::<Vec<_>>());
+
+    // Repeated split_last yields descending elements
+    let mut lasts = Vec::new();
+    let mut rest = sgs;
+    while let Some((last, init)) = rest.split_last() {
+        lasts.push(last);
+        rest = init;
+    }
+    assert_eq!(lasts, (0..100).rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_set_map_round_trip() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<u32, 100>::new();
+    while sgs.len() < 50 {
+        sgs.insert(rng.random_range(0..1000));
+    }
+    sgs.pop_first();
+
+    // Set -> map: every element keyed, value computed from it
+    let sgm = sgs.clone().into_map_with(|e| e * 2);
+    assert_eq!(sgm.len(), sgs.len());
+    assert!(sgm.iter().all(|(k, v)| *v == k * 2));
+    assert!(sgm.keys().eq(sgs.iter()));
+
+    // Map -> set: values dropped, elements and structure preserved
+    let round_trip = SgSet::from(sgm);
+    assert_eq!(round_trip, sgs);
+
+    // Empty round trip
+    let empty = SgSet::<u32, 100>::new();
+    assert!(SgSet::from(empty.into_map_with(|_| 0)).is_empty());
+}
+
+#[test]
+fn test_set_first_last() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<u16, 256>::new();
+    let mut bts = BTreeSet::new();
+
+    // Empty
+    assert_eq!(sgs.first(), None);
+    assert_eq!(sgs.last(), None);
+
+    // Random inserts and removals, boundaries tracked against `BTreeSet`
+    for _ in 0..2_000 {
+        let val = rng.random_range(0..512);
+        match rng.random_bool(0.6) && !sgs.is_full() {
+            true => assert_eq!(sgs.insert(val), bts.insert(val)),
+            false => assert_eq!(sgs.remove(&val), bts.remove(&val)),
+        }
+
+        assert_eq!(sgs.first(), bts.first());
+        assert_eq!(sgs.last(), bts.last());
+    }
+
+    // Drained from both ends
+    while !bts.is_empty() {
+        assert_eq!(sgs.pop_first(), bts.pop_first());
+        assert_eq!(sgs.pop_last(), bts.pop_last());
+        assert_eq!(sgs.first(), bts.first());
+        assert_eq!(sgs.last(), bts.last());
+    }
+    assert_eq!(sgs.first(), None);
+    assert_eq!(sgs.last(), None);
+}
+
+#[test]
+fn test_set_op_lens() {
+    const N: usize = 16;
+    let mut rng = rand::rng();
+
+    for _ in 0..200 {
+        let a_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let b_btree: BTreeSet<u8> = (0..rng.random_range(0..N))
+            .map(|_| rng.random_range(0..24))
+            .collect();
+        let a = SgSet::<_, N>::from_iter(a_btree.iter().copied());
+        let b = SgSet::<_, N>::from_iter(b_btree.iter().copied());
+
+        // Against the lazy iterators and `BTreeSet`
+        assert_eq!(a.union_len(&b), a.union(&b).count());
+        assert_eq!(a.union_len(&b), a_btree.union(&b_btree).count());
+        assert_eq!(a.intersection_len(&b), a.intersection(&b).count());
+        assert_eq!(a.difference_len(&b), a.difference(&b).count());
+        assert_eq!(b.difference_len(&a), b_btree.difference(&a_btree).count());
+        assert_eq!(
+            a.symmetric_difference_len(&b),
+            a.symmetric_difference(&b).count()
+        );
+
+        // Against the materialized sets, and as a capacity pre-check
+        assert_eq!(
+            a.intersection_len(&b),
+            a.intersection_set(&b).unwrap().len()
+        );
+        assert_eq!(a.difference_len(&b), a.difference_set(&b).unwrap().len());
+        match a.union_len(&b) <= N {
+            true => assert_eq!(a.union_len(&b), a.union_set(&b).unwrap().len()),
+            false => assert_eq!(a.union_set(&b), Err(SgError::StackCapacityExceeded)),
+        }
+        match a.symmetric_difference_len(&b) <= N {
+            true => assert_eq!(
+                a.symmetric_difference_len(&b),
+                a.symmetric_difference_set(&b).unwrap().len()
+            ),
+            false => assert_eq!(
+                a.symmetric_difference_set(&b),
+                Err(SgError::StackCapacityExceeded)
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_set_cloned_copied_iter() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<u32, 100>::new();
+    while sgs.len() < 100 {
+        sgs.insert(rng.random_range(0..1000));
+    }
+    let bts: BTreeSet<u32> = sgs.iter().copied().collect();
+
+    assert_eq!(sgs.copied_iter().len(), sgs.len());
+    assert!(sgs.copied_iter().eq(bts.iter().copied()));
+    assert!(sgs.copied_iter().rev().eq(bts.iter().rev().copied()));
+
+    // Owned clones of non-`Copy` data, set left intact
+    let strs = SgSet::<String, 100>::from_iter(bts.iter().map(|v| v.to_string()));
+    let owned: Vec<String> = strs.cloned_iter().collect();
+    assert!(owned.iter().eq(strs.iter()));
+    assert_eq!(strs.len(), 100);
+}
+
+#[test]
+fn test_set_from_refs() {
+    let mut rng = rand::rng();
+    let vec: Vec<u32> = (0..200).map(|_| rng.random_range(0..100)).collect();
+    let bts: BTreeSet<u32> = vec.iter().copied().collect();
+
+    let sgs = SgSet::<u32, 100>::from_refs(&vec).unwrap();
+    assert!(sgs.iter().eq(bts.iter()));
+    assert_eq!(vec.len(), 200);
+
+    let strs: Vec<String> = bts.iter().map(|v| v.to_string()).collect();
+    let sgs = SgSet::<String, 100>::from_refs(strs.iter()).unwrap();
+    assert_eq!(sgs.len(), strs.len());
+
+    assert_eq!(
+        SgSet::<u32, 1>::from_refs(&[1, 2]),
+        Err(SgError::StackCapacityExceeded)
+    );
+}
+
+#[test]
+fn test_set_hash_with() {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut rng = rand::rng();
+    let mut vec: Vec<u32> = (0..100).map(|_| rng.random()).collect();
+
+    let sgs_1 = SgSet::<_, 100>::from_iter(vec.clone());
+    vec.reverse();
+    let sgs_2 = SgSet::<_, 200>::from_iter(vec);
+
+    let mut hasher_1 = DefaultHasher::new();
+    let mut hasher_2 = DefaultHasher::new();
+    sgs_1.hash_with(&mut hasher_1);
+    sgs_2.hash_with(&mut hasher_2);
+    assert_eq!(hasher_1.finish(), hasher_2.finish());
+
+    // `Hash` is built on the same stream
+    let mut hasher_3 = DefaultHasher::new();
+    sgs_1.hash(&mut hasher_3);
+    assert_eq!(hasher_1.finish(), hasher_3.finish());
+
+    // Contents change the hash
+    let mut sgs_3 = sgs_1.clone();
+    sgs_3.pop_first();
+    let mut hasher_4 = DefaultHasher::new();
+    sgs_3.hash_with(&mut hasher_4);
+    assert_ne!(hasher_1.finish(), hasher_4.finish());
+}
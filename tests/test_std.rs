@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+
+use escapegoat::{SgError, SgMap, SgSet};
+
+use rand::Rng;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+#[test]
+fn test_map_try_from_btree() {
+    let mut rng = rand::rng();
+    let mut btm = BTreeMap::new();
+
+    while btm.len() < DEFAULT_CAPACITY {
+        btm.insert(rng.random::<u32>(), rng.random::<u64>());
+    }
+
+    let sgm = SgMap::<_, _, DEFAULT_CAPACITY>::try_from_btree(btm.clone()).unwrap();
+    assert!(sgm.iter().eq(btm.iter()));
+    assert_eq!(sgm.first_key_value(), btm.first_key_value());
+    assert_eq!(sgm.last_key_value(), btm.last_key_value());
+
+    // Built balanced: minimum height for 100 elements
+    assert_eq!(sgm.debug_stats().height, 7);
+
+    // Trait form
+    let sgm_2 = SgMap::<_, _, DEFAULT_CAPACITY>::try_from(btm.clone()).unwrap();
+    assert_eq!(sgm_2, sgm);
+
+    // Fully functional after conversion
+    let mut sgm_3 = sgm.clone();
+    for k in btm.keys() {
+        assert!(sgm_3.remove(k).is_some());
+    }
+    assert!(sgm_3.is_empty());
+
+    // Too large
+    assert_eq!(
+        SgMap::<_, _, { DEFAULT_CAPACITY - 1 }>::try_from(btm),
+        Err(SgError::StackCapacityExceeded)
+    );
+
+    // Empty
+    assert!(
+        SgMap::<u32, u64, 0>::try_from_btree(BTreeMap::new())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_set_try_from_btree() {
+    let mut rng = rand::rng();
+    let mut bts = BTreeSet::new();
+
+    while bts.len() < DEFAULT_CAPACITY {
+        bts.insert(rng.random::<u32>());
+    }
+
+    let sgs = SgSet::<_, DEFAULT_CAPACITY>::try_from_btree(bts.clone()).unwrap();
+    assert!(sgs.iter().eq(bts.iter()));
+    assert_eq!(SgSet::<_, DEFAULT_CAPACITY>::try_from(bts.clone()), Ok(sgs));
+
+    assert_eq!(
+        SgSet::<_, 1>::try_from(bts),
+        Err(SgError::StackCapacityExceeded)
+    );
+}
+
+#[test]
+fn test_set_to_sorted_vec() {
+    let mut rng = rand::rng();
+    let mut sgs = SgSet::<_, DEFAULT_CAPACITY>::new();
+
+    while sgs.len() < DEFAULT_CAPACITY {
+        sgs.insert(rng.random::<u32>());
+    }
+
+    // Removals leave gaps in the arena
+    let removed: Vec<u32> = sgs.iter().step_by(3).copied().collect();
+    for val in &removed {
+        sgs.remove(val);
+    }
+
+    let vec = sgs.to_sorted_vec();
+    assert_eq!(vec.len(), sgs.len());
+    assert!(vec.is_sorted());
+    assert!(vec.iter().eq(sgs.iter()));
+
+    assert!(
+        SgSet::<u32, DEFAULT_CAPACITY>::new()
+            .to_sorted_vec()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_structure_string() {
+    // Parse `(left key@depth right)` back into in-order `(key, depth)` pairs
+    fn parse(s: &str) -> Vec<(u32, usize)> {
+        s.split(['(', ')'])
+            .filter(|tok| !tok.is_empty())
+            .map(|tok| {
+                let (key, depth) = tok.split_once('@').unwrap();
+                (key.parse().unwrap(), depth.parse().unwrap())
+            })
+            .collect()
+    }
+
+    let mut rng = rand::rng();
+    let mut sgm = SgMap::<u32, u32, DEFAULT_CAPACITY>::new();
+    assert_eq!(sgm.structure_string(), "");
+
+    while sgm.len() < DEFAULT_CAPACITY {
+        sgm.insert(rng.random_range(0..1_000), 0);
+
+        // In-order keys, and depths consistent with height
+        let nodes = parse(&sgm.structure_string());
+        assert!(nodes.iter().map(|(k, _)| k).eq(sgm.keys()));
+        let max_depth = nodes.iter().map(|(_, d)| *d).max().unwrap();
+        assert_eq!(max_depth + 1, sgm.debug_stats().height);
+        assert_eq!(nodes.iter().filter(|(_, d)| *d == 0).count(), 1);
+    }
+
+    // Balanced parens, and the set renders the same shape
+    let rendered = sgm.structure_string();
+    assert_eq!(rendered.matches('(').count(), sgm.len());
+    assert_eq!(rendered.matches(')').count(), sgm.len());
+    let sgs = SgSet::<u32, DEFAULT_CAPACITY>::from(sgm);
+    assert_eq!(sgs.structure_string(), rendered);
+}
@@ -1,7 +1,10 @@
-use escapegoat::{SgMap, SgSet};
+use std::error::Error;
+use std::fmt;
+
+use escapegoat::{CollectError, SgError, SgMap, SgSet};
 
 fn is_auto_trait_friendly<T: Sized + Send + Sync + Unpin>() {}
-fn is_default<T>() {}
+fn is_default<T: Default>() {}
 
 #[test]
 fn test_auto_traits_map() {
@@ -22,3 +25,115 @@ fn test_default_map() {
 fn test_default_set() {
     is_default::<SgSet<usize, 10>>();
 }
+
+// Neither `Default` nor `Clone`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Opaque(u8);
+
+#[test]
+fn test_default_no_elem_bound() {
+    is_default::<SgMap<Opaque, Opaque, 10>>();
+    is_default::<SgSet<Opaque, 10>>();
+}
+
+#[test]
+fn test_default_derive_and_static() {
+    #[derive(Default)]
+    struct Registry {
+        by_id: SgMap<Opaque, Opaque, 8>,
+        tags: SgSet<Opaque, 8>,
+    }
+
+    let mut reg = Registry::default();
+    assert!(reg.by_id.is_empty() && reg.tags.is_empty());
+    reg.by_id.insert(Opaque(1), Opaque(2));
+    reg.tags.insert(Opaque(3));
+    assert_eq!(reg.by_id.get(&Opaque(1)), Some(&Opaque(2)));
+
+    static EMPTY_MAP: SgMap<u32, u32, 8> = SgMap::EMPTY;
+    static EMPTY_SET: SgSet<u32, 8> = SgSet::EMPTY;
+    assert!(EMPTY_MAP.is_empty() && EMPTY_SET.is_empty());
+    assert_eq!(EMPTY_MAP.capacity(), 8);
+
+    const REGISTRY: Registry = Registry {
+        by_id: SgMap::EMPTY,
+        tags: SgSet::EMPTY,
+    };
+    assert!(REGISTRY.by_id.is_empty() && REGISTRY.tags.is_empty());
+    assert_eq!(REGISTRY.by_id, SgMap::default());
+}
+
+fn is_error<T: Error + Send + Sync + 'static>() {}
+
+#[test]
+fn test_error_traits() {
+    is_error::<SgError>();
+    is_error::<CollectError>();
+
+    assert_eq!(
+        SgError::StackCapacityExceeded.to_string(),
+        "stack storage capacity exceeded"
+    );
+    assert_eq!(
+        CollectError::TooMany.to_string(),
+        "iterator yielded more elements than the array's length"
+    );
+}
+
+// Downstream error type wrapping `SgError`
+#[derive(Debug)]
+enum AppError {
+    Storage(SgError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Storage(err) => write!(f, "storage: {err}"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Storage(err) => Some(err),
+        }
+    }
+}
+
+impl From<SgError> for AppError {
+    fn from(err: SgError) -> Self {
+        AppError::Storage(err)
+    }
+}
+
+#[test]
+fn test_error_propagation() {
+    fn fill_custom(set: &mut SgSet<u8, 2>) -> Result<(), AppError> {
+        for i in 0..3 {
+            set.try_insert(i)?;
+        }
+        Ok(())
+    }
+
+    fn fill_boxed(set: &mut SgSet<u8, 2>) -> Result<(), Box<dyn Error>> {
+        for i in 0..3 {
+            set.try_insert(i)?;
+        }
+        Ok(())
+    }
+
+    let err = fill_custom(&mut SgSet::new()).unwrap_err();
+    assert_eq!(err.to_string(), "storage: stack storage capacity exceeded");
+    assert_eq!(
+        err.source().unwrap().downcast_ref::<SgError>(),
+        Some(&SgError::StackCapacityExceeded)
+    );
+
+    let err = fill_boxed(&mut SgSet::new()).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SgError>(),
+        Some(&SgError::StackCapacityExceeded)
+    );
+}